@@ -0,0 +1,214 @@
+use core::{borrow::Borrow, iter::FusedIterator, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use alloc::{borrow::ToOwned, vec::Vec};
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::Seekable};
+
+
+/// An adapter for a [`CursorLendingIterator`] which yields owned copies of its items, grouped
+/// into chunks of up to `chunk_size` items each.
+///
+/// Every chunk has exactly `chunk_size` items, except possibly the final chunk, which has the
+/// remaining items of the collection. This can amortize per-batch costs in downstream
+/// processing.
+///
+/// This adapter only supports forwards iteration. Iteration starts from the inner iterator's
+/// current item if it is [valid], or else from the first entry of the collection; once the end
+/// of the collection is reached, no more chunks are yielded (until the adapter is seeked or
+/// reset). Seeking the adapter resets chunk boundaries: the next chunk starts at the item the
+/// seek landed on.
+///
+/// [valid]: CursorLendingIterator::valid
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct ChunksIter<I, Borrowed: ?Sized> {
+    iter:            I,
+    chunk_size:      usize,
+    /// Whether `iter`'s current item (if any) has not yet been yielded in a chunk.
+    current_pending: bool,
+    exhausted:       bool,
+    _borrowed:       PhantomData<fn(&Borrowed)>,
+}
+
+impl<I, Borrowed> ChunksIter<I, Borrowed>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    /// Wrap the provided iterator, yielding chunks of up to `chunk_size` items.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    #[inline]
+    #[must_use]
+    pub fn new(iter: I, chunk_size: usize) -> Self {
+        assert_ne!(chunk_size, 0, "chunk size must be nonzero");
+
+        let current_pending = iter.valid();
+        Self {
+            iter,
+            chunk_size,
+            current_pending,
+            exhausted:       false,
+            _borrowed:       PhantomData,
+        }
+    }
+
+    /// Get the maximum number of items in each chunk.
+    #[inline]
+    #[must_use]
+    pub const fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`. The next chunk starts at that item.
+    ///
+    /// See [`Seekable::seek`].
+    pub fn seek<Key, Cmp>(&mut self, min_bound: &Key)
+    where
+        I:   Seekable<Key, Cmp>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        self.iter.seek(min_bound);
+        self.restart();
+    }
+
+    /// Move the iterator to the smallest key in the collection. The next chunk starts at that
+    /// item.
+    ///
+    /// See [`Seekable::seek_to_first`].
+    pub fn seek_to_first<Key, Cmp>(&mut self)
+    where
+        I:   Seekable<Key, Cmp>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        self.iter.seek_to_first();
+        self.restart();
+    }
+
+    /// Reset the iterator to its initial position. The next chunk starts at the first entry of
+    /// the collection.
+    ///
+    /// See [`Seekable::reset`].
+    pub fn reset<Key, Cmp>(&mut self)
+    where
+        I:   Seekable<Key, Cmp>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        self.iter.reset();
+        self.restart();
+    }
+
+    /// Start chunking from the inner iterator's current position.
+    fn restart(&mut self) {
+        self.current_pending = self.iter.valid();
+        self.exhausted = false;
+    }
+}
+
+impl<I, Borrowed> Iterator for ChunksIter<I, Borrowed>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    type Item = Vec<Borrowed::Owned>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+
+        while chunk.len() < self.chunk_size {
+            if self.current_pending {
+                self.current_pending = false;
+            } else {
+                self.iter.next();
+            }
+
+            let Some(item) = self.iter.current() else {
+                self.exhausted = true;
+                break;
+            };
+            chunk.push(item.borrow().to_owned());
+        }
+
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+impl<I, Borrowed> FusedIterator for ChunksIter<I, Borrowed>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{}
+
+impl<I: Debug, Borrowed: ?Sized> Debug for ChunksIter<I, Borrowed> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ChunksIter")
+            .field("iter", &self.iter)
+            .field("chunk_size", &self.chunk_size)
+            .field("current_pending", &self.current_pending)
+            .field("exhausted", &self.exhausted)
+            .finish_non_exhaustive()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn chunks_of_four() {
+        let data: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut chunks = ChunksIter::<_, u8>::new(TestIter::new(data).unwrap(), 4);
+
+        assert_eq!(chunks.next(), Some(alloc::vec![0, 1, 2, 3]));
+        assert_eq!(chunks.next(), Some(alloc::vec![4, 5, 6, 7]));
+        assert_eq!(chunks.next(), Some(alloc::vec![8, 9]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+
+        chunks.seek::<u8, OrdComparator>(&3);
+        let sizes: Vec<usize> = chunks.by_ref().map(|chunk| chunk.len()).collect();
+        assert_eq!(sizes, [4, 3]);
+
+        chunks.reset::<u8, OrdComparator>();
+        let sizes: Vec<usize> = chunks.map(|chunk| chunk.len()).collect();
+        assert_eq!(sizes, [4, 4, 2]);
+    }
+}
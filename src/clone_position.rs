@@ -0,0 +1,69 @@
+use crate::cursor::CursorLendingIterator;
+
+
+/// A [`CursorLendingIterator`] whose position can be saved cheaply, and later restored in a new
+/// iterator which shares the same underlying data.
+///
+/// This supports speculative lookahead: save the position, move a new iterator at that position
+/// (or the original iterator) ahead, and discard whichever is no longer needed. Unlike [`Clone`],
+/// neither saving nor restoring a position copies the backing data, so both should be cheap even
+/// for large collections; sources which own their data could share it with an `Rc` or `Arc`.
+pub trait ClonePosition: CursorLendingIterator + Sized {
+    /// A saved position of the iterator, such as an index into its collection.
+    type PositionHandle;
+
+    /// Save the current position of the iterator, including whether it is `!valid()`.
+    #[must_use]
+    fn clone_position(&self) -> Self::PositionHandle;
+
+    /// Create a new iterator over the same underlying data as `self`, at the position saved in
+    /// `handle`.
+    ///
+    /// The handle should have been saved from `self`, or from an iterator which shares its
+    /// underlying data. Other handles may place the new iterator at an arbitrary position,
+    /// possibly `!valid()`, but must not cause a panic or undefined behavior.
+    #[must_use]
+    fn at_position(&self, handle: &Self::PositionHandle) -> Self;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, seekable::Seekable as _};
+    use crate::sorted_slice_cursor::SortedSliceCursor;
+    use super::*;
+
+
+    #[test]
+    fn clone_stays_at_saved_position() {
+        let data: &[u8] = [1, 3, 5, 7, 9].as_slice();
+        let mut original = SortedSliceCursor::new(data, OrdComparator);
+        original.seek(&4);
+
+        let handle = original.clone_position();
+        assert_eq!(original.next(), Some(&7));
+        assert_eq!(original.next(), Some(&9));
+
+        let mut lookahead = original.at_position(&handle);
+        assert_eq!(lookahead.current(), Some(&5));
+        assert_eq!(lookahead.data().as_ptr(), data.as_ptr());
+        assert_eq!(lookahead.next(), Some(&7));
+
+        // Moving either iterator does not move the other, nor a clone made from the same handle.
+        assert_eq!(original.next(), None);
+        assert_eq!(lookahead.current(), Some(&7));
+        assert_eq!(original.at_position(&handle).current(), Some(&5));
+    }
+
+    #[test]
+    fn invalid_position() {
+        let data: &[u8] = [1, 3].as_slice();
+        let mut original = SortedSliceCursor::new(data, OrdComparator);
+
+        let handle = original.clone_position();
+        original.next();
+        let mut clone = original.at_position(&handle);
+        assert!(!clone.valid());
+        assert_eq!(clone.prev(), Some(&3));
+    }
+}
@@ -0,0 +1,196 @@
+use core::{borrow::Borrow, iter::FusedIterator, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use alloc::borrow::ToOwned;
+
+use crate::{cursor::CursorLendingIterator, lending_iterator_support::LentItem};
+
+
+/// An adapter for a [`CursorLendingIterator`] which combines runs of adjacent entries with a
+/// user-provided function, yielding owned accumulated values.
+///
+/// Starting from an owned copy of an entry, each following entry is passed to
+/// `coalesce(accumulated, next_entry)`. If the function returns `Ok(merged)`, then `merged`
+/// becomes the accumulated value, and coalescing continues. If the function returns
+/// `Err((finished, restart))`, then `finished` is yielded, and `restart` becomes the new
+/// accumulated value (usually, `restart` is an owned copy of `next_entry`).
+///
+/// This generalizes deduplication to arbitrary combinations of adjacent entries, such as summing
+/// the values of entries with equal keys.
+///
+/// Iteration proceeds forwards from the inner iterator's position: the first entry coalesced is
+/// the one after the inner iterator's current entry, or the first entry of the collection if the
+/// inner iterator is not valid. Unlike a cursor, this adapter is a [`FusedIterator`]; once the
+/// end of the collection is reached, it does not wrap around.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct CoalesceIter<I, Borrowed: ?Sized + ToOwned, F> {
+    iter:      I,
+    coalesce:  F,
+    /// An accumulated value which has not yet been combined with any later entries.
+    pending:   Option<Borrowed::Owned>,
+    exhausted: bool,
+    _borrowed: PhantomData<fn(&Borrowed)>,
+}
+
+impl<I, Borrowed, F> CoalesceIter<I, Borrowed, F>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    F:                             FnMut(
+        Borrowed::Owned,
+        &Borrowed,
+    ) -> Result<Borrowed::Owned, (Borrowed::Owned, Borrowed::Owned)>,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    /// Wrap the provided iterator, combining adjacent entries with `coalesce`.
+    ///
+    /// See the type-level documentation for how `coalesce` is used.
+    #[inline]
+    #[must_use]
+    pub const fn new(iter: I, coalesce: F) -> Self {
+        Self {
+            iter,
+            coalesce,
+            pending:   None,
+            exhausted: false,
+            _borrowed: PhantomData,
+        }
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    ///
+    /// Any value which was accumulated but not yet yielded is discarded.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, Borrowed, F> Iterator for CoalesceIter<I, Borrowed, F>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    F:                             FnMut(
+        Borrowed::Owned,
+        &Borrowed,
+    ) -> Result<Borrowed::Owned, (Borrowed::Owned, Borrowed::Owned)>,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    type Item = Borrowed::Owned;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let mut accumulated = if let Some(pending) = self.pending.take() {
+            pending
+        } else if let Some(item) = self.iter.next() {
+            item.borrow().to_owned()
+        } else {
+            self.exhausted = true;
+            return None;
+        };
+
+        loop {
+            let Some(item) = self.iter.next() else {
+                self.exhausted = true;
+                return Some(accumulated);
+            };
+
+            match (self.coalesce)(accumulated, item.borrow()) {
+                Ok(merged) => accumulated = merged,
+                Err((finished, restart)) => {
+                    self.pending = Some(restart);
+                    return Some(finished);
+                }
+            }
+        }
+    }
+}
+
+impl<I, Borrowed, F> FusedIterator for CoalesceIter<I, Borrowed, F>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    F:                             FnMut(
+        Borrowed::Owned,
+        &Borrowed,
+    ) -> Result<Borrowed::Owned, (Borrowed::Owned, Borrowed::Owned)>,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{}
+
+impl<I, Borrowed, F> Debug for CoalesceIter<I, Borrowed, F>
+where
+    I:               Debug,
+    Borrowed:        ?Sized + ToOwned,
+    Borrowed::Owned: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("CoalesceIter")
+            .field("iter", &self.iter)
+            .field("coalesce", &"..")
+            .field("pending", &self.pending)
+            .field("exhausted", &self.exhausted)
+            .finish_non_exhaustive()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use super::*;
+
+
+    #[test]
+    fn sum_equal_keys() {
+        let data: &[(u8, u32)] = &[(1, 10), (1, 5), (2, 1), (3, 2), (3, 3), (3, 4), (7, 0)];
+        let cursor = SortedSliceCursor::new(data, OrdComparator);
+
+        let coalesced: Vec<(u8, u32)> = CoalesceIter::new(
+            cursor,
+            |(key, sum), &(next_key, value)| {
+                if key == next_key {
+                    Ok((key, sum + value))
+                } else {
+                    Err(((key, sum), (next_key, value)))
+                }
+            },
+        ).collect();
+
+        assert_eq!(coalesced, [(1, 15), (2, 1), (3, 9), (7, 0)]);
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn fused_and_empty() {
+        let data: &[u8] = &[];
+        let mut iter = CoalesceIter::<_, u8, _>::new(
+            SortedSliceCursor::new(data, OrdComparator),
+            |acc, &next| Err((acc, next)),
+        );
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let data: &[u8] = &[4];
+        let mut iter = CoalesceIter::<_, u8, _>::new(
+            SortedSliceCursor::new(data, OrdComparator),
+            |acc, &next| Err((acc, next)),
+        );
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
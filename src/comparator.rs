@@ -1,4 +1,4 @@
-use core::cmp::Ordering;
+use core::{cmp::Ordering, fmt::{Debug, Formatter, Result as FmtResult}};
 
 #[cfg(feature = "clone-behavior")]
 use clone_behavior::{IndependentClone, MirroredClone, NearInstant, NonRecursive};
@@ -36,6 +36,22 @@ pub trait Comparator<Key: ?Sized> {
     fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering;
 }
 
+/// Determine whether two keys are equal according to the provided comparator.
+///
+/// Adapters which need to check whether keys are equal should use this, rather than relying on
+/// a [`PartialEq`] implementation of the keys (which need not exist, and need not agree with the
+/// comparator).
+#[expect(clippy::redundant_pub_crate, reason = "emphasize that this is internal")]
+#[inline]
+#[must_use]
+pub(crate) fn keys_equal<Key, Cmp>(cmp: &Cmp, lhs: &Key, rhs: &Key) -> bool
+where
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    cmp.cmp(lhs, rhs) == Ordering::Equal
+}
+
 #[cfg(feature = "generic-container")]
 impl<Key: ?Sized, C: FragileContainer<dyn Comparator<Key>>> Comparator<Key> for C {
     #[inline]
@@ -93,3 +109,336 @@ impl MirroredClone<NearInstant> for OrdComparator {
         Self
     }
 }
+
+/// A [`Comparator`] which considers every key to be equal to every other key.
+///
+/// This is useful for degenerate collections with a single implicit key, such as collections
+/// keyed by `()`; the only total order on `()` is the one where every key compares as equal.
+///
+/// Adapters in this crate handle comparators under which all keys are equal. For example, a
+/// [`MergingIter`] using `UnitComparator` yields the items of its source iterators in the order
+/// of the sources, as ties between keys are broken by the index of the source iterator.
+///
+/// [`MergingIter`]: crate::MergingIter
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnitComparator;
+
+impl<Key: ?Sized> Comparator<Key> for UnitComparator {
+    /// Always returns [`Ordering::Equal`].
+    #[inline]
+    fn cmp(&self, _lhs: &Key, _rhs: &Key) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+#[cfg(feature = "clone-behavior")]
+impl NonRecursive for UnitComparator {}
+
+#[cfg(feature = "clone-behavior")]
+impl IndependentClone<NearInstant> for UnitComparator {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "clone-behavior")]
+impl MirroredClone<NearInstant> for UnitComparator {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        Self
+    }
+}
+
+/// A [`Comparator`] for byte strings which compares bytes by their rank in a collation table,
+/// rather than by their numeric value.
+///
+/// Byte strings are compared element-by-element, where each byte `b` is ranked as `table[b]`;
+/// the first pair of bytes with different ranks determines the order. If one byte string is a
+/// prefix of the other (up to rank), the shorter one is lesser. Distinct bytes may have equal
+/// ranks, in which case they are treated as equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollatedSliceComparator {
+    table: [u8; 256],
+}
+
+impl CollatedSliceComparator {
+    /// Create a comparator which ranks each byte `b` as `table[b]`.
+    #[inline]
+    #[must_use]
+    pub const fn new(table: [u8; 256]) -> Self {
+        Self { table }
+    }
+
+    /// Create a comparator which ranks each byte by its numeric value, and thus behaves like
+    /// [`OrdComparator`] on byte strings.
+    #[must_use]
+    pub fn identity() -> Self {
+        let mut table = [0; 256];
+        for (rank, byte) in table.iter_mut().zip(0..=u8::MAX) {
+            *rank = byte;
+        }
+        Self { table }
+    }
+
+    /// Get the collation table, which maps each byte to its rank.
+    #[inline]
+    #[must_use]
+    pub const fn table(&self) -> &[u8; 256] {
+        &self.table
+    }
+
+    /// Get the rank of a byte.
+    #[inline]
+    #[must_use]
+    pub fn rank(&self, byte: u8) -> u8 {
+        #[expect(clippy::indexing_slicing, reason = "every `u8` is a valid index of `[u8; 256]`")]
+        self.table[usize::from(byte)]
+    }
+}
+
+impl Default for CollatedSliceComparator {
+    /// Equivalent to [`CollatedSliceComparator::identity`].
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Comparator<[u8]> for CollatedSliceComparator {
+    fn cmp(&self, lhs: &[u8], rhs: &[u8]) -> Ordering {
+        for (&lhs_byte, &rhs_byte) in lhs.iter().zip(rhs) {
+            let ordering = self.rank(lhs_byte).cmp(&self.rank(rhs_byte));
+            if ordering.is_ne() {
+                return ordering;
+            }
+        }
+
+        lhs.len().cmp(&rhs.len())
+    }
+}
+
+/// A [`Comparator`] which compares keys by precomputed byte strings, such as sort keys
+/// produced by a database collation.
+///
+/// The provided function extracts each key's comparison bytes, and the extracted byte strings
+/// are compared lexicographically by their numeric values, as with [`Ord`] on `[u8]`. Keys with
+/// equal comparison bytes compare as equal.
+#[derive(Clone, Copy)]
+pub struct ByteKeyComparator<F> {
+    sort_key: F,
+}
+
+impl<F> ByteKeyComparator<F> {
+    /// Create a comparator which compares keys by the bytes returned from `sort_key`.
+    #[inline]
+    #[must_use]
+    pub const fn new(sort_key: F) -> Self {
+        Self { sort_key }
+    }
+
+    /// Convert the comparator back into its sort-key function.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> F {
+        self.sort_key
+    }
+}
+
+impl<Key, F> Comparator<Key> for ByteKeyComparator<F>
+where
+    Key: ?Sized,
+    F:   Fn(&Key) -> &[u8],
+{
+    #[inline]
+    fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering {
+        Ord::cmp((self.sort_key)(lhs), (self.sort_key)(rhs))
+    }
+}
+
+impl<F> Debug for ByteKeyComparator<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ByteKeyComparator")
+            .field("sort_key", &"..")
+            .finish()
+    }
+}
+
+/// A [`Comparator`] which compares keys with a closure or function.
+///
+/// `Key` may be unsized, such as `str` or `[u8]`, in which case the function should take
+/// `&str` or `&[u8]` arguments.
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use seekable_iterator::{
+///     CursorLendingIterator as _, FnComparator, MergingIter, SortedSliceCursor,
+/// };
+///
+/// struct MyKey {
+///     name:    &'static str,
+///     version: u32,
+/// }
+///
+/// let cmp = FnComparator::new(|a: &MyKey, b| a.version.cmp(&b.version));
+///
+/// let old = [MyKey { name: "a", version: 1 }, MyKey { name: "b", version: 4 }];
+/// let new = [MyKey { name: "c", version: 2 }, MyKey { name: "d", version: 3 }];
+/// let mut merge = MergingIter::new(
+///     vec![
+///         SortedSliceCursor::new(old.as_slice(), cmp),
+///         SortedSliceCursor::new(new.as_slice(), cmp),
+///     ],
+///     cmp,
+/// );
+///
+/// let mut names = Vec::new();
+/// while let Some(key) = merge.next() {
+///     names.push(key.name);
+/// }
+/// assert_eq!(names, ["a", "c", "d", "b"]);
+/// # }
+/// ```
+#[derive(Clone, Copy)]
+pub struct FnComparator<F>(F);
+
+impl<F> FnComparator<F> {
+    /// Create a comparator which compares keys with `cmp`.
+    ///
+    /// The bound on `cmp` lets the argument types of a closure be inferred from `Key`.
+    #[inline]
+    #[must_use]
+    pub const fn new<Key>(cmp: F) -> Self
+    where
+        Key: ?Sized,
+        F:   Fn(&Key, &Key) -> Ordering,
+    {
+        Self(cmp)
+    }
+
+    /// Convert the comparator back into its comparison function.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
+impl<Key, F> Comparator<Key> for FnComparator<F>
+where
+    Key: ?Sized,
+    F:   Fn(&Key, &Key) -> Ordering,
+{
+    #[inline]
+    fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering {
+        (self.0)(lhs, rhs)
+    }
+}
+
+impl<F> Debug for FnComparator<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("FnComparator")
+            .field(&"..")
+            .finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    #[cfg(feature = "alloc")]
+    use crate::{merging_iter::MergingIter, sorted_slice_cursor::SortedSliceCursor};
+    #[cfg(feature = "alloc")]
+    use crate::cursor::CursorLendingIterator as _;
+
+
+    #[test]
+    fn collation_with_digits_after_letters() {
+        let mut table = *CollatedSliceComparator::identity().table();
+        for (digit, rank) in table.iter_mut().skip(usize::from(b'0')).take(10).zip(230..) {
+            *digit = rank;
+        }
+        let collated = CollatedSliceComparator::new(table);
+
+        assert_eq!(collated.cmp(b"a1".as_slice(), b"ab".as_slice()), Ordering::Greater);
+        assert_eq!(collated.cmp(b"9".as_slice(), b"z".as_slice()), Ordering::Greater);
+        assert_eq!(collated.cmp(b"0".as_slice(), b"9".as_slice()), Ordering::Less);
+        assert_eq!(collated.cmp(b"ab".as_slice(), b"abc".as_slice()), Ordering::Less);
+        assert_eq!(collated.cmp(b"ab1".as_slice(), b"ab1".as_slice()), Ordering::Equal);
+
+        let mut words: [&[u8]; 5] = [b"1a", b"a1", b"a", b"ab", b"b"];
+        words.sort_by(|lhs, rhs| collated.cmp(lhs, rhs));
+        assert_eq!(words, [b"a".as_slice(), b"ab", b"a1", b"b", b"1a"]);
+    }
+
+    #[test]
+    fn identity_matches_ord() {
+        let identity = CollatedSliceComparator::default();
+        let words: [&[u8]; 4] = [b"", b"\x00", b"\xff", b"a\x80"];
+        for lhs in words {
+            for rhs in words {
+                assert_eq!(identity.cmp(lhs, rhs), Ord::cmp(lhs, rhs));
+            }
+        }
+    }
+
+    /// A key whose sort key reverses the order of its value.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Entry {
+        value:    u8,
+        sort_key: [u8; 1],
+    }
+
+    impl Entry {
+        const fn new(value: u8) -> Self {
+            Self { value, sort_key: [u8::MAX - value] }
+        }
+    }
+
+    fn entry_sort_key(entry: &Entry) -> &[u8] {
+        &entry.sort_key
+    }
+
+    #[test]
+    fn byte_keys_reverse_order() {
+        let cmp = ByteKeyComparator::new(entry_sort_key);
+        assert_eq!(cmp.cmp(&Entry::new(1), &Entry::new(2)), Ordering::Greater);
+        assert_eq!(cmp.cmp(&Entry::new(9), &Entry::new(9)), Ordering::Equal);
+        assert_eq!(cmp.cmp(&Entry::new(200), &Entry::new(3)), Ordering::Less);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn byte_keys_control_merge_order() {
+        let cmp = ByteKeyComparator::new(entry_sort_key);
+        let first = [Entry::new(9), Entry::new(4), Entry::new(1)];
+        let second = [Entry::new(7), Entry::new(4), Entry::new(2)];
+        let mut merge = MergingIter::new(
+            vec![
+                SortedSliceCursor::new(first.as_slice(), cmp),
+                SortedSliceCursor::new(second.as_slice(), cmp),
+            ],
+            cmp,
+        );
+
+        for expected in [9, 7, 4, 4, 2, 1] {
+            assert_eq!(merge.next().map(|entry| entry.value), Some(expected));
+        }
+        assert_eq!(merge.next(), None);
+    }
+
+    #[test]
+    fn closures_compare_unsized_keys() {
+        let by_len = FnComparator::new(|lhs: &str, rhs| lhs.len().cmp(&rhs.len()));
+        assert_eq!(by_len.cmp("zz", "aaa"), Ordering::Less);
+        assert_eq!(by_len.cmp("ab", "ba"), Ordering::Equal);
+
+        let descending = FnComparator::new(|lhs: &[u8], rhs: &[u8]| rhs.cmp(lhs));
+        assert_eq!(descending.cmp(b"a", b"b"), Ordering::Greater);
+        assert_eq!(descending.into_inner()(b"b", b"b"), Ordering::Equal);
+    }
+}
@@ -0,0 +1,296 @@
+use core::{cmp::Ordering, marker::PhantomData};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+
+#[cfg(feature = "clone-behavior")]
+use clone_behavior::{IndependentClone, MirroredClone, Speed};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+
+use crate::comparator::Comparator;
+#[cfg(feature = "alloc")]
+use crate::saturating::Saturating;
+
+
+/// Provided builder methods for composing [`Comparator`]s fluently, such as
+/// `OrdComparator.map_key(extract_timestamp).reversed()`.
+///
+/// These methods are not provided on [`Comparator`] itself, so that adding them does not affect
+/// existing implementations. The trait is generic over the key type, like [`Comparator`]; for a
+/// comparator which implements `Comparator<Key>` for many key types (like [`OrdComparator`]),
+/// the key type is inferred from the extraction function of [`map_key`], or may otherwise need
+/// to be specified, as in `ComparatorExt::<u32>::reversed(OrdComparator)`. The adapters can also
+/// be created with their constructors, such as [`Reversed::new`].
+///
+/// All implementations are automatically provided by a blanket impl.
+///
+/// [`OrdComparator`]: crate::OrdComparator
+/// [`map_key`]: ComparatorExt::map_key
+pub trait ComparatorExt<Key: ?Sized>: Comparator<Key> + Sized {
+    /// Get a comparator which orders keys in the reverse of the order given by `self`.
+    #[inline]
+    #[must_use]
+    fn reversed(self) -> Reversed<Self> {
+        Reversed::new(self)
+    }
+
+    /// Get a comparator over `Outer` keys which compares the keys extracted by `extract` with
+    /// `self`.
+    ///
+    /// Since closures returning references often fail to infer the correct higher-ranked
+    /// signature, `extract` may need to be a function item (or a closure with an explicitly
+    /// annotated signature, such as one passed through a helper function).
+    #[inline]
+    #[must_use]
+    fn map_key<Outer, F>(self, extract: F) -> MapKey<Self, F, Key>
+    where
+        Outer: ?Sized,
+        F:     Fn(&Outer) -> &Key,
+    {
+        MapKey::new(self, extract)
+    }
+
+    /// Get a comparator which orders keys by `self`, and breaks ties (keys which `self` considers
+    /// `Equal`) with `tie_breaker`.
+    ///
+    /// This is analogous to [`Ordering::then`].
+    #[inline]
+    #[must_use]
+    fn then<B: Comparator<Key>>(self, tie_breaker: B) -> Then<Self, B> {
+        Then::new(self, tie_breaker)
+    }
+
+    /// Get a comparator which orders keys by `self`, except that every key greater than or equal
+    /// to `max_key` is considered equal to `max_key`.
+    ///
+    /// See [`Saturating`] for caveats.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn saturating_at(self, max_key: Key::Owned) -> Saturating<Self, Key>
+    where
+        Key: ToOwned,
+    {
+        Saturating::new(self, max_key)
+    }
+}
+
+impl<Key: ?Sized, C: Comparator<Key>> ComparatorExt<Key> for C {}
+
+/// A [`Comparator`] which reverses the order of an inner comparator.
+///
+/// A source which is sorted in descending order by some comparator is sorted in ascending order
+/// by the reversed comparator, so a [`MergingIter`] using `Reversed` walks keys largest-first
+/// with `seek` and `next`; see [`MergingIter::new_descending`].
+///
+/// Created by [`ComparatorExt::reversed`], or by [`Reversed::new`].
+///
+/// [`MergingIter`]: crate::MergingIter
+/// [`MergingIter::new_descending`]: crate::MergingIter::new_descending
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reversed<C> {
+    cmp: C,
+}
+
+impl<C> Reversed<C> {
+    /// Reverse the order given by `cmp`.
+    #[inline]
+    #[must_use]
+    pub const fn new(cmp: C) -> Self {
+        Self { cmp }
+    }
+
+    /// Convert the adapter back into the inner comparator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.cmp
+    }
+}
+
+impl<Key: ?Sized, C: Comparator<Key>> Comparator<Key> for Reversed<C> {
+    #[inline]
+    fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering {
+        self.cmp.cmp(rhs, lhs)
+    }
+}
+
+#[cfg(feature = "clone-behavior")]
+impl<S: Speed, C: IndependentClone<S>> IndependentClone<S> for Reversed<C> {
+    #[inline]
+    fn independent_clone(&self) -> Self {
+        Self { cmp: self.cmp.independent_clone() }
+    }
+}
+
+#[cfg(feature = "clone-behavior")]
+impl<S: Speed, C: MirroredClone<S>> MirroredClone<S> for Reversed<C> {
+    #[inline]
+    fn mirrored_clone(&self) -> Self {
+        Self { cmp: self.cmp.mirrored_clone() }
+    }
+}
+
+/// A [`Comparator`] which compares keys extracted from larger keys.
+///
+/// Created by [`ComparatorExt::map_key`], or by [`MapKey::new`].
+pub struct MapKey<C, F, Inner: ?Sized> {
+    cmp:     C,
+    extract: F,
+    _inner:  PhantomData<fn(&Inner)>,
+}
+
+impl<C, F, Inner: ?Sized> MapKey<C, F, Inner> {
+    /// Compare the `Inner` keys extracted by `extract` with `cmp`.
+    #[inline]
+    #[must_use]
+    pub const fn new(cmp: C, extract: F) -> Self {
+        Self {
+            cmp,
+            extract,
+            _inner: PhantomData,
+        }
+    }
+
+    /// Convert the adapter back into the inner comparator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.cmp
+    }
+}
+
+impl<Outer, Inner, C, F> Comparator<Outer> for MapKey<C, F, Inner>
+where
+    Outer: ?Sized,
+    Inner: ?Sized,
+    C:     Comparator<Inner>,
+    F:     Fn(&Outer) -> &Inner,
+{
+    #[inline]
+    fn cmp(&self, lhs: &Outer, rhs: &Outer) -> Ordering {
+        self.cmp.cmp((self.extract)(lhs), (self.extract)(rhs))
+    }
+}
+
+impl<C: Clone, F: Clone, Inner: ?Sized> Clone for MapKey<C, F, Inner> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cmp:     self.cmp.clone(),
+            extract: self.extract.clone(),
+            _inner:  PhantomData,
+        }
+    }
+}
+
+impl<C: Copy, F: Copy, Inner: ?Sized> Copy for MapKey<C, F, Inner> {}
+
+impl<C: Debug, F, Inner: ?Sized> Debug for MapKey<C, F, Inner> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("MapKey")
+            .field("cmp", &self.cmp)
+            .field("extract", &"..")
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`Comparator`] which uses a second comparator to break the ties of a first comparator.
+///
+/// Created by [`ComparatorExt::then`], or by [`Then::new`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Then<A, B> {
+    first:  A,
+    second: B,
+}
+
+impl<A, B> Then<A, B> {
+    /// Order keys by `first`, and break ties with `second`.
+    #[inline]
+    #[must_use]
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    /// Convert the adapter back into the two inner comparators.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<Key, A, B> Comparator<Key> for Then<A, B>
+where
+    Key: ?Sized,
+    A:   Comparator<Key>,
+    B:   Comparator<Key>,
+{
+    #[inline]
+    fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering {
+        self.first.cmp(lhs, rhs).then_with(|| self.second.cmp(lhs, rhs))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::comparator::OrdComparator;
+    use super::*;
+
+
+    #[derive(Debug)]
+    struct Event {
+        timestamp: u32,
+        id:        u8,
+    }
+
+    fn timestamp(event: &Event) -> &u32 {
+        &event.timestamp
+    }
+
+    fn id(event: &Event) -> &u8 {
+        &event.id
+    }
+
+    #[cfg(feature = "clone-behavior")]
+    #[test]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn reversed_clones() {
+        use clone_behavior::NearInstant;
+
+        let cmp = ComparatorExt::<u8>::reversed(OrdComparator);
+        let independent = IndependentClone::<NearInstant>::independent_clone(&cmp);
+        let mirrored = MirroredClone::<NearInstant>::mirrored_clone(&cmp);
+        assert_eq!(independent.cmp(&1, &2), Ordering::Greater);
+        assert_eq!(mirrored.cmp(&1, &2), Ordering::Greater);
+    }
+
+    #[test]
+    fn map_key_reversed() {
+        let cmp = OrdComparator.map_key(timestamp).reversed();
+
+        let early = Event { timestamp: 1, id: 0 };
+        let late = Event { timestamp: 5, id: 0 };
+        assert_eq!(cmp.cmp(&early, &late), Ordering::Greater);
+        assert_eq!(cmp.cmp(&late, &early), Ordering::Less);
+        assert_eq!(cmp.cmp(&late, &late), Ordering::Equal);
+    }
+
+    #[test]
+    fn then_breaks_ties() {
+        let cmp = OrdComparator.map_key(timestamp).then(OrdComparator.map_key(id).reversed());
+
+        let mut events = [
+            Event { timestamp: 2, id: 1 },
+            Event { timestamp: 1, id: 1 },
+            Event { timestamp: 2, id: 3 },
+            Event { timestamp: 1, id: 2 },
+        ];
+        events.sort_by(|lhs, rhs| cmp.cmp(lhs, rhs));
+
+        let order = events.map(|event| (event.timestamp, event.id));
+        assert_eq!(order, [(1, 2), (1, 1), (2, 3), (2, 1)]);
+    }
+}
@@ -0,0 +1,177 @@
+use core::{cell::Cell, cmp::Ordering};
+
+use crate::comparator::Comparator;
+
+
+/// A [`Comparator`] adapter which counts how many comparisons have been performed, for the
+/// purpose of profiling.
+///
+/// The count is stored in a [`Cell`], so `CountingComparator` is not [`Sync`]. Clones of a
+/// `CountingComparator` start with the same count as the source comparator, but count
+/// independently afterwards.
+#[derive(Default, Debug, Clone)]
+pub struct CountingComparator<C> {
+    cmp:   C,
+    count: Cell<usize>,
+}
+
+impl<C> CountingComparator<C> {
+    /// Wrap the provided comparator, with an initial count of zero.
+    #[inline]
+    #[must_use]
+    pub const fn new(cmp: C) -> Self {
+        Self {
+            cmp,
+            count: Cell::new(0),
+        }
+    }
+
+    /// Get the number of comparisons performed since this comparator was created, or since the
+    /// count was last reset.
+    #[inline]
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.count.get()
+    }
+
+    /// Reset the number of comparisons performed to zero.
+    #[inline]
+    pub fn reset_count(&self) {
+        self.count.set(0);
+    }
+
+    /// Get a reference to the inner comparator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &C {
+        &self.cmp
+    }
+
+    /// Convert the adapter back into the inner comparator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.cmp
+    }
+}
+
+impl<Key: ?Sized, C: Comparator<Key>> Comparator<Key> for CountingComparator<C> {
+    /// Compare the keys with the inner comparator, and increment the comparison count.
+    #[inline]
+    fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering {
+        self.count.set(self.count.get().saturating_add(1));
+        self.cmp.cmp(lhs, rhs)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::comparator::OrdComparator;
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn counts_and_resets() {
+        let cmp = CountingComparator::new(OrdComparator);
+        assert_eq!(cmp.count(), 0);
+
+        assert_eq!(cmp.cmp(&1, &2), Ordering::Less);
+        assert_eq!(cmp.cmp(&2, &2), Ordering::Equal);
+        assert_eq!(cmp.count(), 2);
+
+        cmp.reset_count();
+        assert_eq!(cmp.count(), 0);
+        assert_eq!(cmp.cmp(&3, &2), Ordering::Greater);
+        assert_eq!(cmp.count(), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn counts_merge_comparisons() {
+        use alloc::vec;
+        use crate::{
+            cursor::CursorLendingIterator, lending_iterator_support::{LendItem, LentItem},
+            merging_iter::MergingIter, seekable::{ItemToKey, Seekable}, test_iter::TestIter,
+        };
+
+        /// Since `TestIter` only implements `Seekable<u8, OrdComparator>`, this wrapper is needed
+        /// to merge `TestIter`s with a `CountingComparator`.
+        struct Counted<'a>(TestIter<'a>);
+
+        impl<'lend> LendItem<'lend> for Counted<'_> {
+            type Item = &'lend u8;
+        }
+
+        impl CursorLendingIterator for Counted<'_> {
+            fn valid(&self) -> bool {
+                self.0.valid()
+            }
+
+            fn next(&mut self) -> Option<LentItem<'_, Self>> {
+                self.0.next()
+            }
+
+            fn current(&self) -> Option<LentItem<'_, Self>> {
+                self.0.current()
+            }
+
+            fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+                self.0.prev()
+            }
+        }
+
+        impl ItemToKey<u8> for Counted<'_> {
+            fn item_to_key(item: LentItem<'_, Self>) -> &'_ u8 {
+                item
+            }
+        }
+
+        impl Seekable<u8, CountingComparator<OrdComparator>> for Counted<'_> {
+            fn reset(&mut self) {
+                self.0.reset();
+            }
+
+            fn seek(&mut self, min_bound: &u8) {
+                self.0.seek(min_bound);
+            }
+
+            fn seek_before(&mut self, strict_upper_bound: &u8) {
+                self.0.seek_before(strict_upper_bound);
+            }
+
+            fn seek_to_first(&mut self) {
+                self.0.seek_to_first();
+            }
+
+            fn seek_to_last(&mut self) {
+                self.0.seek_to_last();
+            }
+        }
+
+        let data_one: &[u8] = [0, 2, 4, 6].as_slice();
+        let data_two: &[u8] = [1, 3, 5].as_slice();
+        let mut merging = MergingIter::new(
+            vec![
+                Counted(TestIter::new(data_one).unwrap()),
+                Counted(TestIter::new(data_two).unwrap()),
+            ],
+            CountingComparator::new(OrdComparator),
+        );
+        assert_eq!(merging.comparator().count(), 0);
+
+        merging.next();
+        let after_first = merging.comparator().count();
+        assert!(after_first > 0);
+
+        while merging.next().is_some() {}
+        let after_all = merging.comparator().count();
+        assert!(after_all > after_first);
+
+        merging.comparator().reset_count();
+        merging.prev();
+        assert!(merging.comparator().count() > 0);
+    }
+}
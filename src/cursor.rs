@@ -2,6 +2,9 @@ use crate::{
     lending_iterator_support::{LendItem, LentItem},
     pooled::{OutOfBuffers, PooledIterator},
 };
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
 #[cfg(feature = "lender")]
 use crate::lender_adapter::LenderAdapter;
 #[cfg(feature = "lending-iterator")]
@@ -91,6 +94,17 @@ pub trait CursorLendingIterator: for<'a> LendItem<'a> {
     /// iteration, so prefer to not use `prev`.
     fn prev(&mut self) -> Option<LentItem<'_, Self>>;
 
+    /// Determine whether every entry of the collection is known to have a distinct key.
+    ///
+    /// If `true`, adapters which skip entries with duplicate keys may pass entries through
+    /// unchanged. Returning `false` is always correct; the default implementation does so, as
+    /// uniqueness is generally not known.
+    #[inline]
+    #[must_use]
+    fn keys_are_unique(&self) -> bool {
+        false
+    }
+
     /// Convert the `CursorLendingIterator` into a [`lender::Lender`] lending iterator.
     ///
     /// The seekability and access to cursor methods are preserved, though none of the
@@ -195,4 +209,50 @@ pub trait CursorPooledIterator: PooledIterator {
     /// # Errors
     /// Returns an error if no buffers were available.
     fn try_prev(&mut self) -> Result<Option<Self::Item>, OutOfBuffers>;
+
+    /// Box the iterator into a [`BoxedPooledIter`] trait object, erasing its concrete type.
+    ///
+    /// This allows pooled iterators over different kinds of sources to be stored together, so
+    /// long as they have the same `Item` type.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    #[must_use]
+    fn into_boxed<'a>(self) -> BoxedPooledIter<'a, Self::Item> where Self: Sized + 'a {
+        Box::new(self)
+    }
+}
+
+/// A boxed [`CursorPooledIterator`] trait object, whose concrete type has been erased.
+///
+/// See [`CursorPooledIterator::into_boxed`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type BoxedPooledIter<'a, Item> = Box<dyn CursorPooledIterator<Item = Item> + 'a>;
+
+/// Move forwards through the rest of the collection, calling `f` with the iterator at each
+/// entry.
+///
+/// If the iterator is [valid], `f` is first called at the current entry; otherwise, iteration
+/// starts from the first entry of the collection. The iterator is valid whenever `f` is called,
+/// and is `!valid()` afterwards.
+///
+/// `f` takes the iterator rather than its current item, so that `f` may also use other state of
+/// the iterator, and so that the lifetime of the item need not be higher-ranked.
+///
+/// [valid]: CursorLendingIterator::valid
+#[expect(clippy::redundant_pub_crate, reason = "emphasize that this is internal")]
+pub(crate) fn drain_from_current<I, F>(iter: &mut I, mut f: F)
+where
+    I: ?Sized + CursorLendingIterator,
+    F: FnMut(&I),
+{
+    if !iter.valid() {
+        iter.next();
+    }
+
+    while iter.valid() {
+        f(iter);
+        iter.next();
+    }
 }
@@ -0,0 +1,133 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+
+#[cfg(feature = "futures")]
+use crate::cursor_stream::CursorStream;
+use crate::cursor::{drain_from_current, CursorLendingIterator};
+use crate::lending_iterator_support::LentItem;
+
+
+/// Extra provided methods for [`CursorLendingIterator`]s.
+///
+/// All implementations are automatically provided by a blanket impl.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub trait CursorLendingIteratorExt: CursorLendingIterator {
+    /// Clone the current item of the iterator into the provided buffer, returning `true` if
+    /// the iterator is [valid] (and thus had a current item to clone).
+    ///
+    /// If the iterator is not valid, then `buf` is left unchanged and `false` is returned.
+    ///
+    /// This gives callers control over allocation when they occasionally need an owned copy of
+    /// an item; the buffer can be reused across calls.
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    fn clone_current_into<Borrowed>(&self, buf: &mut Borrowed::Owned) -> bool
+    where
+        Borrowed:                         ?Sized + ToOwned,
+        for<'lend> LentItem<'lend, Self>: Borrow<Borrowed>,
+    {
+        if let Some(item) = self.current() {
+            item.borrow().clone_into(buf);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move forwards through the rest of the collection, and return the sum of the byte lengths
+    /// of the items, such as for memory accounting.
+    ///
+    /// For a [`MergingIter`], this is the total payload size of the union of its sources.
+    ///
+    /// This consumes the rest of the iterator: if the iterator is [valid], the current item is
+    /// the first item counted; otherwise, counting starts from the first entry of the
+    /// collection. Afterwards, the iterator is `!valid()`.
+    ///
+    /// [`MergingIter`]: crate::MergingIter
+    /// [valid]: CursorLendingIterator::valid
+    fn total_byte_size(&mut self) -> usize
+    where
+        for<'lend> LentItem<'lend, Self>: AsRef<[u8]>,
+    {
+        let mut total = 0;
+        drain_from_current(self, |this| {
+            total += this.current().map_or(0, |item| item.as_ref().len());
+        });
+        total
+    }
+
+    /// Convert the iterator into a [`Stream`] of owned copies of its items, for composition
+    /// with async stream combinators.
+    ///
+    /// The stream polls `next` synchronously and is always ready. See [`CursorStream`] for more.
+    ///
+    /// [`Stream`]: futures_core::Stream
+    #[cfg(feature = "futures")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "futures")))]
+    #[inline]
+    #[must_use]
+    fn into_stream<Borrowed>(self) -> CursorStream<Self, Borrowed>
+    where
+        Self:                             Sized,
+        Borrowed:                         ?Sized + ToOwned,
+        for<'lend> LentItem<'lend, Self>: Borrow<Borrowed>,
+    {
+        CursorStream::new(self)
+    }
+}
+
+impl<I: ?Sized + CursorLendingIterator> CursorLendingIteratorExt for I {}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{comparator::OrdComparator, merging_iter::MergingIter, seekable::Seekable as _};
+    use crate::{sorted_slice_cursor::SortedSliceCursor, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn clone_into_preallocated() {
+        let data: &[u8] = [3, 5, 7].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+        let mut buf: u8 = 0;
+
+        assert!(!iter.clone_current_into::<u8>(&mut buf));
+        assert_eq!(buf, 0);
+
+        iter.next();
+        assert!(iter.clone_current_into::<u8>(&mut buf));
+        assert_eq!(buf, 3);
+
+        iter.seek(&6);
+        assert!(iter.clone_current_into::<u8>(&mut buf));
+        assert_eq!(buf, 7);
+
+        iter.next();
+        assert!(!iter.clone_current_into::<u8>(&mut buf));
+        assert_eq!(buf, 7);
+    }
+
+    #[test]
+    fn byte_size_of_merged_union() {
+        let first: [&[u8]; 3] = [b"a", b"ccc", b"eeee"];
+        let second: [&[u8]; 2] = [b"bb", b"dddddd"];
+        let mut merge = MergingIter::new(
+            vec![
+                SortedSliceCursor::new(first.as_slice(), OrdComparator),
+                SortedSliceCursor::new(second.as_slice(), OrdComparator),
+            ],
+            OrdComparator,
+        );
+
+        assert_eq!(merge.total_byte_size(), 16);
+        assert!(!merge.valid());
+
+        // Counting starts from the current entry.
+        merge.seek(b"d".as_slice());
+        assert_eq!(merge.total_byte_size(), 10);
+    }
+}
@@ -0,0 +1,142 @@
+use core::{borrow::Borrow, marker::PhantomData, pin::Pin, task::{Context, Poll}};
+use alloc::borrow::ToOwned;
+
+use futures_core::{FusedStream, Stream};
+
+use crate::{cursor::CursorLendingIterator, lending_iterator_support::LentItem};
+
+
+/// A [`Stream`] over owned copies of the items of a [`CursorLendingIterator`].
+///
+/// Each poll moves the inner iterator forwards with `next`, synchronously, and yields an owned
+/// copy of its item; since the inner iterator is in memory, the stream is always ready and never
+/// returns [`Poll::Pending`]. This allows sorted output, such as that of a [`MergingIter`], to be
+/// composed with async stream combinators.
+///
+/// The stream starts at the entry which the iterator would move to with `next`: the entry after
+/// its current entry if it is [valid], and otherwise the first entry of the collection. Once the
+/// iterator reaches the end of its collection, the stream terminates, and does not wrap around.
+///
+/// Created by [`CursorLendingIteratorExt::into_stream`].
+///
+/// [`CursorLendingIteratorExt::into_stream`]: crate::CursorLendingIteratorExt::into_stream
+/// [`MergingIter`]: crate::MergingIter
+/// [valid]: CursorLendingIterator::valid
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures")))]
+pub struct CursorStream<I, Borrowed: ?Sized> {
+    iter:       I,
+    terminated: bool,
+    _borrowed:  PhantomData<fn(&Borrowed)>,
+}
+
+impl<I, Borrowed: ?Sized> CursorStream<I, Borrowed> {
+    /// Wrap the provided iterator.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(iter: I) -> Self {
+        Self {
+            iter,
+            terminated: false,
+            _borrowed:  PhantomData,
+        }
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the stream back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, Borrowed> Stream for CursorStream<I, Borrowed>
+where
+    I:                             CursorLendingIterator + Unpin,
+    Borrowed:                      ?Sized + ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    type Item = Borrowed::Owned;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.terminated {
+            return Poll::Ready(None);
+        }
+
+        let item = this.iter.next().map(|item| item.borrow().to_owned());
+        this.terminated = item.is_none();
+        Poll::Ready(item)
+    }
+}
+
+impl<I, Borrowed> FusedStream for CursorStream<I, Borrowed>
+where
+    I:                             CursorLendingIterator + Unpin,
+    Borrowed:                      ?Sized + ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    #[inline]
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use core::future;
+    use alloc::{vec, vec::Vec};
+
+    use futures_executor::block_on;
+
+    use crate::{
+        comparator::OrdComparator, cursor_ext::CursorLendingIteratorExt as _,
+        merging_iter::MergingIter, test_iter::TestIter,
+    };
+    use super::*;
+
+
+    /// Collect every item of a stream, without relying on stream combinators.
+    async fn collect<S: Stream + Unpin>(mut stream: S) -> Vec<S::Item> {
+        let mut items = Vec::new();
+        while let Some(item) = future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+            items.push(item);
+        }
+        items
+    }
+
+    #[test]
+    fn collect_merge_stream() {
+        let merge = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 4, 6]).unwrap(),
+                TestIter::new(&[2, 4, 9]).unwrap(),
+            ],
+            OrdComparator,
+        );
+        let mut stream = merge.into_stream::<u8>();
+
+        assert_eq!(block_on(collect(&mut stream)), [1, 2, 4, 4, 6, 9]);
+        assert!(stream.is_terminated());
+        // The stream does not wrap around.
+        assert!(block_on(collect(&mut stream)).is_empty());
+    }
+
+    #[test]
+    fn stream_starts_after_current_entry() {
+        let data: &[u8] = [3, 5, 7].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+        iter.next();
+
+        assert_eq!(block_on(collect(iter.into_stream::<u8>())), [5, 7]);
+    }
+}
@@ -0,0 +1,357 @@
+use core::borrow::Borrow;
+use alloc::borrow::ToOwned;
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::{ItemToKey, Seekable}};
+
+
+/// An adapter for a [`CursorLendingIterator`] which, when iterating forwards, yields only the
+/// first item of each run of adjacent items with equal keys.
+///
+/// Keys are considered equal if the provided comparator says so; the keys need not implement
+/// [`PartialEq`] or [`Eq`].
+///
+/// This adapter only supports forwards iteration. An owned copy of the most recently yielded key
+/// is kept, in order to skip over the remaining items with that key. If the adapter was created
+/// with [`DedupIter::new_sorted_by`] and the inner iterator reports that its [keys are unique],
+/// no key is copied, and items are passed through unchanged.
+///
+/// [keys are unique]: CursorLendingIterator::keys_are_unique
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct DedupIter<I, Key: ?Sized + ToOwned, Cmp> {
+    iter:         I,
+    cmp:          Cmp,
+    last_key:     LastKey<Key::Owned>,
+    /// Whether `cmp` is known to be the comparator `iter` is sorted by, in which case `iter`'s
+    /// [`keys_are_unique`](CursorLendingIterator::keys_are_unique) applies to `cmp`.
+    trust_unique: bool,
+}
+
+impl<I, Key, Cmp> DedupIter<I, Key, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: ?Sized + ToOwned,
+    Cmp: Comparator<Key>,
+{
+    /// Wrap the provided iterator, whose keys are compared with `cmp`.
+    ///
+    /// `cmp` need not be the comparator the iterator is sorted by; for instance, it may consider
+    /// more keys equal. If the iterator is sorted according to `cmp`, then every key is yielded
+    /// exactly once when iterating forwards over the whole collection.
+    #[must_use]
+    pub fn new(iter: I, cmp: Cmp) -> Self {
+        let mut this = Self {
+            iter,
+            cmp,
+            last_key:     LastKey::new(),
+            trust_unique: false,
+        };
+        this.last_key.record::<Key, _>(&this.iter, false);
+        this
+    }
+
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    /// Get the current value the iterator is at.
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, I>> {
+        self.iter.current()
+    }
+
+    /// Move the iterator forwards to the first item whose key is not equal to the current key,
+    /// and return that item.
+    ///
+    /// As with [`CursorLendingIterator::next()`], if the iterator is not valid, it moves to the
+    /// first entry of the collection; and if there is no such item, the iterator becomes
+    /// `!valid()` and `None` is returned.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, I>> {
+        let cmp = &self.cmp;
+        self.last_key.next_distinct::<Key, _, _>(
+            &mut self.iter,
+            |_, lhs, rhs| keys_equal(cmp, lhs, rhs),
+            self.trust_unique,
+        )
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, Key, Cmp> DedupIter<I, Key, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized + ToOwned,
+    Cmp: Comparator<Key>,
+{
+    /// Wrap the provided iterator, whose keys are compared with `cmp`, which must behave
+    /// identically to the comparator that the iterator is sorted and seeks by.
+    ///
+    /// Since `cmp` is then the iterator's own comparator, if the iterator reports that its
+    /// [keys are unique], items are passed through unchanged, without copying any key. Otherwise,
+    /// this is the same as [`DedupIter::new`].
+    ///
+    /// [keys are unique]: CursorLendingIterator::keys_are_unique
+    #[must_use]
+    pub fn new_sorted_by(iter: I, cmp: Cmp) -> Self {
+        let mut this = Self {
+            iter,
+            cmp,
+            last_key:     LastKey::new(),
+            trust_unique: true,
+        };
+        this.last_key.record::<Key, _>(&this.iter, true);
+        this
+    }
+
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`.
+    ///
+    /// See [`Seekable::seek`].
+    pub fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.last_key.record::<Key, _>(&self.iter, self.trust_unique);
+    }
+
+    /// Move the iterator to the smallest key in the collection.
+    ///
+    /// See [`Seekable::seek_to_first`].
+    pub fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.last_key.record::<Key, _>(&self.iter, self.trust_unique);
+    }
+
+    /// Reset the iterator to its initial position, before the first entry and after the last
+    /// entry (if there are any entries in the collection).
+    ///
+    /// See [`Seekable::reset`].
+    pub fn reset(&mut self) {
+        self.iter.reset();
+        self.last_key.clear();
+    }
+}
+
+/// The most recently yielded key of an adapter which skips items whose keys equal that key,
+/// shared by [`DedupIter`] and [`DistinctKeysIter`].
+///
+/// [`DistinctKeysIter`]: crate::DistinctKeysIter
+#[expect(clippy::redundant_pub_crate, reason = "emphasize that this is internal")]
+#[derive(Debug, Clone)]
+pub(crate) struct LastKey<OwnedKey>(Option<OwnedKey>);
+
+impl<OwnedKey> LastKey<OwnedKey> {
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new() -> Self {
+        Self(None)
+    }
+
+    /// Forget the recorded key.
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.0 = None;
+    }
+
+    /// Determine whether a key is recorded.
+    #[cfg(test)]
+    #[inline]
+    #[must_use]
+    pub(crate) const fn is_recorded(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Record an owned copy of `iter`'s current key, reusing the previous copy's buffer.
+    ///
+    /// Nothing is recorded if `iter` is `!valid()`, or if `trust_unique` is set and `iter`
+    /// reports that its keys are unique, in which case no item needs to be skipped.
+    pub(crate) fn record<Key, I>(&mut self, iter: &I, trust_unique: bool)
+    where
+        Key: ?Sized + ToOwned<Owned = OwnedKey>,
+        I:   CursorLendingIterator + ItemToKey<Key>,
+    {
+        let current = iter.current().filter(|_| !(trust_unique && iter.keys_are_unique()));
+
+        if let Some(item) = current {
+            let key = I::item_to_key(item);
+
+            if let Some(last_key) = &mut self.0 {
+                key.clone_into(last_key);
+            } else {
+                self.0 = Some(key.to_owned());
+            }
+        } else {
+            self.0 = None;
+        }
+    }
+
+    /// Move `iter` forwards to the first item whose key is not equal to the recorded key,
+    /// according to `keys_equal`, and record and return that item's key.
+    ///
+    /// `keys_equal` is given `iter` as well as the two keys, so that a comparator stored in
+    /// `iter` can be used.
+    pub(crate) fn next_distinct<'a, Key, I, Equal>(
+        &mut self,
+        iter:         &'a mut I,
+        keys_equal:   Equal,
+        trust_unique: bool,
+    ) -> Option<LentItem<'a, I>>
+    where
+        Key:      ?Sized + ToOwned<Owned = OwnedKey>,
+        OwnedKey: Borrow<Key>,
+        I:        CursorLendingIterator + ItemToKey<Key>,
+        Equal:    Fn(&I, &Key, &Key) -> bool,
+    {
+        loop {
+            iter.next();
+            let Some(item) = iter.current() else {
+                self.0 = None;
+                return None;
+            };
+
+            let key = I::item_to_key(item);
+            let is_duplicate = self.0.as_ref().is_some_and(|last_key| {
+                keys_equal(iter, last_key.borrow(), key)
+            });
+
+            if !is_duplicate {
+                break;
+            }
+        }
+
+        self.record::<Key, I>(iter, trust_unique);
+        iter.current()
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use core::cmp::Ordering;
+
+    use crate::{comparator::OrdComparator, indexed::IndexedCursor as _};
+    use crate::{sorted_slice_cursor::SortedSliceCursor, test_iter::TestIter};
+    use super::*;
+
+
+    /// A key type which deliberately implements neither `PartialEq` nor `Eq`.
+    #[derive(Debug, Clone)]
+    struct Version {
+        major: u8,
+        label: &'static str,
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByMajor;
+
+    impl Comparator<Version> for ByMajor {
+        fn cmp(&self, lhs: &Version, rhs: &Version) -> Ordering {
+            lhs.major.cmp(&rhs.major)
+        }
+    }
+
+    #[test]
+    fn dedup_without_eq() {
+        static DATA: [Version; 6] = [
+            Version { major: 1, label: "a" },
+            Version { major: 1, label: "b" },
+            Version { major: 2, label: "c" },
+            Version { major: 3, label: "d" },
+            Version { major: 3, label: "e" },
+            Version { major: 3, label: "f" },
+        ];
+
+        let mut iter = DedupIter::new(SortedSliceCursor::new(DATA.as_slice(), ByMajor), ByMajor);
+
+        for label in ["a", "c", "d"] {
+            assert_eq!(iter.next().unwrap().label, label);
+        }
+        assert!(iter.next().is_none());
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn dedup_and_seek() {
+        let data: &[u8] = [0, 0, 1, 2, 2, 2, 5, 5].as_slice();
+        let mut iter = DedupIter::new(TestIter::new(data).unwrap(), OrdComparator);
+
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.inner().current_index(), Some(3));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+
+        iter.seek(&2);
+        assert_eq!(iter.current(), Some(&2));
+        assert_eq!(iter.next(), Some(&5));
+
+        iter.seek_to_first();
+        assert_eq!(iter.next(), Some(&1));
+
+        iter.reset();
+        assert_eq!(iter.next(), Some(&0));
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn unique_source_is_not_buffered() {
+        let data: &[u8] = [0, 1, 2, 5].as_slice();
+        let mut iter = DedupIter::new_sorted_by(TestIter::new(data).unwrap(), OrdComparator);
+        assert!(iter.inner().keys_are_unique());
+
+        for expected in [0, 1, 2, 5] {
+            assert_eq!(iter.next(), Some(&expected));
+            assert!(!iter.last_key.is_recorded());
+        }
+        assert_eq!(iter.next(), None);
+
+        iter.seek(&2);
+        assert_eq!(iter.current(), Some(&2));
+        assert!(!iter.last_key.is_recorded());
+
+        let duplicated: &[u8] = [0, 0, 1].as_slice();
+        let mut iter = DedupIter::new_sorted_by(TestIter::new(duplicated).unwrap(), OrdComparator);
+        assert_eq!(iter.next(), Some(&0));
+        assert!(iter.last_key.is_recorded());
+    }
+
+    #[test]
+    fn unique_source_with_coarser_comparator() {
+        use crate::comparator::UnitComparator;
+
+        // The source's keys are unique under its own order, but not under `UnitComparator`.
+        let data: &[u8] = [0, 1, 2].as_slice();
+        let mut iter = DedupIter::new(TestIter::new(data).unwrap(), UnitComparator);
+        assert!(iter.inner().keys_are_unique());
+
+        assert_eq!(iter.next(), Some(&0));
+        assert!(iter.last_key.is_recorded());
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,215 @@
+use core::marker::PhantomData;
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+    seekable_iterators::SeekableLendingIterator,
+};
+
+
+/// A [`SeekableLendingIterator`] over the items of one sorted source, `A`, whose keys are not
+/// present in another sorted source, `B`.
+///
+/// Items are lent from `A`; if `A` has several items with a key absent from `B`, each of them is
+/// yielded. Both sources must be sorted by the provided comparator (and their own comparators
+/// must behave identically to it); otherwise, the output is unspecified.
+///
+/// Each item of `A` is checked by seeking `B` to its key, in whichever direction the iterator
+/// moves.
+///
+/// The position of `B` is an implementation detail, and `B` should not be relied upon to be at
+/// any particular position.
+#[derive(Debug, Clone)]
+pub struct DifferenceIter<A, B, Cmp, Key: ?Sized> {
+    left:  A,
+    right: B,
+    cmp:   Cmp,
+    _key:  PhantomData<Key>,
+}
+
+impl<A, B, Cmp, Key> DifferenceIter<A, B, Cmp, Key>
+where
+    A:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    B:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    /// Create an iterator over the items of `left` whose keys are not present in `right`.
+    ///
+    /// The new iterator is `!valid()`, regardless of the positions of `left` and `right`.
+    #[must_use]
+    pub fn new(mut left: A, right: B, cmp: Cmp) -> Self {
+        left.reset();
+        Self {
+            left,
+            right,
+            cmp,
+            _key: PhantomData,
+        }
+    }
+
+    /// Get the comparator used to compare the keys of the two sources.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Convert the iterator back into the two sources.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (A, B) {
+        (self.left, self.right)
+    }
+
+    /// Determine whether `left`'s current key is present in `right`. Returns `false` if `left` is
+    /// not valid.
+    fn current_in_right(&mut self) -> bool {
+        let Some(left_item) = self.left.current() else {
+            return false;
+        };
+        let left_key = A::item_to_key(left_item);
+
+        self.right.seek(left_key);
+        self.right.current().is_some_and(|right_item| {
+            keys_equal(&self.cmp, B::item_to_key(right_item), left_key)
+        })
+    }
+
+    /// Move `left` forwards, if necessary, to the first item at or after its current position
+    /// whose key is absent from `right`.
+    fn align_forwards(&mut self) {
+        while self.current_in_right() {
+            self.left.next();
+        }
+    }
+
+    /// Move `left` backwards, if necessary, to the last item at or before its current position
+    /// whose key is absent from `right`.
+    fn align_backwards(&mut self) {
+        while self.current_in_right() {
+            self.left.prev();
+        }
+    }
+}
+
+impl<'lend, A, B, Cmp, Key> LendItem<'lend> for DifferenceIter<A, B, Cmp, Key>
+where
+    A:   LendItem<'lend>,
+    Key: ?Sized,
+{
+    type Item = A::Item;
+}
+
+impl<A, B, Cmp, Key> CursorLendingIterator for DifferenceIter<A, B, Cmp, Key>
+where
+    A:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    B:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.left.valid()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.left.next();
+        self.align_forwards();
+        self.left.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.left.current()
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.left.prev();
+        self.align_backwards();
+        self.left.current()
+    }
+
+    /// The items are a subset of `left`'s items, so they have unique keys if `left`'s do.
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.left.keys_are_unique()
+    }
+}
+
+impl<A, B, Cmp, Key> ItemToKey<Key> for DifferenceIter<A, B, Cmp, Key>
+where
+    A:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        A::item_to_key(item)
+    }
+}
+
+impl<A, B, Cmp, Key> Seekable<Key, Cmp> for DifferenceIter<A, B, Cmp, Key>
+where
+    A:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    B:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.left.reset();
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.left.seek(min_bound);
+        self.align_forwards();
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.left.seek_before(strict_upper_bound);
+        self.align_backwards();
+    }
+
+    fn seek_to_first(&mut self) {
+        self.left.seek_to_first();
+        self.align_forwards();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.left.seek_to_last();
+        self.align_backwards();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn difference() {
+        let left: &[u8] = &[1, 2, 3];
+        let right: &[u8] = &[2];
+        let mut iter = DifferenceIter::new(
+            TestIter::new(left).unwrap(),
+            TestIter::new(right).unwrap(),
+            OrdComparator,
+        );
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.prev(), Some(&3));
+        assert_eq!(iter.prev(), Some(&1));
+        assert_eq!(iter.prev(), None);
+
+        iter.seek(&2);
+        assert_eq!(iter.current(), Some(&3));
+        iter.seek_before(&3);
+        assert_eq!(iter.current(), Some(&1));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// An adapter which presents the current key in a transformed form, such as a formatted string,
+/// while leaving the wrapped iterator's ordering and seeking unchanged.
+///
+/// [`DisplayMapIter::display_current`] applies the provided function to the current key. Every
+/// other method, including every seek, is forwarded to the inner iterator and uses the original
+/// key type; this separates how keys are displayed from how they are ordered.
+#[derive(Debug, Clone)]
+pub struct DisplayMapIter<I, F> {
+    iter:    I,
+    display: F,
+}
+
+impl<I, F> DisplayMapIter<I, F> {
+    /// Wrap the provided iterator, displaying its keys with `display`.
+    #[inline]
+    #[must_use]
+    pub const fn new(iter: I, display: F) -> Self {
+        Self { iter, display }
+    }
+
+    /// Apply the display function to the current key, if the iterator is [valid].
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[must_use]
+    pub fn display_current<Key, D>(&self) -> Option<D>
+    where
+        I:   CursorLendingIterator + ItemToKey<Key>,
+        Key: ?Sized,
+        F:   Fn(&Key) -> D,
+    {
+        self.iter.current().map(|item| (self.display)(I::item_to_key(item)))
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: LendItem<'lend>, F> LendItem<'lend> for DisplayMapIter<I, F> {
+    type Item = I::Item;
+}
+
+impl<I: CursorLendingIterator, F> CursorLendingIterator for DisplayMapIter<I, F> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current()
+    }
+
+    #[inline]
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, F, Key> ItemToKey<Key> for DisplayMapIter<I, F>
+where
+    I:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+impl<I, F, Key, Cmp> Seekable<Key, Cmp> for DisplayMapIter<I, F>
+where
+    I:   Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+
+    #[inline]
+    fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+    }
+
+    #[inline]
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.iter.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::test_iter::TestIter;
+    use super::*;
+
+
+    #[test]
+    fn seeks_use_raw_keys() {
+        let data: &[u8] = &[1, 4, 9, 16];
+        let mut iter = DisplayMapIter::new(TestIter::new(data).unwrap(), |key: &u8| {
+            char::from_digit(u32::from(*key) % 10, 10)
+        });
+
+        assert_eq!(iter.display_current(), None);
+
+        iter.seek(&10);
+        assert_eq!(iter.current(), Some(&16));
+        assert_eq!(iter.display_current(), Some(Some('6')));
+
+        iter.seek_before(&9);
+        assert_eq!(iter.display_current(), Some(Some('4')));
+        assert_eq!(iter.next(), Some(&9));
+        assert_eq!(iter.display_current(), Some(Some('9')));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn formatted_strings() {
+        use alloc::{format, string::String};
+
+        let data: &[u8] = &[7, 42, 255];
+        let mut iter = DisplayMapIter::new(TestIter::new(data).unwrap(), |key: &u8| -> String {
+            format!("{key:#04x}")
+        });
+
+        iter.seek(&8);
+        assert_eq!(iter.current(), Some(&42));
+        assert_eq!(iter.display_current().as_deref(), Some("0x2a"));
+
+        iter.seek_to_last();
+        assert_eq!(iter.display_current().as_deref(), Some("0xff"));
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::borrow::ToOwned;
+
+use crate::{comparator::{keys_equal, Comparator}, dedup_iter::LastKey, merging_iter::MergingIter};
+use crate::{cursor::CursorLendingIterator as _, seekable::Seekable as _};
+use crate::{
+    lending_iterator_support::LentItem,
+    seekable::ItemToKey,
+    seekable_iterators::SeekableLendingIterator,
+};
+
+
+/// An adapter for [`MergingIter`] which, when iterating forwards, yields only the first item of
+/// each group of items with equal keys (according to the `MergingIter`'s comparator).
+///
+/// Among items with equal keys, the item yielded is the one from the lowest-indexed source
+/// (in the current order of the sources; see [`MergingIter::current_source`]) containing that
+/// key. If that source contains the key multiple times, its first such item is yielded.
+///
+/// This adapter only supports forwards iteration. An owned copy of the most recently yielded key
+/// is kept, in order to skip over the remaining items with that key; if the `MergingIter`
+/// reports that its [keys are unique], no key is copied, and items are passed through unchanged.
+///
+/// [keys are unique]: crate::CursorLendingIterator::keys_are_unique
+///
+/// Created by [`MergingIter::distinct_keys`].
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct DistinctKeysIter<Key: ?Sized + ToOwned, Cmp, Iter> {
+    merging:  MergingIter<Key, Cmp, Iter>,
+    /// Holds an owned copy of `merging`'s current key, if `merging` is `valid()` and its keys
+    /// are not known to be unique. Since the comparator is the `MergingIter`'s own, its
+    /// [`keys_are_unique`](crate::CursorLendingIterator::keys_are_unique) can be trusted.
+    last_key: LastKey<Key::Owned>,
+}
+
+impl<Key, Cmp, Iter> DistinctKeysIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    #[must_use]
+    pub(crate) fn new(merging: MergingIter<Key, Cmp, Iter>) -> Self {
+        let mut this = Self {
+            merging,
+            last_key: LastKey::new(),
+        };
+        this.last_key.record::<Key, _>(&this.merging, true);
+        this
+    }
+
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    ///
+    /// [`CursorLendingIterator::valid()`]: crate::CursorLendingIterator::valid
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        self.merging.valid()
+    }
+
+    /// Get the current value the iterator is at.
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    ///
+    /// [`CursorLendingIterator::current()`]: crate::CursorLendingIterator::current
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, Iter>> {
+        self.merging.current()
+    }
+
+    /// Move the iterator forwards to the first item whose key is strictly greater than the
+    /// current key, and return that item.
+    ///
+    /// As with [`CursorLendingIterator::next()`], if the iterator is not valid, it moves to the
+    /// first entry of the collection; and if there is no greater key, the iterator becomes
+    /// `!valid()` and `None` is returned.
+    ///
+    /// [`CursorLendingIterator::next()`]: crate::CursorLendingIterator::next
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, Iter>> {
+        self.last_key.next_distinct::<Key, _, _>(
+            &mut self.merging,
+            |merging, lhs, rhs| keys_equal(merging.comparator(), lhs, rhs),
+            true,
+        )
+    }
+
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`.
+    ///
+    /// See [`Seekable::seek`].
+    ///
+    /// [`Seekable::seek`]: crate::Seekable::seek
+    pub fn seek(&mut self, min_bound: &Key) {
+        self.merging.seek(min_bound);
+        self.last_key.record::<Key, _>(&self.merging, true);
+    }
+
+    /// Move the iterator to the smallest key in the collection.
+    ///
+    /// See [`Seekable::seek_to_first`].
+    ///
+    /// [`Seekable::seek_to_first`]: crate::Seekable::seek_to_first
+    pub fn seek_to_first(&mut self) {
+        self.merging.seek_to_first();
+        self.last_key.record::<Key, _>(&self.merging, true);
+    }
+
+    /// Reset the iterator to its initial position, before the first entry and after the last
+    /// entry (if there are any entries in the collection).
+    ///
+    /// See [`Seekable::reset`].
+    ///
+    /// [`Seekable::reset`]: crate::Seekable::reset
+    pub fn reset(&mut self) {
+        self.merging.reset();
+        self.last_key.clear();
+    }
+
+    /// Get a reference to the inner [`MergingIter`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &MergingIter<Key, Cmp, Iter> {
+        &self.merging
+    }
+
+    /// Convert the adapter back into the inner [`MergingIter`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> MergingIter<Key, Cmp, Iter> {
+        self.merging
+    }
+
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use alloc::vec;
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    fn overlapping_sources() {
+        let data_one:   &[u8] = [0, 2, 2, 4, 6, 8].as_slice();
+        let data_two:   &[u8] = [1, 2, 3, 4, 5].as_slice();
+        let data_three: &[u8] = [0, 4, 4, 9].as_slice();
+        let merging = MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+        let mut iter = merging.distinct_keys();
+
+        for expected in [0, 1, 2, 3, 4, 5, 6, 8, 9] {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert!(iter.next().is_none());
+        assert!(!iter.valid());
+
+        // Wraps around, like a normal cursor
+        assert_eq!(iter.next(), Some(&0));
+    }
+
+    #[test]
+    fn lowest_source_wins_ties() {
+        let data_one:   &[u8] = [2, 5].as_slice();
+        let data_two:   &[u8] = [1, 2, 3, 5].as_slice();
+        let data_three: &[u8] = [1, 3, 5].as_slice();
+        let merging = MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+        let mut iter = merging.distinct_keys();
+
+        for (key, source) in [(1, 1), (2, 0), (3, 1), (5, 0)] {
+            assert_eq!(iter.next(), Some(&key));
+            assert_eq!(iter.inner().current_source(), Some(source));
+        }
+        assert!(iter.next().is_none());
+
+        iter.seek(&3);
+        assert_eq!(iter.current(), Some(&3));
+        assert_eq!(iter.inner().current_source(), Some(1));
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.inner().current_source(), Some(0));
+
+        iter.seek_to_first();
+        assert_eq!(iter.current(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+    }
+
+    #[test]
+    fn unique_sources_pass_through() {
+        let data_one: &[u8] = [0, 1, 2].as_slice();
+        let data_two: &[u8] = [4, 6].as_slice();
+        let merging = MergingIter::new_auto(
+            vec![TestIter::new(data_two).unwrap(), TestIter::new(data_one).unwrap()],
+            OrdComparator,
+        );
+        let mut iter = merging.distinct_keys();
+
+        for expected in [0, 1, 2, 4, 6] {
+            assert_eq!(iter.next(), Some(&expected));
+            assert!(!iter.last_key.is_recorded());
+        }
+        assert!(iter.next().is_none());
+    }
+}
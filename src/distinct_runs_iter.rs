@@ -0,0 +1,174 @@
+use core::borrow::Borrow as _;
+use alloc::borrow::ToOwned;
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::seekable::ItemToKey;
+
+
+/// An adapter for a [`CursorLendingIterator`] which, when iterating forwards, yields the key of
+/// each run of adjacent items with equal keys, together with the length of that run.
+///
+/// Keys are considered equal if the provided comparator says so; the keys need not implement
+/// [`PartialEq`] or [`Eq`].
+///
+/// This adapter only supports forwards iteration, and walks over the inner iterator's items only
+/// once. Items of a run are not buffered; instead, an owned copy of the first key of the run is
+/// kept, and the run is counted while the inner iterator moves past it. As a result, once a run
+/// has been yielded, the inner iterator is at the first item of the following run (or is
+/// `!valid()`, if the run ended at the end of the collection).
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct DistinctRunsIter<I, Cmp, Key: ?Sized + ToOwned> {
+    iter:    I,
+    cmp:     Cmp,
+    /// An owned copy of the first key of the current run. Should be `Some` if and only if the
+    /// adapter is at a run.
+    run_key: Option<Key::Owned>,
+    /// The length of the current run. Should be `0` if and only if the adapter is not at a run.
+    run_len: usize,
+    /// Whether `iter` is at the first item of the run after the current run, which it reached
+    /// while counting the current run.
+    at_next: bool,
+}
+
+impl<I, Cmp, Key> DistinctRunsIter<I, Cmp, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized + ToOwned,
+{
+    /// Wrap the provided iterator, whose keys are compared with `cmp`.
+    ///
+    /// The adapter starts out not at any run; the first call to [`next`] yields the run starting
+    /// at the entry after `iter`'s current position (or the first entry, if `iter` is not
+    /// valid). If the iterator is sorted and `!valid()`, then every key is yielded exactly once
+    /// when iterating forwards over the whole collection.
+    ///
+    /// [`next`]: DistinctRunsIter::next
+    #[must_use]
+    pub const fn new(iter: I, cmp: Cmp) -> Self {
+        Self {
+            iter,
+            cmp,
+            run_key: None,
+            run_len: 0,
+            at_next: false,
+        }
+    }
+
+    /// Get the length of the current run and its first key, if the adapter is at any run.
+    #[must_use]
+    pub fn current(&self) -> Option<(usize, &Key)> {
+        let run_key = self.run_key.as_ref()?;
+        Some((self.run_len, run_key.borrow()))
+    }
+
+    /// Move forwards to the next run of items with equal keys, and return the length of that
+    /// run along with its first key.
+    ///
+    /// As with [`CursorLendingIterator::next()`], if the adapter is not at any run, it moves to
+    /// the run at the start of the collection; and if there is no next run, `None` is returned.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<(usize, &Key)> {
+        // Unless counting the previous run already moved onto this run, move onto it. If the
+        // previous run ended at the end of the collection, `iter` is `!valid()`, and there is
+        // no next run.
+        let ended = self.run_len != 0 && !self.at_next;
+        if !self.at_next && !ended {
+            self.iter.next();
+        }
+        self.at_next = false;
+        self.run_len = 0;
+
+        let Some(item) = self.iter.current().filter(|_| !ended) else {
+            self.run_key = None;
+            return None;
+        };
+
+        let key = I::item_to_key(item);
+        if let Some(run_key) = &mut self.run_key {
+            key.clone_into(run_key);
+        } else {
+            self.run_key = Some(key.to_owned());
+        }
+
+        let mut run_len = 1;
+        if let Some(run_key) = &self.run_key {
+            while self.iter.next().is_some_and(|next_item| {
+                keys_equal(&self.cmp, run_key.borrow(), I::item_to_key(next_item))
+            }) {
+                run_len += 1;
+            }
+        }
+
+        self.run_len = run_len;
+        self.at_next = self.iter.valid();
+        self.current()
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, indexed::IndexedCursor as _, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    fn run_lengths() {
+        let data: &[u8] = [1, 2, 2, 3, 3, 3].as_slice();
+        let mut iter = DistinctRunsIter::new(TestIter::new(data).unwrap(), OrdComparator);
+
+        assert_eq!(iter.next(), Some((1, &1)));
+        assert_eq!(iter.inner().current_index(), Some(1));
+        assert_eq!(iter.next(), Some((2, &2)));
+        assert_eq!(iter.inner().current_index(), Some(3));
+        assert_eq!(iter.current(), Some((2, &2)));
+        assert_eq!(iter.next(), Some((3, &3)));
+        assert!(!iter.inner().valid());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.current(), None);
+
+        // Wraps around, like a normal cursor
+        assert_eq!(iter.next(), Some((1, &1)));
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn walks_forwards_once() {
+        use crate::comparator::UnitComparator;
+
+        // With `UnitComparator`, the whole collection is one run, even though the source's keys
+        // are unique.
+        let data: &[u8] = [1, 4, 9].as_slice();
+        let mut iter = DistinctRunsIter::new(TestIter::new(data).unwrap(), UnitComparator);
+        assert!(iter.inner().keys_are_unique());
+
+        assert_eq!(iter.next(), Some((3, &1)));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = DistinctRunsIter::new(TestIter::new(data).unwrap(), OrdComparator);
+        for expected in [1, 4, 9] {
+            assert_eq!(iter.next(), Some((1, &expected)));
+        }
+        assert_eq!(iter.next(), None);
+    }
+}
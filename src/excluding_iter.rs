@@ -0,0 +1,241 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::{ItemToKey, Seekable}};
+
+
+/// An adapter for a [`CursorLendingIterator`] which, when iterating forwards, skips every item
+/// whose key is in a sorted set of excluded keys.
+///
+/// The excluded keys are provided as a slice sorted according to the comparator, and a cursor
+/// into that slice is advanced in lockstep with the inner iterator; no seeks are performed on
+/// the inner iterator. This is useful for anti-joins, such as excluding a set of keys from the
+/// output of a [`MergingIter`].
+///
+/// This adapter only supports forwards iteration. The inner iterator should be sorted according
+/// to the comparator; otherwise, excluded keys may be yielded.
+///
+/// [`MergingIter`]: crate::MergingIter
+#[derive(Debug, Clone)]
+pub struct ExcludingIter<'a, I, Key, Cmp> {
+    iter:         I,
+    cmp:          Cmp,
+    excluded:     &'a [Key],
+    /// If `iter` is `valid()`, this is the index of the first excluded key which is not `Less`
+    /// than `iter`'s current key. Otherwise, this is `0`.
+    excluded_idx: usize,
+}
+
+impl<'a, I, Key, Cmp> ExcludingIter<'a, I, Key, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+{
+    /// Wrap the provided iterator, skipping items whose keys are in `excluded`.
+    ///
+    /// `excluded` must be sorted according to `cmp`. If the iterator's current item has an
+    /// excluded key, the iterator is immediately moved forwards to the next item whose key is
+    /// not excluded.
+    #[must_use]
+    pub fn new(iter: I, cmp: Cmp, excluded: &'a [Key]) -> Self {
+        let mut this = Self {
+            iter,
+            cmp,
+            excluded,
+            excluded_idx: 0,
+        };
+        this.sync_excluded_idx();
+        this.skip_excluded();
+        this
+    }
+
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    /// Get the current value the iterator is at.
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, I>> {
+        self.iter.current()
+    }
+
+    /// Move the iterator forwards to the next item whose key is not excluded, and return that
+    /// item.
+    ///
+    /// As with [`CursorLendingIterator::next()`], if the iterator is not valid, it moves to the
+    /// first such entry of the collection; and if there is no such item, the iterator becomes
+    /// `!valid()` and `None` is returned.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, I>> {
+        self.iter.next();
+        self.skip_excluded();
+        self.iter.current()
+    }
+
+    /// Get the sorted slice of excluded keys.
+    #[inline]
+    #[must_use]
+    pub const fn excluded(&self) -> &'a [Key] {
+        self.excluded
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Set `self.excluded_idx` from scratch, based on the inner iterator's current key.
+    fn sync_excluded_idx(&mut self) {
+        self.excluded_idx = if let Some(item) = self.iter.current() {
+            let key = I::item_to_key(item);
+            self.excluded.partition_point(|excluded| {
+                self.cmp.cmp(excluded, key) == Ordering::Less
+            })
+        } else {
+            0
+        };
+    }
+
+    /// Move the inner iterator forwards until its current key is not excluded (or it becomes
+    /// `!valid()`), advancing `self.excluded_idx` along the way.
+    fn skip_excluded(&mut self) {
+        loop {
+            let Some(item) = self.iter.current() else {
+                self.excluded_idx = 0;
+                return;
+            };
+            let key = I::item_to_key(item);
+
+            while self.excluded.get(self.excluded_idx).is_some_and(|excluded| {
+                self.cmp.cmp(excluded, key) == Ordering::Less
+            }) {
+                self.excluded_idx += 1;
+            }
+
+            let is_excluded = self.excluded.get(self.excluded_idx).is_some_and(|excluded| {
+                keys_equal(&self.cmp, excluded, key)
+            });
+
+            if !is_excluded {
+                return;
+            }
+
+            self.iter.next();
+        }
+    }
+}
+
+impl<I, Key, Cmp> ExcludingIter<'_, I, Key, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Cmp: Comparator<Key>,
+{
+    /// Move the iterator to the smallest non-excluded key which is greater or equal than the
+    /// provided `min_bound`.
+    ///
+    /// See [`Seekable::seek`].
+    pub fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.sync_excluded_idx();
+        self.skip_excluded();
+    }
+
+    /// Move the iterator to the smallest non-excluded key in the collection.
+    ///
+    /// See [`Seekable::seek_to_first`].
+    pub fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.excluded_idx = 0;
+        self.skip_excluded();
+    }
+
+    /// Reset the iterator to its initial position, before the first entry and after the last
+    /// entry (if there are any entries in the collection).
+    ///
+    /// See [`Seekable::reset`].
+    pub fn reset(&mut self) {
+        self.iter.reset();
+        self.excluded_idx = 0;
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    fn exclude_and_seek() {
+        let data: &[u8] = &[0, 1, 1, 2, 4, 4, 7, 9];
+        let mut iter = ExcludingIter::new(TestIter::new(data).unwrap(), OrdComparator, &[1, 3, 4]);
+
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&7));
+        assert_eq!(iter.next(), Some(&9));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(&0));
+
+        iter.seek(&3);
+        assert_eq!(iter.current(), Some(&7));
+        iter.seek(&1);
+        assert_eq!(iter.current(), Some(&2));
+
+        iter.reset();
+        assert!(!iter.valid());
+        iter.seek_to_first();
+        assert_eq!(iter.current(), Some(&0));
+    }
+
+    #[test]
+    fn excluded_at_construction() {
+        let data: &[u8] = &[0, 1, 2];
+        let mut inner = TestIter::new(data).unwrap();
+        inner.seek_to_first();
+
+        let mut iter = ExcludingIter::new(inner, OrdComparator, &[0, 1]);
+        assert_eq!(iter.current(), Some(&2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn exclude_from_merge() {
+        use alloc::{vec, vec::Vec};
+        use crate::merging_iter::MergingIter;
+
+        let data_one: &[u8] = &[0, 2, 4, 6, 8];
+        let data_two: &[u8] = &[1, 3, 5, 7, 9];
+        let merge = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        let mut iter = ExcludingIter::new(merge, OrdComparator, &[2, 5]);
+
+        let mut yielded = Vec::new();
+        while let Some(&item) = iter.next() {
+            yielded.push(item);
+        }
+        assert_eq!(yielded, [0, 1, 3, 4, 6, 7, 8, 9]);
+    }
+}
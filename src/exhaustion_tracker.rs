@@ -0,0 +1,234 @@
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// Report whether a circular cursor became `!valid()` by running off one end of its collection,
+/// as opposed to being at its initial position.
+///
+/// Every `!valid()` cursor is at the same phantom position, before the first entry and after the
+/// last entry. This trait distinguishes how the cursor got there: whether the most recent motion
+/// moved forwards past the last entry, moved backwards past the first entry, or neither (for
+/// instance, because the cursor was just created or [reset]). In particular, this tells whether
+/// a `next` call which returned `None` hit the end of the collection, and so the following
+/// `next` will wrap around to the start.
+///
+/// Implemented by [`ExhaustionTracker`].
+///
+/// [reset]: Seekable::reset
+pub trait TracksExhaustion {
+    /// Determine whether the most recent motion moved forwards past the last entry (or found
+    /// that there was no entry to move forwards to).
+    ///
+    /// Forwards motions are `next`, `seek`, and `seek_to_first`.
+    #[must_use]
+    fn is_exhausted_forward(&self) -> bool;
+
+    /// Determine whether the most recent motion moved backwards past the first entry (or found
+    /// that there was no entry to move backwards to).
+    ///
+    /// Backwards motions are `prev`, `seek_before`, and `seek_to_last`.
+    #[must_use]
+    fn is_exhausted_backward(&self) -> bool;
+}
+
+/// Which end of the collection, if any, the most recent motion ran off of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Exhaustion {
+    None,
+    Forward,
+    Backward,
+}
+
+/// An adapter which implements [`TracksExhaustion`] for any [`CursorLendingIterator`], by
+/// recording which end (if any) each motion ran off of.
+///
+/// All other behavior is forwarded to the inner iterator.
+#[derive(Debug, Clone)]
+pub struct ExhaustionTracker<I> {
+    iter:       I,
+    exhaustion: Exhaustion,
+}
+
+impl<I> ExhaustionTracker<I> {
+    /// Wrap the provided iterator.
+    ///
+    /// Neither end is considered to have been reached, even if the iterator is `!valid()`.
+    #[inline]
+    #[must_use]
+    pub const fn new(iter: I) -> Self {
+        Self {
+            iter,
+            exhaustion: Exhaustion::None,
+        }
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: CursorLendingIterator> ExhaustionTracker<I> {
+    /// Record that a motion in the given direction was just performed.
+    fn record_motion(&mut self, direction: Exhaustion) {
+        self.exhaustion = if self.iter.valid() {
+            Exhaustion::None
+        } else {
+            direction
+        };
+    }
+}
+
+impl<I> TracksExhaustion for ExhaustionTracker<I> {
+    #[inline]
+    fn is_exhausted_forward(&self) -> bool {
+        self.exhaustion == Exhaustion::Forward
+    }
+
+    #[inline]
+    fn is_exhausted_backward(&self) -> bool {
+        self.exhaustion == Exhaustion::Backward
+    }
+}
+
+impl<'lend, I: LendItem<'lend>> LendItem<'lend> for ExhaustionTracker<I> {
+    type Item = I::Item;
+}
+
+impl<I: CursorLendingIterator> CursorLendingIterator for ExhaustionTracker<I> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next();
+        self.record_motion(Exhaustion::Forward);
+        self.iter.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current()
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev();
+        self.record_motion(Exhaustion::Backward);
+        self.iter.current()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, Key> ItemToKey<Key> for ExhaustionTracker<I>
+where
+    I:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+impl<I, Key, Cmp> Seekable<Key, Cmp> for ExhaustionTracker<I>
+where
+    I:   CursorLendingIterator + Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.exhaustion = Exhaustion::None;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.record_motion(Exhaustion::Forward);
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.iter.seek_before(strict_upper_bound);
+        self.record_motion(Exhaustion::Backward);
+    }
+
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.record_motion(Exhaustion::Forward);
+    }
+
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+        self.record_motion(Exhaustion::Backward);
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::test_iter::TestIter;
+    use super::*;
+
+
+    #[test]
+    fn fresh_versus_ran_off_end() {
+        let data: &[u8] = [1, 2].as_slice();
+        let mut iter = ExhaustionTracker::new(TestIter::new(data).unwrap());
+
+        assert!(!iter.valid());
+        assert!(!iter.is_exhausted_forward());
+        assert!(!iter.is_exhausted_backward());
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert!(!iter.is_exhausted_forward());
+        assert_eq!(iter.next(), None);
+        assert!(iter.is_exhausted_forward());
+        assert!(!iter.is_exhausted_backward());
+
+        // The next motion wraps around.
+        assert_eq!(iter.next(), Some(&1));
+        assert!(!iter.is_exhausted_forward());
+        assert_eq!(iter.prev(), None);
+        assert!(iter.is_exhausted_backward());
+
+        iter.reset();
+        assert!(!iter.is_exhausted_forward());
+        assert!(!iter.is_exhausted_backward());
+    }
+
+    #[test]
+    fn seeks_past_ends() {
+        let data: &[u8] = [1, 2].as_slice();
+        let mut iter = ExhaustionTracker::new(TestIter::new(data).unwrap());
+
+        iter.seek(&3);
+        assert!(iter.is_exhausted_forward());
+        iter.seek_before(&1);
+        assert!(iter.is_exhausted_backward());
+        iter.seek(&2);
+        assert!(!iter.is_exhausted_forward());
+        assert!(!iter.is_exhausted_backward());
+
+        let mut empty = ExhaustionTracker::new(TestIter::new(&[]).unwrap());
+        empty.seek_to_last();
+        assert!(empty.is_exhausted_backward());
+    }
+}
@@ -0,0 +1,197 @@
+use core::borrow::Borrow;
+use alloc::{borrow::ToOwned, collections::VecDeque};
+
+use crate::{cursor::CursorLendingIterator, lending_iterator_support::LentItem};
+
+
+/// One of the two read positions of a [`ForkedIter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub enum ForkHandle {
+    /// The first read position.
+    Left,
+    /// The second read position.
+    Right,
+}
+
+impl ForkHandle {
+    /// Get the index of this handle's position in `ForkedIter::positions`.
+    #[inline]
+    #[must_use]
+    const fn index(self) -> usize {
+        match self {
+            Self::Left  => 0,
+            Self::Right => 1,
+        }
+    }
+}
+
+/// An adapter which forks a single [`CursorLendingIterator`] into two independent forwards read
+/// positions, identified by a [`ForkHandle`], by buffering owned copies of its items.
+///
+/// The source is only ever moved forwards, and each of its items is copied into the buffer
+/// once. Each handle reads the same sequence of items at its own pace with
+/// [`ForkedIter::next`]; an item stays in the buffer until both handles have read it.
+///
+/// Both handles start at the entry which the source would move to with `next`: the entry after
+/// its current entry if it is [valid], and otherwise the first entry of the collection. Once the
+/// source reaches the end of its collection, the fork does not wrap around; each handle then
+/// receives `None` after reading every buffered item.
+///
+/// # Buffer growth
+/// The buffer holds every item which the leading handle has read but the lagging handle has not,
+/// and is unbounded; if one handle lags far behind the other (or is never used), the buffer grows
+/// to hold up to the entire rest of the collection.
+///
+/// [valid]: CursorLendingIterator::valid
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct ForkedIter<I, Borrowed: ?Sized + ToOwned> {
+    iter:      I,
+    /// Items read by the leading handle but not yet by the lagging handle, oldest first.
+    buffer:    VecDeque<Borrowed::Owned>,
+    /// The index in `buffer` of the next item each handle will read.
+    positions: [usize; 2],
+    /// Whether the source has reached the end of its collection.
+    exhausted: bool,
+}
+
+impl<I, Borrowed> ForkedIter<I, Borrowed>
+where
+    I:                             CursorLendingIterator,
+    Borrowed:                      ?Sized + ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<Borrowed>,
+{
+    /// Fork the provided iterator.
+    #[inline]
+    #[must_use]
+    pub const fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buffer:    VecDeque::new(),
+            positions: [0, 0],
+            exhausted: false,
+        }
+    }
+
+    /// Move the given handle one position forwards, and return the item at that position.
+    ///
+    /// If the other handle has already read the item, it is taken from the buffer; otherwise,
+    /// the source is moved forwards and its item is buffered. Returns `None` once the handle has
+    /// read every item in the rest of the collection.
+    pub fn next(&mut self, handle: ForkHandle) -> Option<&Borrowed> {
+        self.discard_read_items();
+
+        let position = self.positions.get(handle.index()).copied()?;
+        if position == self.buffer.len() {
+            if self.exhausted {
+                return None;
+            }
+            if let Some(item) = self.iter.next() {
+                self.buffer.push_back(item.borrow().to_owned());
+            } else {
+                self.exhausted = true;
+                return None;
+            }
+        }
+
+        if let Some(slot) = self.positions.get_mut(handle.index()) {
+            *slot += 1;
+        }
+        self.buffer.get(position).map(Borrow::borrow)
+    }
+
+    /// Get the number of items currently buffered, which have been read by one handle but not
+    /// the other.
+    #[inline]
+    #[must_use]
+    pub const fn buffered_len(&self) -> usize {
+        let [left, right] = self.positions;
+        left.abs_diff(right)
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    ///
+    /// The inner iterator is positioned at the last item read by the leading handle.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Drop the buffered items which both handles have read.
+    fn discard_read_items(&mut self) {
+        let [left, right] = self.positions;
+        let read_by_both = left.min(right);
+
+        self.buffer.drain(..read_by_both);
+        for position in &mut self.positions {
+            *position -= read_by_both;
+        }
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::test_iter::TestIter;
+    use super::*;
+
+
+    #[test]
+    fn handles_read_at_different_rates() {
+        let data: &[u8] = [1, 2, 3, 4, 5].as_slice();
+        let mut forked = ForkedIter::<_, u8>::new(TestIter::new(data).unwrap());
+
+        assert_eq!(forked.next(ForkHandle::Left), Some(&1));
+        assert_eq!(forked.next(ForkHandle::Left), Some(&2));
+        assert_eq!(forked.next(ForkHandle::Left), Some(&3));
+        assert_eq!(forked.buffered_len(), 3);
+
+        assert_eq!(forked.next(ForkHandle::Right), Some(&1));
+        assert_eq!(forked.buffered_len(), 2);
+
+        let mut left = Vec::new();
+        while let Some(&item) = forked.next(ForkHandle::Left) {
+            left.push(item);
+        }
+        assert_eq!(left, [4, 5]);
+        assert_eq!(forked.buffered_len(), 4);
+
+        let mut right = Vec::new();
+        while let Some(&item) = forked.next(ForkHandle::Right) {
+            right.push(item);
+        }
+        assert_eq!(right, [2, 3, 4, 5]);
+        assert_eq!(forked.buffered_len(), 0);
+
+        // Neither handle wraps around.
+        assert_eq!(forked.next(ForkHandle::Left), None);
+        assert_eq!(forked.next(ForkHandle::Right), None);
+    }
+
+    #[test]
+    fn starts_after_current_entry() {
+        let data: &[u8] = [1, 2, 3].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+        iter.next();
+
+        let mut forked = ForkedIter::<_, u8>::new(iter);
+        assert_eq!(forked.next(ForkHandle::Right), Some(&2));
+        assert_eq!(forked.next(ForkHandle::Left), Some(&2));
+        assert_eq!(forked.next(ForkHandle::Left), Some(&3));
+        assert_eq!(forked.next(ForkHandle::Left), None);
+        assert_eq!(forked.next(ForkHandle::Right), Some(&3));
+        assert_eq!(forked.next(ForkHandle::Right), None);
+    }
+}
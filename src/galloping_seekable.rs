@@ -0,0 +1,320 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator, indexed::IndexedCursor};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// An adapter which implements [`Seekable::seek`] and [`Seekable::seek_before`] by exponential
+/// ("galloping") search over the inner cursor's entries, starting from its current position.
+///
+/// To seek, the adapter steps through entries with the inner cursor's `next` (or `prev`, if the
+/// target is behind the current position), comparing only at exponentially increasing distances
+/// until it overshoots the target, and then performs a binary search over the last step by
+/// moving back and forth within it. A seek to an entry `d` positions away thus takes
+/// `O(log d)` comparisons and `O(d)` moves, which is much faster than a linear scan when
+/// comparisons are expensive, while staying cheap for nearby targets. If the cursor is not
+/// valid, the search starts from the first entry.
+///
+/// This is intended for sources, such as those backed by sequential media, whose `seek` falls
+/// back to scanning. The inner cursor's [`Seekable`] implementation is only used for
+/// [`reset`], [`seek_to_first`], and [`seek_to_last`]. The entries must be sorted according to
+/// the comparator; otherwise, seeks may position the cursor incorrectly.
+///
+/// [`reset`]: Seekable::reset
+/// [`seek_to_first`]: Seekable::seek_to_first
+/// [`seek_to_last`]: Seekable::seek_to_last
+#[derive(Debug, Clone)]
+pub struct GallopingSeekable<I, Cmp> {
+    iter: I,
+    cmp:  Cmp,
+}
+
+impl<I, Cmp> GallopingSeekable<I, Cmp> {
+    /// Wrap the provided cursor, whose entries must be sorted according to `cmp`.
+    #[inline]
+    #[must_use]
+    pub const fn new(iter: I, cmp: Cmp) -> Self {
+        Self { iter, cmp }
+    }
+
+    /// Get the comparator used for seeking.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Get a reference to the inner cursor.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner cursor.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: CursorLendingIterator, Cmp> GallopingSeekable<I, Cmp> {
+    /// Move the inner cursor one entry in the direction of the search.
+    fn advance(&mut self, forwards: bool) {
+        if forwards {
+            self.iter.next();
+        } else {
+            self.iter.prev();
+        }
+    }
+
+    /// Move the inner cursor from offset `*pos` to offset `target`, where offsets count entries
+    /// from the search's starting entry in the direction of the search.
+    fn move_to(&mut self, pos: &mut usize, target: usize, forwards: bool) {
+        while *pos < target {
+            self.advance(forwards);
+            *pos += 1;
+        }
+        while *pos > target {
+            self.advance(!forwards);
+            *pos -= 1;
+        }
+    }
+
+    /// Determine whether the search has reached or passed `key` at the current entry.
+    ///
+    /// Forwards, this is whether the entry is not `Less` than `key`; backwards, whether it is
+    /// `Less` than `key`. Past either end of the collection, the search has always finished.
+    fn reached<Key>(&self, key: &Key, forwards: bool) -> bool
+    where
+        I:   ItemToKey<Key>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        self.iter.current().is_none_or(|item| {
+            let ordering = self.cmp.cmp(I::item_to_key(item), key);
+            (ordering == Ordering::Less) != forwards
+        })
+    }
+
+    /// Move the inner cursor, in the given direction, to the first entry at which the search has
+    /// [`reached`] `key`, or past the end of the collection if there is no such entry. The
+    /// search must not have reached `key` at the current entry.
+    ///
+    /// [`reached`]: GallopingSeekable::reached
+    fn gallop<Key>(&mut self, key: &Key, forwards: bool)
+    where
+        I:   ItemToKey<Key>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        let mut pos: usize = 0;
+        // The search has not reached `key` at any offset before `lo`, and has reached it at `hi`.
+        let mut lo: usize = 1;
+        let mut step: usize = 1;
+        let mut hi = loop {
+            let probe = lo.saturating_add(step - 1);
+            while pos < probe && self.iter.valid() {
+                self.advance(forwards);
+                pos += 1;
+            }
+            if !self.iter.valid() {
+                // Moved past the end of the collection.
+                break pos;
+            }
+            if self.reached(key, forwards) {
+                break probe;
+            }
+            lo = probe + 1;
+            step = step.saturating_mul(2);
+        };
+
+        while lo < hi {
+            let mid = lo + ((hi - lo) >> 1_u8);
+            self.move_to(&mut pos, mid, forwards);
+            if self.reached(key, forwards) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        self.move_to(&mut pos, lo, forwards);
+    }
+}
+
+impl<'lend, I: LendItem<'lend>, Cmp> LendItem<'lend> for GallopingSeekable<I, Cmp> {
+    type Item = I::Item;
+}
+
+impl<I: CursorLendingIterator, Cmp> CursorLendingIterator for GallopingSeekable<I, Cmp> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current()
+    }
+
+    #[inline]
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I: IndexedCursor, Cmp> IndexedCursor for GallopingSeekable<I, Cmp> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    #[inline]
+    fn current_index(&self) -> Option<usize> {
+        self.iter.current_index()
+    }
+
+    #[inline]
+    fn seek_to_index(&mut self, index: usize) {
+        self.iter.seek_to_index(index);
+    }
+}
+
+impl<I, Cmp, Key> ItemToKey<Key> for GallopingSeekable<I, Cmp>
+where
+    I:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+impl<I, Cmp, Key> Seekable<Key, Cmp> for GallopingSeekable<I, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        if !self.iter.valid() {
+            self.iter.next();
+        }
+        let Some(item) = self.iter.current() else {
+            return;
+        };
+
+        if self.cmp.cmp(I::item_to_key(item), min_bound) == Ordering::Less {
+            self.gallop(min_bound, true);
+        } else {
+            // Find the last entry which is `Less` than `min_bound`, and move past it.
+            self.gallop(min_bound, false);
+            self.iter.next();
+        }
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.seek(strict_upper_bound);
+        self.iter.prev();
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use core::array;
+
+    use crate::{comparator::OrdComparator, counting_comparator::CountingComparator};
+    use crate::{sorted_slice_cursor::SortedSliceCursor, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn near_and_far_seeks() {
+        let data: &[u8] = &[0, 2, 2, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20];
+        let mut iter = GallopingSeekable::new(TestIter::new(data).unwrap(), OrdComparator);
+
+        for target in 0..=21 {
+            for start in [None, Some(0), Some(3), Some(7), Some(12)] {
+                iter.seek_to_index(start.unwrap_or(usize::MAX));
+                iter.seek(&target);
+                let expected = data.iter().position(|&entry| entry >= target);
+                assert_eq!(iter.current_index(), expected, "seek {target} from {start:?}");
+
+                iter.seek_to_index(start.unwrap_or(usize::MAX));
+                iter.seek_before(&target);
+                let expected = data.iter().rposition(|&entry| entry < target);
+                assert_eq!(iter.current_index(), expected, "seek_before {target} from {start:?}");
+            }
+        }
+
+        iter.seek_to_last();
+        assert_eq!(iter.current(), Some(&20));
+        iter.seek_to_first();
+        assert_eq!(iter.current(), Some(&0));
+        iter.reset();
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn fewer_comparisons_than_linear() {
+        let data: [u8; 200] = array::from_fn(|idx| u8::try_from(idx).unwrap());
+
+        let mut iter = GallopingSeekable::new(
+            SortedSliceCursor::new(data.as_slice(), CountingComparator::new(OrdComparator)),
+            CountingComparator::new(OrdComparator),
+        );
+
+        // A far seek. A linear scan would compare against each of the 181 entries up to `180`.
+        iter.seek(&180);
+        assert_eq!(iter.current(), Some(&180));
+        assert!(iter.comparator().count() <= 20, "{}", iter.comparator().count());
+
+        // A near seek, from `180`. A linear scan would need 3 comparisons.
+        iter.comparator().reset_count();
+        iter.seek(&182);
+        assert_eq!(iter.current(), Some(&182));
+        assert!(iter.comparator().count() <= 5, "{}", iter.comparator().count());
+
+        // A near seek backwards.
+        iter.comparator().reset_count();
+        iter.seek(&179);
+        assert_eq!(iter.current(), Some(&179));
+        assert!(iter.comparator().count() <= 6, "{}", iter.comparator().count());
+    }
+}
@@ -0,0 +1,398 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::Seekable};
+
+
+/// A cursor over a sorted collection whose entries can be addressed by their index in the
+/// collection, such as a cursor over a sorted slice.
+///
+/// Index `0` refers to the first entry of the collection, and index `len() - 1` refers to the
+/// last entry. Indices refer to positions in the cursor's collection, not to keys; in
+/// particular, they are unaffected by duplicate keys.
+pub trait IndexedCursor {
+    /// Get the number of entries in the collection.
+    #[must_use]
+    fn len(&self) -> usize;
+
+    /// Determine whether the collection has no entries.
+    #[inline]
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the index of the entry the cursor is currently at, or `None` if the cursor is not
+    /// [valid].
+    ///
+    /// [valid]: crate::cursor::CursorLendingIterator::valid
+    #[must_use]
+    fn current_index(&self) -> Option<usize>;
+
+    /// Move the cursor to the entry at the given index.
+    ///
+    /// If `index >= self.len()`, the cursor becomes `!valid()`, and is conceptually one position
+    /// before the first entry and one position after the last entry.
+    fn seek_to_index(&mut self, index: usize);
+
+    /// Perform a [`seek`], and return the number of single-step motions (calls to `next` or
+    /// `prev`) that would have been needed to move between the old and new positions.
+    ///
+    /// If the cursor was not valid before the seek, it is treated as being one position before
+    /// the first entry; if the cursor is not valid after the seek, it is treated as being one
+    /// position after the last entry.
+    ///
+    /// Unlike [`SeekableLendingIteratorExt::seek_counting`], which walks one entry at a time,
+    /// this computes the distance from the indices of the old and new positions, and is thus
+    /// exact and not capped.
+    ///
+    /// [`seek`]: Seekable::seek
+    /// [`SeekableLendingIteratorExt::seek_counting`]: crate::SeekableLendingIteratorExt::seek_counting
+    fn seek_counting_indexed<Key, Cmp>(&mut self, min_bound: &Key) -> usize
+    where
+        Self: Seekable<Key, Cmp>,
+        Key:  ?Sized,
+        Cmp:  ?Sized + Comparator<Key>,
+    {
+        let old_position = self.current_index().map_or(0, |idx| idx + 1);
+        self.seek(min_bound);
+        let new_position = self.current_index().map_or_else(|| self.len() + 1, |idx| idx + 1);
+
+        old_position.abs_diff(new_position)
+    }
+
+    /// Perform a [`seek_before`], and return the number of single-step motions (calls to `next`
+    /// or `prev`) that would have been needed to move between the old and new positions.
+    ///
+    /// If the cursor was not valid before the seek, it is treated as being one position after
+    /// the last entry; if the cursor is not valid after the seek, it is treated as being one
+    /// position before the first entry.
+    ///
+    /// Unlike [`SeekableLendingIteratorExt::seek_before_counting`], which walks one entry at a
+    /// time, this computes the distance from the indices of the old and new positions, and is
+    /// thus exact and not capped.
+    ///
+    /// [`seek_before`]: Seekable::seek_before
+    /// [`SeekableLendingIteratorExt::seek_before_counting`]: crate::SeekableLendingIteratorExt::seek_before_counting
+    fn seek_before_counting_indexed<Key, Cmp>(&mut self, strict_upper_bound: &Key) -> usize
+    where
+        Self: Seekable<Key, Cmp>,
+        Key:  ?Sized,
+        Cmp:  ?Sized + Comparator<Key>,
+    {
+        let old_position = self.current_index().map_or_else(|| self.len() + 1, |idx| idx + 1);
+        self.seek_before(strict_upper_bound);
+        let new_position = self.current_index().map_or(0, |idx| idx + 1);
+
+        old_position.abs_diff(new_position)
+    }
+
+    /// Find the index of the first entry for which `pred` returns `true`, and move the cursor to
+    /// that entry.
+    ///
+    /// The collection must be partitioned by `pred`: every entry for which `pred` returns `false`
+    /// must come before every entry for which it returns `true`. This mirrors
+    /// [`slice::partition_point`], except that `None` is returned (and the cursor becomes
+    /// `!valid()`) if `pred` is `false` for every entry.
+    ///
+    /// Unlike [`SeekableLendingIteratorExt::partition_point`], which walks one entry at a time,
+    /// this performs a binary search with [`seek_to_index`], calling `pred` `O(log len)` times.
+    ///
+    /// [`seek_to_index`]: IndexedCursor::seek_to_index
+    /// [`SeekableLendingIteratorExt::partition_point`]: crate::SeekableLendingIteratorExt::partition_point
+    fn partition_point_indexed<P>(&mut self, mut pred: P) -> Option<usize>
+    where
+        Self: CursorLendingIterator,
+        P:    FnMut(&LentItem<'_, Self>) -> bool,
+    {
+        let mut low = 0;
+        let mut high = self.len();
+
+        // Invariant: `pred` is `false` before `low`, and `true` at and after `high`.
+        while low < high {
+            let mid = low + ((high - low) >> 1_u8);
+            self.seek_to_index(mid);
+
+            if self.current().is_some_and(|item| pred(&item)) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        self.seek_to_index(low);
+        self.current_index()
+    }
+
+    /// Move the cursor to the first entry which is not ordered before a target, as determined by
+    /// `cmp_to_target`, and return the index of that entry.
+    ///
+    /// As with [`slice::binary_search_by`], `cmp_to_target` should return the order of an entry
+    /// relative to the target: [`Ordering::Less`] if the entry comes before the target,
+    /// [`Ordering::Equal`] if it matches, and [`Ordering::Greater`] if it comes after. This is
+    /// analogous to [`seek`], but uses the closure for a single query instead of the cursor's
+    /// comparator; if every entry is ordered before the target, `None` is returned and the cursor
+    /// becomes `!valid()`.
+    ///
+    /// This only makes sense if the closure's order is consistent with the order in which the
+    /// entries are stored: every entry for which `cmp_to_target` returns `Less` must come before
+    /// every other entry, and every entry for which it returns `Greater` must come after every
+    /// other entry. For instance, comparing only a prefix of each key, or comparing keys at a
+    /// coarser granularity, is consistent with the stored order. If the closure is inconsistent
+    /// with the stored order, the resulting position is unspecified.
+    ///
+    /// This performs a binary search with [`IndexedCursor::partition_point_indexed`], calling
+    /// `cmp_to_target` `O(log len)` times.
+    ///
+    /// [`seek`]: Seekable::seek
+    fn seek_by<F>(&mut self, mut cmp_to_target: F) -> Option<usize>
+    where
+        Self: CursorLendingIterator,
+        F:    FnMut(&LentItem<'_, Self>) -> Ordering,
+    {
+        self.partition_point_indexed(|item| cmp_to_target(item) != Ordering::Less)
+    }
+
+    /// Move the cursor to the last entry for which `pred` returns `true`, and return whether
+    /// such an entry was found.
+    ///
+    /// The collection must be partitioned by `pred`: every entry for which `pred` returns `true`
+    /// must come before every entry for which it returns `false`. If `pred` is `false` for every
+    /// entry, `false` is returned and the cursor becomes `!valid()`.
+    ///
+    /// Unlike [`SeekableLendingIteratorExt::seek_last_matching`], which walks one entry at a
+    /// time, this performs a binary search with [`IndexedCursor::partition_point_indexed`],
+    /// calling `pred` `O(log len)` times.
+    ///
+    /// [`SeekableLendingIteratorExt::seek_last_matching`]: crate::SeekableLendingIteratorExt::seek_last_matching
+    fn seek_last_matching_indexed<P>(&mut self, mut pred: P) -> bool
+    where
+        Self: CursorLendingIterator,
+        P:    FnMut(&LentItem<'_, Self>) -> bool,
+    {
+        let first_unmatched = self.partition_point_indexed(|item| !pred(item));
+
+        let last_matched = first_unmatched.unwrap_or_else(|| self.len()).checked_sub(1);
+
+        // If no entry matched, the out-of-bounds index makes the cursor `!valid()`.
+        self.seek_to_index(last_matched.unwrap_or_else(|| self.len()));
+        last_matched.is_some()
+    }
+
+    /// Move the cursor to the entry at a fractional position in the collection, such as the
+    /// position of a scrollbar.
+    ///
+    /// `percentile` is clamped to `[0.0, 1.0]` (with `NaN` treated as `0.0`), and the cursor is
+    /// moved to index `round(percentile * (len() - 1))` with [`seek_to_index`], rounding halves
+    /// upwards. `0.0` thus refers to the first entry and `1.0` to the last entry.
+    ///
+    /// Returns whether the cursor is [valid] afterwards; this is `false` only if the collection
+    /// is empty. For very large collections, the landed index is approximate, as it is computed
+    /// with floating-point arithmetic.
+    ///
+    /// [`seek_to_index`]: IndexedCursor::seek_to_index
+    /// [valid]: crate::cursor::CursorLendingIterator::valid
+    fn seek_to_percentile(&mut self, percentile: f64) -> bool {
+        let Some(last_index) = self.len().checked_sub(1) else {
+            self.seek_to_index(0);
+            return false;
+        };
+
+        let percentile = if percentile.is_nan() {
+            0.0_f64
+        } else {
+            percentile.clamp(0.0, 1.0)
+        };
+
+        #[expect(
+            clippy::as_conversions,
+            clippy::cast_possible_truncation,
+            clippy::cast_precision_loss,
+            clippy::cast_sign_loss,
+            clippy::float_arithmetic,
+            reason = "the product is in `0.0..=last_index`, and is clamped again after rounding",
+        )]
+        let index = (percentile * last_index as f64 + 0.5_f64) as usize;
+
+        self.seek_to_index(index.min(last_index));
+        self.current_index().is_some()
+    }
+
+    /// Move the cursor to a randomly-chosen entry, for sampling from the collection.
+    ///
+    /// `random_below` is called with `len()` (only if the collection is nonempty), and should
+    /// return an index chosen uniformly at random from `0..len`; the cursor is then moved to that
+    /// index with [`seek_to_index`]. For instance, with the `rand` crate, `random_below` could be
+    /// `|len| rng.random_range(..len)`.
+    ///
+    /// Returns whether the cursor is [valid] afterwards; this is `false` if the collection is
+    /// empty, or if `random_below` returned an out-of-bounds index.
+    ///
+    /// Random positioning is only supported for cursors which can address entries by index;
+    /// sorted collections without indices cannot choose a uniformly random entry without
+    /// scanning them.
+    ///
+    /// [`seek_to_index`]: IndexedCursor::seek_to_index
+    /// [valid]: crate::cursor::CursorLendingIterator::valid
+    fn seek_random_with<F>(&mut self, random_below: F) -> bool
+    where
+        F: FnOnce(usize) -> usize,
+    {
+        let len = self.len();
+        if len == 0 {
+            self.seek_to_index(0);
+            return false;
+        }
+
+        self.seek_to_index(random_below(len));
+        self.current_index().is_some()
+    }
+}
+
+
+/// A source which may be able to count the entries in a range of keys without scanning them,
+/// such as a cursor over an indexed collection.
+///
+/// This is used by [`MergingIter::count_between`] to avoid scanning its sources when every
+/// source can provide a count.
+///
+/// [`MergingIter::count_between`]: crate::MergingIter::count_between
+pub trait CountBetweenHint<Key: ?Sized, Cmp: ?Sized> {
+    /// Get the number of entries whose keys are in `lower..upper`, if it can be computed cheaply
+    /// (for instance, by index arithmetic) without moving the source.
+    ///
+    /// `cmp` must behave identically to the comparator used by the source. If `upper` is not
+    /// greater than `lower`, the count is `0`.
+    ///
+    /// The default implementation returns `None`, indicating that the entries must be scanned
+    /// to be counted.
+    #[inline]
+    #[must_use]
+    fn count_between_hint(&self, _cmp: &Cmp, _lower: &Key, _upper: &Key) -> Option<usize> {
+        None
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, cursor::CursorLendingIterator as _};
+    use crate::sorted_slice_cursor::SortedSliceCursor;
+    use super::*;
+
+
+    /// A small deterministic xorshift generator, standing in for a seeded RNG.
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn below(&mut self, bound: usize) -> usize {
+            self.0 ^= self.0 << 13_u8;
+            self.0 ^= self.0 >> 7_u8;
+            self.0 ^= self.0 << 17_u8;
+            let bound = u64::try_from(bound).unwrap();
+            usize::try_from(self.0 % bound).unwrap()
+        }
+    }
+
+    #[test]
+    fn seeded_positions_are_deterministic() {
+        let data: &[u8] = [1, 3, 5, 7, 9, 11, 13].as_slice();
+        let landed = |seed| {
+            let mut rng = XorShift(seed);
+            let mut iter = SortedSliceCursor::new(data, OrdComparator);
+            let mut positions = [0; 8];
+            for position in &mut positions {
+                assert!(iter.seek_random_with(|len| rng.below(len)));
+                *position = iter.current_index().unwrap();
+            }
+            positions
+        };
+
+        assert_eq!(landed(0x5eed), landed(0x5eed));
+        assert_ne!(landed(0x5eed), landed(0xfeed));
+    }
+
+    #[test]
+    fn uniform_coverage() {
+        let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
+        let mut iter = SortedSliceCursor::new(data, OrdComparator);
+        let mut rng = XorShift(0x5eed);
+        let mut counts = [0_u32; 10];
+
+        for _ in 0..10_000_u32 {
+            assert!(iter.seek_random_with(|len| rng.below(len)));
+            let value = iter.current().copied().unwrap();
+            if let Some(count) = counts.get_mut(usize::from(value)) {
+                *count += 1;
+            }
+        }
+
+        for count in counts {
+            assert!((800..1200).contains(&count), "count {count} is far from 1000");
+        }
+    }
+
+    #[test]
+    fn seek_by_custom_closure() {
+        let data: &[u8] = [3, 12, 17, 25, 31, 36, 44].as_slice();
+        let mut iter = SortedSliceCursor::new(data, OrdComparator);
+
+        // Seek by the tens digit alone, which is consistent with the stored order.
+        assert_eq!(iter.seek_by(|item| item.div_euclid(10).cmp(&3)), Some(4));
+        assert_eq!(iter.current(), Some(&31));
+        assert_eq!(iter.seek_by(|item| item.div_euclid(10).cmp(&2)), Some(3));
+        assert_eq!(iter.current(), Some(&25));
+
+        // The first entry after the target is chosen if no entry matches it.
+        assert_eq!(iter.seek_by(|item| item.cmp(&&20)), Some(3));
+        assert_eq!(iter.current(), Some(&25));
+
+        assert_eq!(iter.seek_by(|item| item.div_euclid(10).cmp(&5)), None);
+        assert!(!iter.valid());
+        assert_eq!(iter.seek_by(|_| Ordering::Greater), Some(0));
+        assert_eq!(iter.current(), Some(&3));
+    }
+
+    #[test]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn percentile_positions() {
+        let mut data = [0_u8; 100];
+        for (value, slot) in (0..).zip(&mut data) {
+            *slot = value;
+        }
+        let mut iter = SortedSliceCursor::new(data.as_slice(), OrdComparator);
+
+        for (percentile, expected) in [(0.0, 0), (0.5, 50), (1.0, 99), (0.25, 25), (0.004, 0)] {
+            assert!(iter.seek_to_percentile(percentile));
+            assert_eq!(iter.current(), Some(&expected));
+        }
+
+        // Out-of-range fractions are clamped.
+        assert!(iter.seek_to_percentile(-3.0));
+        assert_eq!(iter.current(), Some(&0));
+        assert!(iter.seek_to_percentile(7.5));
+        assert_eq!(iter.current(), Some(&99));
+        assert!(iter.seek_to_percentile(f64::NAN));
+        assert_eq!(iter.current(), Some(&0));
+
+        let empty: &[u8] = &[];
+        assert!(!SortedSliceCursor::new(empty, OrdComparator).seek_to_percentile(0.5));
+    }
+
+    #[test]
+    #[expect(clippy::panic, reason = "the callback must never be called")]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn empty_and_out_of_bounds() {
+        let empty: &[u8] = &[];
+        let mut iter = SortedSliceCursor::new(empty, OrdComparator);
+        assert!(!iter.seek_random_with(|_| panic!("should not sample an empty collection")));
+
+        let mut iter = SortedSliceCursor::new([1_u8].as_slice(), OrdComparator);
+        assert!(!iter.seek_random_with(|len| len));
+        assert!(!iter.valid());
+    }
+}
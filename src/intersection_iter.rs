@@ -0,0 +1,369 @@
+use core::{cmp::Ordering, marker::PhantomData};
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+    seekable_iterators::SeekableLendingIterator,
+};
+
+
+/// A [`SeekableLendingIterator`] over the items of one sorted source, `A`, whose keys are also
+/// present in another sorted source, `B`.
+///
+/// Items are lent from `A`; if `A` has several items with a key present in `B`, each of them is
+/// yielded. Both sources must be sorted by the provided comparator (and their own comparators
+/// must behave identically to it); otherwise, the output is unspecified.
+///
+/// Iterating forwards advances the two sources in lockstep, seeking each source to the other's
+/// current key, so that runs of keys present in only one source are skipped by seeking. Iterating
+/// backwards steps through `A` one item at a time, and seeks `B` to check each key.
+///
+/// The position of `B` is an implementation detail, and `B` should not be relied upon to be at
+/// any particular position.
+#[derive(Debug, Clone)]
+pub struct IntersectionIter<A, B, Cmp, Key: ?Sized> {
+    left:  A,
+    right: B,
+    cmp:   Cmp,
+    _key:  PhantomData<Key>,
+}
+
+impl<A, B, Cmp, Key> IntersectionIter<A, B, Cmp, Key>
+where
+    A:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    B:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    /// Create an iterator over the items of `left` whose keys are present in `right`.
+    ///
+    /// The new iterator is `!valid()`, regardless of the positions of `left` and `right`.
+    #[must_use]
+    pub fn new(mut left: A, right: B, cmp: Cmp) -> Self {
+        left.reset();
+        Self {
+            left,
+            right,
+            cmp,
+            _key: PhantomData,
+        }
+    }
+
+    /// Get the comparator used to compare the keys of the two sources.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Convert the iterator back into the two sources.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (A, B) {
+        (self.left, self.right)
+    }
+
+    /// Move `left` forwards, if necessary, to the first item at or after its current position
+    /// whose key is present in `right`.
+    fn align_forwards(&mut self) {
+        loop {
+            let Some(left_item) = self.left.current() else {
+                return;
+            };
+            let left_key = A::item_to_key(left_item);
+
+            self.right.seek(left_key);
+            let Some(right_item) = self.right.current() else {
+                // No key in `right` is at least `left_key`.
+                self.left.reset();
+                return;
+            };
+            let right_key = B::item_to_key(right_item);
+
+            if self.cmp.cmp(right_key, left_key) == Ordering::Equal {
+                return;
+            }
+
+            // `right_key` is greater than `left_key`, so skip everything in `left` before
+            // `right_key`.
+            self.left.seek(right_key);
+        }
+    }
+
+    /// Move `left` backwards, if necessary, to the last item at or before its current position
+    /// whose key is present in `right`.
+    fn align_backwards(&mut self) {
+        loop {
+            let Some(left_item) = self.left.current() else {
+                return;
+            };
+            let left_key = A::item_to_key(left_item);
+
+            self.right.seek(left_key);
+            if self.right.current().is_some_and(|right_item| {
+                keys_equal(&self.cmp, B::item_to_key(right_item), left_key)
+            }) {
+                return;
+            }
+
+            self.left.prev();
+        }
+    }
+}
+
+impl<'lend, A, B, Cmp, Key> LendItem<'lend> for IntersectionIter<A, B, Cmp, Key>
+where
+    A:   LendItem<'lend>,
+    Key: ?Sized,
+{
+    type Item = A::Item;
+}
+
+impl<A, B, Cmp, Key> CursorLendingIterator for IntersectionIter<A, B, Cmp, Key>
+where
+    A:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    B:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.left.valid()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.left.next();
+        self.align_forwards();
+        self.left.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.left.current()
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.left.prev();
+        self.align_backwards();
+        self.left.current()
+    }
+
+    /// The items are a subset of `left`'s items, so they have unique keys if `left`'s do.
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.left.keys_are_unique()
+    }
+}
+
+impl<A, B, Cmp, Key> ItemToKey<Key> for IntersectionIter<A, B, Cmp, Key>
+where
+    A:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        A::item_to_key(item)
+    }
+}
+
+impl<A, B, Cmp, Key> Seekable<Key, Cmp> for IntersectionIter<A, B, Cmp, Key>
+where
+    A:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    B:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.left.reset();
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.left.seek(min_bound);
+        self.align_forwards();
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.left.seek_before(strict_upper_bound);
+        self.align_backwards();
+    }
+
+    fn seek_to_first(&mut self) {
+        self.left.seek_to_first();
+        self.align_forwards();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.left.seek_to_last();
+        self.align_backwards();
+    }
+}
+
+
+/// Count the pairs of entries with equal keys in two sorted sources, without materializing their
+/// intersection.
+///
+/// Each equal pair is counted: a key which appears `m` times in `left` and `n` times in `right`
+/// contributes `m * n` to the count, which is the number of rows an inner join on that key
+/// would produce. For sources with unique keys, this is the number of keys present in both.
+///
+/// The two sources are walked forwards in lockstep, starting from their current entries (or from
+/// their first entries, if they are `!valid()`), until one of them is exhausted; the sources are
+/// consumed, and are afterwards at unspecified positions. Unlike [`IntersectionIter`], no seeks
+/// are performed, so this suits sources of similar sizes; when counting duplicates, each source
+/// moves back one entry per run of equal keys. Both sources must be sorted by the provided
+/// comparator; otherwise, the count is unspecified.
+pub fn intersection_count<A, B, Cmp, Key>(left: &mut A, right: &mut B, cmp: &Cmp) -> usize
+where
+    A:   CursorLendingIterator + ItemToKey<Key>,
+    B:   CursorLendingIterator + ItemToKey<Key>,
+    Cmp: ?Sized + Comparator<Key>,
+    Key: ?Sized,
+{
+    if !left.valid() {
+        left.next();
+    }
+    if !right.valid() {
+        right.next();
+    }
+
+    let mut count = 0;
+    loop {
+        let (Some(left_item), Some(right_item)) = (left.current(), right.current()) else {
+            return count;
+        };
+        let left_key = A::item_to_key(left_item);
+
+        match cmp.cmp(left_key, B::item_to_key(right_item)) {
+            Ordering::Less    => {
+                left.next();
+            }
+            Ordering::Greater => {
+                right.next();
+            }
+            Ordering::Equal   => {
+                let mut right_run = 1;
+                while right.next().is_some_and(|item| {
+                    keys_equal(cmp, left_key, B::item_to_key(item))
+                }) {
+                    right_run += 1;
+                }
+                // Move back to the last entry of the run, to compare its key with `left`.
+                right.prev();
+
+                let mut left_run = 1;
+                loop {
+                    let Some(next_item) = left.next() else {
+                        break;
+                    };
+                    let next_key = A::item_to_key(next_item);
+                    if !right.current().is_some_and(|item| {
+                        keys_equal(cmp, next_key, B::item_to_key(item))
+                    }) {
+                        break;
+                    }
+                    left_run += 1;
+                }
+                right.next();
+
+                count += left_run * right_run;
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::shadow_unrelated, reason = "each check in the test starts from fresh bindings")]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    fn intersection() {
+        let left: &[u8] = &[1, 2, 3];
+        let right: &[u8] = &[2, 3, 4];
+        let mut iter = IntersectionIter::new(
+            TestIter::new(left).unwrap(),
+            TestIter::new(right).unwrap(),
+            OrdComparator,
+        );
+
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.prev(), Some(&3));
+        assert_eq!(iter.prev(), Some(&2));
+        assert_eq!(iter.prev(), None);
+
+        iter.seek(&1);
+        assert_eq!(iter.current(), Some(&2));
+        iter.seek_before(&2);
+        assert!(!iter.valid());
+        iter.seek_to_last();
+        assert_eq!(iter.current(), Some(&3));
+    }
+
+    #[test]
+    fn leapfrogging_intersection() {
+        let left: &[u8] = &[0, 1, 2, 5, 6, 9, 10, 11, 15];
+        let right: &[u8] = &[3, 4, 5, 7, 8, 11, 12, 13, 14, 15, 20];
+        let mut iter = IntersectionIter::new(
+            TestIter::new(left).unwrap(),
+            TestIter::new(right).unwrap(),
+            OrdComparator,
+        );
+
+        for expected in [5, 11, 15] {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.next(), None);
+
+        for expected in [15, 11, 5] {
+            assert_eq!(iter.prev(), Some(&expected));
+        }
+        assert_eq!(iter.prev(), None);
+    }
+
+    #[test]
+    fn intersection_count_of_unique_keys() {
+        let left: &[u8] = &[1, 2, 3];
+        let right: &[u8] = &[2, 3, 4];
+
+        let count = intersection_count(
+            &mut TestIter::new(left).unwrap(),
+            &mut TestIter::new(right).unwrap(),
+            &OrdComparator,
+        );
+        assert_eq!(count, 2);
+
+        let empty: &[u8] = &[];
+        let count = intersection_count(
+            &mut TestIter::new(left).unwrap(),
+            &mut TestIter::new(empty).unwrap(),
+            &OrdComparator,
+        );
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn intersection_count_of_duplicates() {
+        let left: &[u8] = &[1, 2, 2, 3, 5, 5];
+        let right: &[u8] = &[2, 2, 2, 3, 4, 5];
+
+        // `2 * 3` pairs with key 2, one with key 3, and `2 * 1` with key 5.
+        let mut left_iter = TestIter::new(left).unwrap();
+        let mut right_iter = TestIter::new(right).unwrap();
+        assert_eq!(intersection_count(&mut left_iter, &mut right_iter, &OrdComparator), 9);
+        assert_eq!(intersection_count(&mut right_iter, &mut left_iter, &OrdComparator), 9);
+
+        // Counting starts from the current entries.
+        let mut left_iter = TestIter::new(left).unwrap();
+        left_iter.seek(&3);
+        let mut right_iter = TestIter::new(right).unwrap();
+        assert_eq!(intersection_count(&mut left_iter, &mut right_iter, &OrdComparator), 3);
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::borrow::ToOwned;
+use core::borrow::Borrow;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// An adapter for a [`CursorLendingIterator`] which caches an owned copy of the current key
+/// after every motion of the iterator.
+///
+/// This is useful for sources whose [`ItemToKey::item_to_key`] conversion is expensive, and whose
+/// current key is needed repeatedly; [`KeyCachingIter::current_key_cached`] serves the cached key
+/// without calling [`ItemToKey::item_to_key`] again.
+///
+/// The cache is refreshed after every method which may move the iterator, including all
+/// [`Seekable`] methods. The buffer holding the cached key is reused where possible.
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct KeyCachingIter<I, Key: ?Sized + ToOwned> {
+    iter:       I,
+    /// Should be `Some` if and only if `iter` is `valid()`, in which case it holds an owned copy
+    /// of `iter`'s current key.
+    cached_key: Option<Key::Owned>,
+}
+
+impl<I, Key> KeyCachingIter<I, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: ?Sized + ToOwned,
+{
+    /// Wrap the provided iterator, caching its current key (if any).
+    #[must_use]
+    pub fn new(iter: I) -> Self {
+        let mut this = Self {
+            iter,
+            cached_key: None,
+        };
+        this.refresh_key();
+        this
+    }
+
+    /// Get the key of the entry the iterator is currently at, if the iterator is [valid].
+    ///
+    /// The key was computed when the iterator was last moved, so this does not call
+    /// [`ItemToKey::item_to_key`].
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[inline]
+    #[must_use]
+    pub fn current_key_cached(&self) -> Option<&Key> {
+        self.cached_key.as_ref().map(Borrow::borrow)
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Recompute the cached key after `self.iter` has moved.
+    fn refresh_key(&mut self) {
+        if let Some(item) = self.iter.current() {
+            let key = I::item_to_key(item);
+
+            if let Some(cached_key) = &mut self.cached_key {
+                key.clone_into(cached_key);
+            } else {
+                self.cached_key = Some(key.to_owned());
+            }
+        } else {
+            self.cached_key = None;
+        }
+    }
+}
+
+impl<'lend, I, Key> LendItem<'lend> for KeyCachingIter<I, Key>
+where
+    I:   LendItem<'lend>,
+    Key: ?Sized + ToOwned,
+{
+    type Item = I::Item;
+}
+
+impl<I, Key> CursorLendingIterator for KeyCachingIter<I, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: ?Sized + ToOwned,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.cached_key.is_some()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next();
+        self.refresh_key();
+        self.iter.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current()
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev();
+        self.refresh_key();
+        self.iter.current()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, Key> ItemToKey<Key> for KeyCachingIter<I, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: ?Sized + ToOwned,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+impl<I, Key, Cmp> Seekable<Key, Cmp> for KeyCachingIter<I, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized + ToOwned,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.cached_key = None;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.refresh_key();
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.iter.seek_before(strict_upper_bound);
+        self.refresh_key();
+    }
+
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.refresh_key();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+        self.refresh_key();
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::test_iter::TestIter;
+    use super::*;
+
+
+    #[test]
+    fn cached_key_follows_motion() {
+        let data: &[u8] = [1, 3, 5, 7].as_slice();
+        let mut iter = KeyCachingIter::<_, u8>::new(TestIter::new(data).unwrap());
+
+        assert_eq!(iter.current_key_cached(), None);
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.current_key_cached(), Some(&1));
+        // Reading the cached key repeatedly does not change it
+        assert_eq!(iter.current_key_cached(), Some(&1));
+
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.current_key_cached(), Some(&3));
+
+        assert_eq!(iter.prev(), Some(&1));
+        assert_eq!(iter.current_key_cached(), Some(&1));
+
+        iter.seek(&4);
+        assert_eq!(iter.current_key_cached(), Some(&5));
+
+        iter.seek_before(&4);
+        assert_eq!(iter.current_key_cached(), Some(&3));
+
+        iter.seek_to_last();
+        assert_eq!(iter.current_key_cached(), Some(&7));
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.current_key_cached(), None);
+
+        iter.seek_to_first();
+        assert_eq!(iter.current_key_cached(), Some(&1));
+
+        iter.reset();
+        assert_eq!(iter.current_key_cached(), None);
+    }
+
+    #[test]
+    fn wraps_already_positioned_iter() {
+        let data: &[u8] = [1, 3, 5, 7].as_slice();
+        let mut inner = TestIter::new(data).unwrap();
+        inner.seek(&5);
+
+        let iter = KeyCachingIter::<_, u8>::new(inner);
+        assert_eq!(iter.current_key_cached(), Some(&5));
+        assert_eq!(iter.current(), Some(&5));
+    }
+}
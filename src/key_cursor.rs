@@ -0,0 +1,148 @@
+use crate::{cursor::CursorLendingIterator, seekable::ItemToKey};
+
+
+/// A [`CursorLendingIterator`] which can move and return only the key of an entry, without
+/// materializing the full item.
+///
+/// Every method has a provided implementation in terms of [`CursorLendingIterator`] and
+/// [`ItemToKey`], so implementing this trait is as simple as `impl KeyCursor<Key> for MyIter {}`.
+/// Sources for which extracting a key is much cheaper than materializing the full item (for
+/// instance, sources which decode values lazily) should override the methods.
+///
+/// Overriding implementations must move the cursor exactly as the corresponding
+/// [`CursorLendingIterator`] methods do.
+pub trait KeyCursor<Key: ?Sized>: CursorLendingIterator + ItemToKey<Key> {
+    /// Get the key of the current entry, if the iterator is [valid].
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[inline]
+    #[must_use]
+    fn current_key(&self) -> Option<&Key> {
+        self.current().map(Self::item_to_key)
+    }
+
+    /// Move the iterator one position forwards, and return the key of the entry at that
+    /// position. Returns `None` if the iterator was at the last entry.
+    ///
+    /// See [`CursorLendingIterator::next`].
+    #[inline]
+    fn next_key(&mut self) -> Option<&Key> {
+        self.next().map(Self::item_to_key)
+    }
+
+    /// Move the iterator one position back, and return the key of the entry at that position.
+    /// Returns `None` if the iterator was at the first entry.
+    ///
+    /// See [`CursorLendingIterator::prev`].
+    #[inline]
+    fn prev_key(&mut self) -> Option<&Key> {
+        self.prev().map(Self::item_to_key)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use crate::{comparator::OrdComparator, indexed::IndexedCursor as _};
+    use crate::{lending_iterator_support::{LendItem, LentItem}, test_iter::TestIter};
+    use crate::sorted_slice_cursor::SortedSliceCursor;
+    use super::*;
+
+
+    impl KeyCursor<u8> for TestIter<'_> {}
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn key_only_matches_full_advancement() {
+        let data: &[u8] = &[0, 1, 1, 4, 9];
+        let mut by_key = SortedSliceCursor::new(data, OrdComparator);
+        let mut by_item = SortedSliceCursor::new(data, OrdComparator);
+
+        for forwards in [true, true, true, false, true, true, true, true, false, false, true] {
+            let (key, item) = if forwards {
+                (KeyCursor::<u8>::next_key(&mut by_key).copied(), by_item.next().copied())
+            } else {
+                (KeyCursor::<u8>::prev_key(&mut by_key).copied(), by_item.prev().copied())
+            };
+            assert_eq!(key, item);
+            assert_eq!(by_key.current_index(), by_item.current_index());
+            assert_eq!(KeyCursor::<u8>::current_key(&by_key), by_item.current());
+        }
+
+        let mut test_iter = TestIter::new(data).unwrap();
+        assert_eq!(test_iter.prev_key(), Some(&9));
+    }
+
+    /// A source whose items are expensive to materialize.
+    struct LazyValues<'a> {
+        keys:         &'a [u8],
+        cursor:       Option<usize>,
+        materialized: Cell<usize>,
+    }
+
+    impl<'lend> LendItem<'lend> for LazyValues<'_> {
+        type Item = (&'lend u8, u32);
+    }
+
+    impl CursorLendingIterator for LazyValues<'_> {
+        fn valid(&self) -> bool {
+            self.cursor.is_some()
+        }
+
+        fn next(&mut self) -> Option<LentItem<'_, Self>> {
+            let next_idx = self.cursor.map_or(0, |idx| idx + 1);
+            self.cursor = (next_idx < self.keys.len()).then_some(next_idx);
+            self.current()
+        }
+
+        fn current(&self) -> Option<LentItem<'_, Self>> {
+            let key = self.keys.get(self.cursor?)?;
+            self.materialized.set(self.materialized.get() + 1);
+            Some((key, u32::from(*key) * 1000))
+        }
+
+        fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+            self.cursor = self.cursor.unwrap_or(self.keys.len()).checked_sub(1);
+            self.current()
+        }
+    }
+
+    impl ItemToKey<u8> for LazyValues<'_> {
+        fn item_to_key(item: LentItem<'_, Self>) -> &'_ u8 {
+            item.0
+        }
+    }
+
+    impl KeyCursor<u8> for LazyValues<'_> {
+        fn current_key(&self) -> Option<&u8> {
+            self.keys.get(self.cursor?)
+        }
+
+        fn next_key(&mut self) -> Option<&u8> {
+            let next_idx = self.cursor.map_or(0, |idx| idx + 1);
+            self.cursor = (next_idx < self.keys.len()).then_some(next_idx);
+            self.current_key()
+        }
+
+        fn prev_key(&mut self) -> Option<&u8> {
+            self.cursor = self.cursor.unwrap_or(self.keys.len()).checked_sub(1);
+            self.current_key()
+        }
+    }
+
+    #[test]
+    fn overridden_key_only_advancement() {
+        let keys: &[u8] = &[2, 3, 5];
+        let mut iter = LazyValues { keys, cursor: None, materialized: Cell::new(0) };
+
+        assert_eq!(iter.next_key(), Some(&2));
+        assert_eq!(iter.next_key(), Some(&3));
+        assert_eq!(iter.prev_key(), Some(&2));
+        assert_eq!(iter.materialized.get(), 0);
+
+        assert_eq!(iter.next(), Some((&3, 3000)));
+        assert_eq!(iter.materialized.get(), 1);
+    }
+}
@@ -0,0 +1,120 @@
+use core::ops::Sub;
+
+use crate::{cursor::CursorLendingIterator, seekable::ItemToKey};
+
+
+/// An [`Iterator`] adapter for a [`CursorLendingIterator`] which yields the differences between
+/// the keys of consecutive entries, such as the gaps between sorted numeric keys.
+///
+/// Each delta is `later_key - earlier_key`, computed with the key type's [`Sub`] implementation.
+/// Keys must be [`Copy`], since a copy of the previous key is kept.
+///
+/// The first key acts as the base for the first delta, and is not itself yielded; a collection
+/// with `n` entries thus yields `n - 1` deltas. If the inner iterator is valid when the adapter
+/// is created, its current key is the base; otherwise, the first entry of the collection is.
+///
+/// This adapter only supports forwards iteration. Since the inner iterator is circular, after
+/// `None` is returned, iteration starts over from the first entry of the collection.
+#[derive(Debug, Clone)]
+pub struct KeyDeltaIter<I, Key> {
+    iter:     I,
+    /// The key of the inner iterator's current entry, if it is valid.
+    prev_key: Option<Key>,
+}
+
+impl<I, Key> KeyDeltaIter<I, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: Copy,
+{
+    /// Wrap the provided iterator. If it is valid, its current key is the base of the first delta.
+    #[must_use]
+    pub fn new(iter: I) -> Self {
+        let prev_key = iter.current().map(|item| *I::item_to_key(item));
+        Self { iter, prev_key }
+    }
+
+    /// Get the key from which the next delta is measured, if there is one.
+    #[inline]
+    #[must_use]
+    pub const fn prev_key(&self) -> Option<Key> {
+        self.prev_key
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Move the inner iterator forwards, and return its new key.
+    fn next_key(&mut self) -> Option<Key> {
+        self.iter.next().map(|item| *I::item_to_key(item))
+    }
+}
+
+impl<I, Key> Iterator for KeyDeltaIter<I, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: Copy + Sub,
+{
+    type Item = Key::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let prev_key = match self.prev_key {
+            Some(prev_key) => prev_key,
+            None => self.next_key()?,
+        };
+
+        self.prev_key = self.next_key();
+        self.prev_key.map(|key| key - prev_key)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use crate::seekable::Seekable as _;
+    use super::*;
+
+
+    #[test]
+    fn gaps_between_keys() {
+        let data: &[u32] = [0, 3, 4, 10].as_slice();
+        let mut deltas = KeyDeltaIter::new(SortedSliceCursor::new(data, OrdComparator));
+
+        assert!(deltas.by_ref().eq([3, 1, 6]));
+        assert_eq!(deltas.prev_key(), None);
+        // Iteration starts over from the first entry.
+        assert_eq!(deltas.next(), Some(3));
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn valid_iterator_is_the_base() {
+        let data: &[u32] = [0, 3, 4, 10].as_slice();
+        let mut cursor = SortedSliceCursor::new(data, OrdComparator);
+        cursor.seek(&4);
+
+        let mut deltas = KeyDeltaIter::new(cursor);
+        assert_eq!(deltas.prev_key(), Some(4));
+        assert_eq!(deltas.next(), Some(6));
+        assert_eq!(deltas.next(), None);
+
+        let single: &[u32] = [7].as_slice();
+        let mut deltas = KeyDeltaIter::new(SortedSliceCursor::new(single, OrdComparator));
+        assert_eq!(deltas.next(), None);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// An adapter which seeks a source whose keys are encoded, using decoded logical keys.
+///
+/// The `encode` function maps a logical key to an encoded key; each seek translates its logical
+/// bound with `encode`, and then delegates to the inner iterator. The `decode` function maps an
+/// encoded key back to a logical key, and is used by [`current_logical_key`].
+///
+/// The adapter implements [`Seekable<LogicalKey, Cmp>`] whenever the inner iterator implements
+/// [`Seekable<EncodedKey, Cmp>`]; the comparator type must therefore be able to compare both
+/// logical and encoded keys (as [`OrdComparator`] can). Items, and their keys given by
+/// [`ItemToKey`], are those of the inner iterator, and are not decoded.
+///
+/// # Order preservation
+/// The encoding must preserve order: for any logical keys `a` and `b`, comparing `a` with `b`
+/// must give the same result as comparing `encode(a)` with `encode(b)`, and `decode` must be
+/// the inverse of `encode`. This cannot be checked, so constructing the adapter with
+/// [`new_order_preserving`] is an assertion by the caller. If the encoding does not preserve
+/// order, seeks may position the iterator incorrectly.
+///
+/// [`current_logical_key`]: KeyRemapSeekable::current_logical_key
+/// [`new_order_preserving`]: KeyRemapSeekable::new_order_preserving
+/// [`Seekable<LogicalKey, Cmp>`]: Seekable
+/// [`Seekable<EncodedKey, Cmp>`]: Seekable
+/// [`OrdComparator`]: crate::OrdComparator
+#[derive(Debug, Clone)]
+pub struct KeyRemapSeekable<I, F, G> {
+    iter:   I,
+    encode: F,
+    decode: G,
+}
+
+impl<I, F, G> KeyRemapSeekable<I, F, G> {
+    /// Wrap the provided iterator, whose keys are produced from logical keys by `encode` and
+    /// converted back by `decode`.
+    ///
+    /// By calling this function, the caller asserts that `encode` preserves the order of keys,
+    /// and that `decode` is its inverse; see the type-level documentation.
+    #[inline]
+    #[must_use]
+    pub const fn new_order_preserving(iter: I, encode: F, decode: G) -> Self {
+        Self { iter, encode, decode }
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Get the decoded logical key of the current entry, if the iterator is valid.
+    #[must_use]
+    pub fn current_logical_key<EncodedKey, LogicalKey>(&self) -> Option<LogicalKey>
+    where
+        I:          CursorLendingIterator + ItemToKey<EncodedKey>,
+        EncodedKey: ?Sized,
+        G:          Fn(&EncodedKey) -> LogicalKey,
+    {
+        self.iter.current().map(|item| (self.decode)(I::item_to_key(item)))
+    }
+}
+
+impl<'lend, I: LendItem<'lend>, F, G> LendItem<'lend> for KeyRemapSeekable<I, F, G> {
+    type Item = I::Item;
+}
+
+impl<I: CursorLendingIterator, F, G> CursorLendingIterator for KeyRemapSeekable<I, F, G> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current()
+    }
+
+    #[inline]
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, F, G, Key> ItemToKey<Key> for KeyRemapSeekable<I, F, G>
+where
+    I:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+impl<I, F, G, LogicalKey, EncodedKey, Cmp> Seekable<LogicalKey, Cmp> for KeyRemapSeekable<I, F, G>
+where
+    I:          Seekable<EncodedKey, Cmp>,
+    F:          Fn(&LogicalKey) -> EncodedKey,
+    LogicalKey: ?Sized,
+    Cmp:        ?Sized + Comparator<LogicalKey> + Comparator<EncodedKey>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+
+    #[inline]
+    fn seek(&mut self, min_bound: &LogicalKey) {
+        self.iter.seek(&(self.encode)(min_bound));
+    }
+
+    #[inline]
+    fn seek_before(&mut self, strict_upper_bound: &LogicalKey) {
+        self.iter.seek_before(&(self.encode)(strict_upper_bound));
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn logical_seeks() {
+        // Offset binary preserves order, unlike e.g. a zig-zag encoding.
+        let encode = |logical: &i8| u8::try_from(i16::from(*logical) + 128).unwrap();
+        let decode = |encoded: &u8| i8::try_from(i16::from(*encoded) - 128).unwrap();
+
+        let encoded = [-100_i8, -3, 0, 5, 90].map(|logical| encode(&logical));
+        assert!(encoded.is_sorted());
+
+        let mut iter = KeyRemapSeekable::new_order_preserving(
+            SortedSliceCursor::new(encoded.as_slice(), OrdComparator),
+            encode,
+            decode,
+        );
+
+        iter.seek(&-4_i8);
+        assert_eq!(iter.current_logical_key::<u8, _>(), Some(-3));
+        iter.seek(&0_i8);
+        assert_eq!(iter.current_logical_key::<u8, _>(), Some(0));
+        iter.seek_before(&0_i8);
+        assert_eq!(iter.current_logical_key::<u8, _>(), Some(-3));
+        iter.seek_before(&-100_i8);
+        assert!(!iter.valid());
+        iter.seek(&91_i8);
+        assert!(!iter.valid());
+
+        iter.seek_to_last();
+        assert_eq!(iter.current_logical_key::<u8, _>(), Some(90));
+        assert_eq!(iter.prev().copied().map(|key| decode(&key)), Some(5));
+    }
+}
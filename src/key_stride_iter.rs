@@ -0,0 +1,201 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::{ItemToKey, Seekable}};
+
+
+/// An adapter which yields entries of a [`Seekable`] iterator at regularly spaced key intervals,
+/// such as for downsampling a time series.
+///
+/// Starting from the first entry of the collection, each call to [`KeyStrideIter::next`] seeks
+/// forwards to the first key greater than or equal to the previously yielded entry's key plus
+/// `stride`, skipping every entry in between, and yields that entry. Unlike
+/// [`Iterator::step_by`], which counts entries, this steps by key value; gaps in the keys are
+/// thus skipped over in a single seek.
+///
+/// The bound is computed with the provided `checked_add` function only when the next entry is
+/// requested, so yielding an entry near the maximum key does not overflow. If `checked_add`
+/// returns `None`, no key can be at least the bound, and iteration ends. If the bound is not
+/// greater than the previous key according to the comparator (as with a zero stride), the
+/// adapter moves to the next entry instead of seeking, so that it always makes progress.
+///
+/// This adapter only supports forwards iteration. After `None` is returned, iteration starts
+/// over from the first entry of the collection.
+#[derive(Debug, Clone)]
+pub struct KeyStrideIter<I, Cmp, Key> {
+    iter:        I,
+    cmp:         Cmp,
+    stride:      Key,
+    checked_add: fn(&Key, &Key) -> Option<Key>,
+    /// The key of the previously yielded entry, or `None` if iteration should start from the
+    /// first entry.
+    last_key:    Option<Key>,
+}
+
+impl<I, Cmp, Key> KeyStrideIter<I, Cmp, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Cmp: Comparator<Key>,
+    Key: Clone,
+{
+    /// Wrap the provided iterator, yielding entries whose keys are at least `stride` apart
+    /// according to `cmp`.
+    ///
+    /// `checked_add` adds `stride` to a key, returning `None` on overflow; for primitive
+    /// integers, this could be `|key, stride| key.checked_add(*stride)`.
+    ///
+    /// Iteration starts from the first entry of the collection, regardless of the position of
+    /// `iter`.
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        iter:        I,
+        cmp:         Cmp,
+        stride:      Key,
+        checked_add: fn(&Key, &Key) -> Option<Key>,
+    ) -> Self {
+        Self {
+            iter,
+            cmp,
+            stride,
+            checked_add,
+            last_key: None,
+        }
+    }
+
+    /// Get the stride between the keys of yielded entries.
+    #[inline]
+    #[must_use]
+    pub const fn stride(&self) -> &Key {
+        &self.stride
+    }
+
+    /// Seek forwards to the next entry at least `stride` after the previously yielded entry (or
+    /// to the first entry, if none has been yielded), and return that entry.
+    ///
+    /// Once no such entry exists, `None` is returned, and the following call starts over from
+    /// the first entry.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, I>> {
+        if let Some(last_key) = self.last_key.take() {
+            match (self.checked_add)(&last_key, &self.stride) {
+                Some(bound) if self.cmp.cmp(&bound, &last_key) == Ordering::Greater => {
+                    self.iter.seek(&bound);
+                }
+                Some(_) => {
+                    self.iter.next();
+                }
+                None => {
+                    self.iter.reset();
+                    return None;
+                }
+            }
+        } else {
+            self.iter.seek_to_first();
+        }
+
+        self.last_key = self.iter.current().map(|item| I::item_to_key(item).clone());
+        self.iter.current()
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use super::*;
+
+
+    #[test]
+    fn stride_over_dense_keys() {
+        let mut data = [0_u32; 101];
+        for (key, slot) in (0..).zip(&mut data) {
+            *slot = key;
+        }
+        let cursor = SortedSliceCursor::new(data.as_slice(), OrdComparator);
+        let mut strided = KeyStrideIter::new(cursor, OrdComparator, 25, |key, stride| {
+            key.checked_add(*stride)
+        });
+
+        for expected in [0, 25, 50, 75, 100] {
+            assert_eq!(strided.next(), Some(&expected));
+        }
+        assert_eq!(strided.next(), None);
+        // Iteration starts over from the first entry.
+        assert_eq!(strided.next(), Some(&0));
+    }
+
+    #[test]
+    fn stride_over_gaps() {
+        let data: &[u32] = [1, 2, 3, 9, 10, 24, 40].as_slice();
+        let cursor = SortedSliceCursor::new(data, OrdComparator);
+        let mut strided = KeyStrideIter::new(cursor, OrdComparator, 5, |key, stride| {
+            key.checked_add(*stride)
+        });
+
+        for expected in [1, 9, 24, 40] {
+            assert_eq!(strided.next(), Some(&expected));
+        }
+        assert_eq!(strided.next(), None);
+    }
+
+    #[test]
+    fn stride_near_max_key() {
+        let data: &[u8] = [0, 250].as_slice();
+        let cursor = SortedSliceCursor::new(data, OrdComparator);
+        let mut strided = KeyStrideIter::new(cursor, OrdComparator, 10, |key, stride| {
+            key.checked_add(*stride)
+        });
+
+        assert_eq!(strided.next(), Some(&0));
+        assert_eq!(strided.next(), Some(&250));
+        // `250 + 10` overflows, so no further entry exists.
+        assert_eq!(strided.next(), None);
+        assert_eq!(strided.next(), Some(&0));
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn zero_stride_makes_progress() {
+        let data: &[u8] = [1, 1, 2, 255].as_slice();
+        let cursor = SortedSliceCursor::new(data, OrdComparator);
+        let mut strided = KeyStrideIter::new(cursor, OrdComparator, 0, |key, stride| {
+            key.checked_add(*stride)
+        });
+
+        for expected in [1, 1, 2, 255] {
+            assert_eq!(strided.next(), Some(&expected));
+        }
+        assert_eq!(strided.next(), None);
+
+        // A saturating addition stops advancing at the maximum key.
+        let cursor = SortedSliceCursor::new(data, OrdComparator);
+        let mut strided = KeyStrideIter::new(
+            cursor,
+            OrdComparator,
+            100,
+            |key, stride| Some(key.saturating_add(*stride)),
+        );
+        for expected in [1, 255] {
+            assert_eq!(strided.next(), Some(&expected));
+        }
+        assert_eq!(strided.next(), None);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::cursor::{drain_from_current, CursorLendingIterator};
+use crate::lending_iterator_support::LentItem;
+
+
+/// A [`CursorLendingIterator`] whose items can be decomposed into a key reference and a value
+/// reference.
+///
+/// This allows entries to be consumed through plain references, without exposing the lifetimes
+/// of lent items; for instance, [`KeyValueCursor::for_each_kv`] passes each entry to a callback,
+/// which suits consumers across an FFI boundary.
+///
+/// This decomposition is expected to be cheap.
+pub trait KeyValueCursor<Key: ?Sized, Value: ?Sized>: CursorLendingIterator {
+    /// Decompose one of the items of the iterator into its key and value.
+    #[must_use]
+    fn item_to_key_value(item: LentItem<'_, Self>) -> (&'_ Key, &'_ Value);
+
+    /// Move forwards through the rest of the collection, calling `f` with the key and value of
+    /// each entry.
+    ///
+    /// If the iterator is [valid], the current entry is the first entry passed to `f`;
+    /// otherwise, iteration starts from the first entry of the collection. Afterwards, the
+    /// iterator is `!valid()`.
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    fn for_each_kv<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&Key, &Value),
+    {
+        drain_from_current(self, |this| {
+            if let Some(item) = this.current() {
+                let (key, value) = Self::item_to_key_value(item);
+                f(key, value);
+            }
+        });
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    use crate::{comparator::OrdComparator, seekable::Seekable as _};
+    use crate::sorted_slice_cursor::SortedSliceCursor;
+    use super::*;
+
+
+    #[test]
+    fn callback_from_current_entry() {
+        let entries: &[(u8, char)] = [(1, 'a'), (4, 'b'), (6, 'c')].as_slice();
+        let mut cursor = SortedSliceCursor::new(entries, OrdComparator);
+        cursor.seek(&(4, 'b'));
+
+        let mut key_sum = 0;
+        let mut last_value = None;
+        cursor.for_each_kv(|key, value| {
+            key_sum += key;
+            last_value = Some(*value);
+        });
+        assert_eq!(key_sum, 10);
+        assert_eq!(last_value, Some('c'));
+        assert!(!cursor.valid());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[expect(clippy::panic, reason = "the callback must never be called")]
+    fn collect_key_value_pairs() {
+        let entries: &[(u8, &str)] = [(1, "one"), (2, "two"), (3, "three")].as_slice();
+        let mut cursor = SortedSliceCursor::new(entries, OrdComparator);
+
+        let mut pairs = Vec::new();
+        cursor.for_each_kv(|&key, &value| pairs.push((key, value)));
+        assert_eq!(pairs, entries);
+        assert!(!cursor.valid());
+
+        let empty: &[(u8, &str)] = &[];
+        SortedSliceCursor::new(empty, OrdComparator)
+            .for_each_kv(|_, _| panic!("an empty cursor has no entries"));
+    }
+}
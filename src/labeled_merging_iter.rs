@@ -0,0 +1,204 @@
+use alloc::vec::Vec;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator, merging_iter::MergingIter};
+use crate::lending_iterator_support::{LendItem, LentItem};
+use crate::{seekable::{ItemToKey, Seekable}, seekable_iterators::SeekableLendingIterator};
+
+
+/// A [`MergingIter`] whose sources each have a label, such as a file name, which makes the source
+/// of the current entry human-readable when debugging.
+///
+/// All merging is delegated to the inner [`MergingIter`]; the labels are only used by
+/// [`LabeledMergingIter::current_label`], which reports the label of the source that the current
+/// entry came from.
+///
+/// The inner [`MergingIter`] can be inspected with [`LabeledMergingIter::inner`], but not
+/// mutably borrowed, since reordering its sources (as with
+/// [`MergingIter::reorder_sources_by_frequency`]) would desynchronize the labels.
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct LabeledMergingIter<Label, Key: ?Sized, Cmp, Iter> {
+    merging: MergingIter<Key, Cmp, Iter>,
+    /// The label of each source, indexed in the same order as the sources of `merging`.
+    labels:  Vec<Label>,
+}
+
+impl<Label, Key, Cmp, Iter> LabeledMergingIter<Label, Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    /// Create a [`MergingIter`] over the provided labeled sources, which keeps track of their
+    /// labels.
+    ///
+    /// See [`MergingIter::new`].
+    #[must_use]
+    pub fn new(sources: Vec<(Label, Iter)>, cmp: Cmp) -> Self {
+        let (labels, iterators) = sources.into_iter().unzip();
+
+        Self {
+            merging: MergingIter::new(iterators, cmp),
+            labels,
+        }
+    }
+
+    /// Get the label of the source whose entry the iterator is currently at, if the iterator is
+    /// [valid].
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[inline]
+    #[must_use]
+    pub fn current_label(&self) -> Option<&Label> {
+        self.labels.get(self.merging.current_source()?)
+    }
+
+    /// Get the labels of the sources, in the order in which they were provided.
+    #[inline]
+    #[must_use]
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// Get a reference to the inner [`MergingIter`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &MergingIter<Key, Cmp, Iter> {
+        &self.merging
+    }
+
+    /// Convert the adapter back into the inner [`MergingIter`] and the labels of its sources.
+    #[inline]
+    #[must_use]
+    pub fn into_parts(self) -> (MergingIter<Key, Cmp, Iter>, Vec<Label>) {
+        (self.merging, self.labels)
+    }
+}
+
+impl<'lend, Label, Key, Cmp, Iter> LendItem<'lend> for LabeledMergingIter<Label, Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Iter: LendItem<'lend>,
+{
+    type Item = Iter::Item;
+}
+
+impl<Label, Key, Cmp, Iter> CursorLendingIterator for LabeledMergingIter<Label, Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.merging.valid()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.merging.next()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.merging.current()
+    }
+
+    #[inline]
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.merging.prev()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.merging.keys_are_unique()
+    }
+}
+
+impl<Label, Key, Cmp, Iter> ItemToKey<Key> for LabeledMergingIter<Label, Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Iter: ItemToKey<Key>,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        Iter::item_to_key(item)
+    }
+}
+
+impl<Label, Key, Cmp, Iter> Seekable<Key, Cmp> for LabeledMergingIter<Label, Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.merging.reset();
+    }
+
+    #[inline]
+    fn seek(&mut self, min_bound: &Key) {
+        self.merging.seek(min_bound);
+    }
+
+    #[inline]
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.merging.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.merging.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.merging.seek_to_last();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn labels_follow_current_source() {
+        let mut iter = LabeledMergingIter::new(
+            vec![
+                ("memtable", TestIter::new(&[2, 5, 7]).unwrap()),
+                ("level-0", TestIter::new(&[1, 5, 8]).unwrap()),
+                ("empty", TestIter::new(&[]).unwrap()),
+            ],
+            OrdComparator,
+        );
+        assert_eq!(iter.labels(), ["memtable", "level-0", "empty"]);
+        assert_eq!(iter.current_label(), None);
+
+        let expected = [
+            (1, "level-0"), (2, "memtable"), (5, "memtable"), (5, "level-0"), (7, "memtable"),
+            (8, "level-0"),
+        ];
+        for (key, label) in expected {
+            assert_eq!(iter.next(), Some(&key));
+            assert_eq!(iter.current_label(), Some(&label));
+            let source = iter.inner().current_source().unwrap();
+            assert_eq!(iter.current_label(), iter.labels().get(source));
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.current_label(), None);
+
+        iter.seek_before(&5);
+        assert_eq!(iter.current_label(), Some(&"memtable"));
+        assert_eq!(iter.prev(), Some(&1));
+        assert_eq!(iter.current_label(), Some(&"level-0"));
+        iter.seek_to_last();
+        assert_eq!(iter.current_label(), Some(&"level-0"));
+    }
+}
@@ -0,0 +1,569 @@
+use core::{borrow::Borrow as _, cmp::Ordering, marker::PhantomData};
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    ops::{Bound, RangeInclusive},
+};
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+    seekable_iterators::SeekableLendingIterator,
+};
+
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Forwards,
+    Backwards,
+}
+
+/// A source of a [`LazyMergingIter`], which is opened at most once.
+struct LazySource<'a, Owned, Iter> {
+    first_key: Owned,
+    last_key:  Owned,
+    /// `Some` if and only if `iter` is `None`.
+    thunk:     Option<Box<dyn FnOnce() -> Iter + 'a>>,
+    iter:      Option<Iter>,
+}
+
+impl<Owned: Debug, Iter: Debug> Debug for LazySource<'_, Owned, Iter> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("LazySource")
+            .field("first_key", &self.first_key)
+            .field("last_key", &self.last_key)
+            .field("iter", &self.iter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A `LazyMergingIter` iterates over the sorted union of several sources, each of which is only
+/// opened once its entries might be needed.
+///
+/// Each source is provided as a thunk which opens it, together with the range of keys which the
+/// source contains. A source is opened when a seek or a step of iteration could reach a key in
+/// its range, and sources whose ranges are never reached are never opened. This is useful for
+/// sources which are expensive to open, such as files.
+///
+/// Once opened, a source stays open. Otherwise, a `LazyMergingIter` behaves like a
+/// [`MergingIter`] in its default mode, including the tie-breaking rules between sources and the
+/// caveats for switching direction.
+///
+/// # Key range requirements
+/// Every entry of a source must have a key within the (inclusive) range provided for that source,
+/// according to the provided comparator. If a range is too narrow, entries of that source may be
+/// skipped, or yielded out of order. A range which is too wide is harmless, but may cause the
+/// source to be opened earlier than necessary.
+///
+/// See [`MergingIter::new`] for the requirements on the comparators.
+///
+/// # Performance
+/// While some sources are unopened, every step of iteration copies the current key, and checks
+/// the key ranges of the unopened sources.
+///
+/// [`MergingIter`]: crate::MergingIter
+/// [`MergingIter::new`]: crate::MergingIter::new
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct LazyMergingIter<'a, Key: ?Sized + ToOwned, Cmp, Iter> {
+    sources:      Vec<LazySource<'a, Key::Owned, Iter>>,
+    cmp:          Cmp,
+    _key:         PhantomData<Key>,
+    /// The index of the current source, which is opened and `valid()` if this is `Some`.
+    ///
+    /// If `None`, no opened source should be `valid()`.
+    current_iter: Option<usize>,
+    /// If `current_iter` is `Some` and `direction` is `Forwards`, then the non-`current_iter`
+    /// opened sources are in front of `current_iter`. If `Backwards`, the non-`current_iter`
+    /// opened sources are behind `current_iter`.
+    direction:    Direction,
+    /// The number of sources which have not yet been opened.
+    unopened:     usize,
+}
+
+impl<'a, Key, Cmp, Iter> LazyMergingIter<'a, Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    /// Create a new [`LazyMergingIter`] from thunks which open each source, paired with the
+    /// inclusive range of keys in that source.
+    ///
+    /// No source is opened by this function, and the iterator starts out `!valid()`. See the
+    /// type-level documentation for the requirements on the key ranges.
+    #[expect(clippy::type_complexity, reason = "it's just a list of pairs")]
+    #[must_use]
+    pub fn new(
+        sources: Vec<(Box<dyn FnOnce() -> Iter + 'a>, RangeInclusive<Key::Owned>)>,
+        cmp:     Cmp,
+    ) -> Self {
+        let sources: Vec<_> = sources
+            .into_iter()
+            .map(|(thunk, key_range)| {
+                let (first_key, last_key) = key_range.into_inner();
+                LazySource {
+                    first_key,
+                    last_key,
+                    thunk: Some(thunk),
+                    iter:  None,
+                }
+            })
+            .collect();
+
+        Self {
+            unopened:     sources.len(),
+            sources,
+            cmp,
+            _key:         PhantomData,
+            current_iter: None,
+            direction:    Direction::Forwards,
+        }
+    }
+
+    /// Determine whether the source at index `source` has been opened.
+    ///
+    /// Returns `false` if there is no such source.
+    #[must_use]
+    pub fn is_opened(&self, source: usize) -> bool {
+        self.sources.get(source).is_some_and(|source| source.iter.is_some())
+    }
+
+    /// Get the number of sources which have been opened.
+    #[inline]
+    #[must_use]
+    pub fn opened_sources(&self) -> usize {
+        self.sources.len() - self.unopened
+    }
+
+    /// Get the index of the source which the current entry comes from, if the iterator is
+    /// `valid()`.
+    #[inline]
+    #[must_use]
+    pub const fn current_source(&self) -> Option<usize> {
+        self.current_iter
+    }
+
+    /// Get the comparator used to merge the sources.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Iterate over the opened sources.
+    fn opened_iters(&mut self) -> impl Iterator<Item = &mut Iter> {
+        self.sources.iter_mut().filter_map(|source| source.iter.as_mut())
+    }
+
+    /// Find the opened and valid source whose `current` key is most `extreme` (`Less` for the
+    /// minimum, `Greater` for the maximum). Ties are broken in favor of the lowest index for the
+    /// minimum, and the highest index for the maximum.
+    fn find_extreme_iter(&self, extreme: Ordering) -> Option<usize> {
+        let mut found: Option<(usize, &Key)> = None;
+
+        for (idx, source) in self.sources.iter().enumerate() {
+            let Some(curr_item) = source.iter.as_ref().and_then(|iter| iter.current()) else {
+                continue;
+            };
+            let curr_key = Iter::item_to_key(curr_item);
+
+            let replace = found.is_none_or(|(_, found_key)| {
+                let ordering = self.cmp.cmp(curr_key, found_key);
+                if extreme == Ordering::Less {
+                    ordering == Ordering::Less
+                } else {
+                    ordering != Ordering::Less
+                }
+            });
+            if replace {
+                found = Some((idx, curr_key));
+            }
+        }
+
+        found.map(|(idx, _)| idx)
+    }
+
+    /// Find an unopened source which must be opened in order to find the most `extreme` entry
+    /// (`Less` for the minimum, `Greater` for the maximum) within `bound`, given the current
+    /// positions of the opened sources.
+    ///
+    /// `bound` is a lower bound when moving forwards, and an upper bound when moving backwards.
+    fn source_to_open(&self, bound: Bound<&Key>, extreme: Ordering) -> Option<usize> {
+        let mut found: Option<(usize, &Key)> = None;
+
+        for (idx, source) in self.sources.iter().enumerate() {
+            if source.iter.is_some() {
+                continue;
+            }
+
+            // `near` is the end of the source's range which is reached first.
+            let (near, far) = if extreme == Ordering::Less {
+                (source.first_key.borrow(), source.last_key.borrow())
+            } else {
+                (source.last_key.borrow(), source.first_key.borrow())
+            };
+
+            let reaches_bound = match bound {
+                Bound::Unbounded       => true,
+                Bound::Included(bound) => self.cmp.cmp(far, bound) != extreme,
+                Bound::Excluded(bound) => self.cmp.cmp(far, bound) == extreme.reverse(),
+            };
+
+            if reaches_bound
+                && found.is_none_or(|(_, found_near)| self.cmp.cmp(near, found_near) == extreme)
+            {
+                found = Some((idx, near));
+            }
+        }
+
+        let (idx, near) = found?;
+
+        // The source is needed unless some opened source has an entry which comes strictly
+        // before the start of the source's range.
+        if let Some(current_item) = self
+            .find_extreme_iter(extreme)
+            .and_then(|current_idx| self.sources.get(current_idx)?.iter.as_ref()?.current())
+        {
+            let current_key = Iter::item_to_key(current_item);
+            if self.cmp.cmp(near, current_key) == extreme.reverse() {
+                return None;
+            }
+        }
+
+        Some(idx)
+    }
+
+    /// Open every unopened source which might contain the most `extreme` entry within `bound`,
+    /// and position each of them at `bound`.
+    fn open_needed(&mut self, bound: Bound<&Key>, extreme: Ordering) {
+        while self.unopened > 0 {
+            let Some(idx) = self.source_to_open(bound, extreme) else {
+                break;
+            };
+            let Some(source) = self.sources.get_mut(idx) else {
+                break;
+            };
+            let Some(thunk) = source.thunk.take() else {
+                break;
+            };
+
+            let iter = source.iter.insert(thunk());
+            self.unopened -= 1;
+            position(iter, &self.cmp, bound, extreme);
+        }
+    }
+
+    /// Get an owned copy of the current key, if any source is unopened.
+    fn owned_current_key(&self) -> Option<Key::Owned> {
+        if self.unopened == 0 {
+            None
+        } else {
+            self.current().map(|item| Self::item_to_key(item).to_owned())
+        }
+    }
+
+    /// Move all non-`current_idx` opened sources strictly in front of (if `extreme` is `Less`)
+    /// or strictly behind (if `extreme` is `Greater`) the `current_idx` source.
+    fn switch_direction(&mut self, current_idx: usize, extreme: Ordering) {
+        let (earlier, current_and_later) = self.sources.split_at_mut(current_idx);
+        let Some((current_source, later)) = current_and_later.split_first_mut() else {
+            return;
+        };
+        let Some(current_item) = current_source.iter.as_ref().and_then(|iter| iter.current())
+        else {
+            return;
+        };
+        let current_key = Iter::item_to_key(current_item);
+
+        for iter in earlier.iter_mut().chain(later).filter_map(|source| source.iter.as_mut()) {
+            position(iter, &self.cmp, Bound::Excluded(current_key), extreme);
+        }
+
+        self.direction = if extreme == Ordering::Less {
+            Direction::Forwards
+        } else {
+            Direction::Backwards
+        };
+    }
+
+    /// Move the current source one step in the direction of `extreme` (`Less` for forwards,
+    /// `Greater` for backwards), opening any sources which are then needed, and find the new
+    /// current source.
+    fn step(&mut self, current_idx: usize, extreme: Ordering) {
+        let last_key = self.owned_current_key();
+
+        if let Some(iter) = self.sources.get_mut(current_idx).and_then(|source| source.iter.as_mut()) {
+            if extreme == Ordering::Less {
+                iter.next();
+            } else {
+                iter.prev();
+            }
+        }
+
+        let bound = last_key
+            .as_ref()
+            .map_or(Bound::Unbounded, |last_key| Bound::Excluded::<&Key>(last_key.borrow()));
+        self.open_needed(bound, extreme);
+        self.current_iter = self.find_extreme_iter(extreme);
+    }
+}
+
+/// Move `iter` to the first entry within `bound` in the direction of `extreme`. That is, if
+/// `extreme` is `Less`, move to the smallest entry within the lower bound `bound`, and if
+/// `extreme` is `Greater`, move to the greatest entry within the upper bound `bound`.
+fn position<Key, Cmp, Iter>(iter: &mut Iter, cmp: &Cmp, bound: Bound<&Key>, extreme: Ordering)
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    let skip_equal = |source: &mut Iter, key: &Key| {
+        while source.current().is_some_and(|item| keys_equal(cmp, key, Iter::item_to_key(item))) {
+            source.next();
+        }
+    };
+
+    match (bound, extreme == Ordering::Less) {
+        (Bound::Unbounded, true)       => iter.seek_to_first(),
+        (Bound::Unbounded, false)      => iter.seek_to_last(),
+        (Bound::Included(bound), true) => iter.seek(bound),
+        (Bound::Excluded(bound), true) => {
+            iter.seek(bound);
+            skip_equal(iter, bound);
+        }
+        (Bound::Included(bound), false) => {
+            // Move past every entry with the bound's key, then step back.
+            iter.seek(bound);
+            skip_equal(iter, bound);
+            iter.prev();
+        }
+        (Bound::Excluded(bound), false) => iter.seek_before(bound),
+    }
+}
+
+impl<'lend, Key, Cmp, Iter> LendItem<'lend> for LazyMergingIter<'_, Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Iter: LendItem<'lend>,
+{
+    type Item = Iter::Item;
+}
+
+impl<Key, Cmp, Iter> CursorLendingIterator for LazyMergingIter<'_, Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.current_iter.is_some()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        if let Some(current_idx) = self.current_iter {
+            if matches!(self.direction, Direction::Backwards) {
+                self.switch_direction(current_idx, Ordering::Less);
+            }
+            self.step(current_idx, Ordering::Less);
+        } else {
+            self.seek_to_first();
+        }
+
+        self.current()
+    }
+
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.sources.get(self.current_iter?)?.iter.as_ref()?.current()
+    }
+
+    /// Move the iterator one position back, and return the entry at that position.
+    /// Returns `None` if the iterator was at the first entry.
+    ///
+    /// See [`MergingIter::prev`] for caveats about backwards iteration.
+    ///
+    /// [`MergingIter::prev`]: crate::MergingIter::prev
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        if let Some(current_idx) = self.current_iter {
+            if matches!(self.direction, Direction::Forwards) {
+                self.switch_direction(current_idx, Ordering::Greater);
+            }
+            self.step(current_idx, Ordering::Greater);
+        } else {
+            self.seek_to_last();
+        }
+
+        self.current()
+    }
+}
+
+impl<Key, Cmp, Iter> ItemToKey<Key> for LazyMergingIter<'_, Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Iter: ItemToKey<Key>,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        Iter::item_to_key(item)
+    }
+}
+
+impl<Key, Cmp, Iter> Seekable<Key, Cmp> for LazyMergingIter<'_, Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    /// Reset the iterator to its initial position. Unopened sources are not opened.
+    fn reset(&mut self) {
+        for iter in self.opened_iters() {
+            iter.reset();
+        }
+        self.current_iter = None;
+        self.direction = Direction::Forwards;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        for iter in self.opened_iters() {
+            iter.seek(min_bound);
+        }
+
+        self.open_needed(Bound::Included(min_bound), Ordering::Less);
+        self.current_iter = self.find_extreme_iter(Ordering::Less);
+        self.direction = Direction::Forwards;
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        for iter in self.opened_iters() {
+            iter.seek_before(strict_upper_bound);
+        }
+
+        self.open_needed(Bound::Excluded(strict_upper_bound), Ordering::Greater);
+        self.current_iter = self.find_extreme_iter(Ordering::Greater);
+        self.direction = Direction::Backwards;
+    }
+
+    fn seek_to_first(&mut self) {
+        for iter in self.opened_iters() {
+            iter.seek_to_first();
+        }
+
+        self.open_needed(Bound::Unbounded, Ordering::Less);
+        self.current_iter = self.find_extreme_iter(Ordering::Less);
+        self.direction = Direction::Forwards;
+    }
+
+    fn seek_to_last(&mut self) {
+        for iter in self.opened_iters() {
+            iter.seek_to_last();
+        }
+
+        self.open_needed(Bound::Unbounded, Ordering::Greater);
+        self.current_iter = self.find_extreme_iter(Ordering::Greater);
+        self.direction = Direction::Backwards;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use alloc::{rc::Rc, vec};
+
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    type Source = (Box<dyn FnOnce() -> TestIter<'static>>, RangeInclusive<u8>);
+
+    /// Create a source over `data`, which records in `opened` whether it was opened.
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn source(data: &'static [u8], opened: &Rc<Cell<bool>>) -> Source {
+        let opened = Rc::clone(opened);
+        let range = *data.first().unwrap()..=*data.last().unwrap();
+        let thunk = move || {
+            opened.set(true);
+            TestIter::new(data).unwrap()
+        };
+        (Box::new(thunk), range)
+    }
+
+    #[test]
+    fn untouched_sources_stay_unopened() {
+        let flags = [(); 3].map(|()| Rc::new(Cell::new(false)));
+        let [low, mid, high] = &flags;
+        let mut iter = LazyMergingIter::new(
+            vec![
+                source(&[1, 2, 3], low),
+                source(&[10, 11], mid),
+                source(&[20, 21], high),
+            ],
+            OrdComparator,
+        );
+        assert_eq!(iter.opened_sources(), 0);
+
+        iter.seek(&10);
+        assert_eq!(iter.current(), Some(&10));
+        assert!(!low.get() && mid.get() && !high.get());
+
+        assert_eq!(iter.next(), Some(&11));
+        assert!(!high.get());
+        assert_eq!(iter.next(), Some(&20));
+        assert!(high.get());
+        assert!(!low.get());
+
+        iter.seek_before(&10);
+        assert_eq!(iter.current(), Some(&3));
+        assert!(low.get());
+        assert!(iter.is_opened(0));
+        assert!(!iter.is_opened(3));
+    }
+
+    #[test]
+    fn overlapping_sources_open_in_time() {
+        let flags = [(); 2].map(|()| Rc::new(Cell::new(false)));
+        let [first, second] = &flags;
+        let mut iter = LazyMergingIter::new(
+            vec![source(&[1, 5, 9], first), source(&[4, 6], second)],
+            OrdComparator,
+        );
+
+        assert_eq!(iter.next(), Some(&1));
+        assert!(!second.get());
+
+        for expected in [4, 5, 6, 9] {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert!(second.get());
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn backwards_iteration() {
+        let flags = [(); 3].map(|()| Rc::new(Cell::new(false)));
+        let [low, mid, high] = &flags;
+        let mut iter = LazyMergingIter::new(
+            vec![
+                source(&[1, 2, 3], low),
+                source(&[2, 3, 8], mid),
+                source(&[20, 21], high),
+            ],
+            OrdComparator,
+        );
+
+        iter.seek_before(&9);
+        assert_eq!(iter.current(), Some(&8));
+        assert!(!low.get() && mid.get() && !high.get());
+
+        for expected in [3, 3, 2, 2, 1] {
+            assert_eq!(iter.prev(), Some(&expected));
+        }
+        assert_eq!(iter.prev(), None);
+        assert!(!high.get());
+
+        assert_eq!(iter.prev(), Some(&21));
+        assert_eq!(iter.opened_sources(), 3);
+    }
+}
@@ -6,6 +6,8 @@ use crate::{
     pooled::PooledIterator, seekable::Seekable,
 };
 use crate::cursor::{CursorLendingIterator, CursorPooledIterator};
+#[cfg(feature = "lending-iterator")]
+use crate::lending_iterator_adapter::LendingIteratorAdapter;
 
 
 /// An adapter for [`CursorLendingIterator`] which implements [`lender::Lender`].
@@ -31,6 +33,16 @@ impl<I> LenderAdapter<I> {
     pub fn into_inner(self) -> I {
         self.0
     }
+
+    /// Convert the adapter into a [`LendingIteratorAdapter`] over the same inner iterator,
+    /// without moving the inner iterator.
+    #[cfg(feature = "lending-iterator")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "lender", feature = "lending-iterator"))))]
+    #[inline]
+    #[must_use]
+    pub fn into_lending_iterator_adapter(self) -> LendingIteratorAdapter<I> {
+        LendingIteratorAdapter::new(self.0)
+    }
 }
 
 impl<'lend, I: CursorLendingIterator> Lending<'lend> for LenderAdapter<I> {
@@ -157,3 +169,40 @@ impl<I: CursorPooledIterator> PooledLenderAdapter<I> {
 }
 
 delegate_seekable!(PooledLenderAdapter.iter PooledIterator);
+
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "lending-iterator")]
+    use lending_iterator::LendingIterator as _;
+
+    #[cfg(feature = "lending-iterator")]
+    use crate::{cursor::CursorLendingIterator as _, test_iter::TestIter};
+    #[cfg(feature = "lending-iterator")]
+    use super::*;
+
+
+    #[cfg(feature = "lending-iterator")]
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn round_trip_between_adapters() {
+        let data: &[u8] = [1, 3, 5, 7].as_slice();
+        let mut lender = TestIter::new(data).unwrap().into_lender();
+        assert_eq!(Lender::next(&mut lender), Some(&1));
+
+        let mut lending_iter = lender.into_lending_iterator_adapter();
+        assert_eq!(lending_iter.current(), Some(&1));
+        assert_eq!(lending_iter.next(), Some(&3));
+        lending_iter.seek(&5);
+
+        let mut lender = lending_iter.into_lender_adapter();
+        assert_eq!(lender.current(), Some(&5));
+        assert_eq!(lender.prev(), Some(&3));
+        assert_eq!(Lender::next(&mut lender), Some(&5));
+        assert_eq!(lender.into_inner().current(), Some(&5));
+    }
+}
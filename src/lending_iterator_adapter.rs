@@ -3,6 +3,8 @@ use lending_iterator::lending_iterator::Item;
 use crate::seekable::delegate_seekable;
 use crate::{comparator::Comparator, pooled::PooledIterator, seekable::Seekable};
 use crate::cursor::{CursorLendingIterator, CursorPooledIterator};
+#[cfg(feature = "lender")]
+use crate::lender_adapter::LenderAdapter;
 
 
 /// An adapter for [`CursorLendingIterator`] which implements [`lending_iterator::LendingIterator`].
@@ -28,6 +30,16 @@ impl<I> LendingIteratorAdapter<I> {
     pub fn into_inner(self) -> I {
         self.0
     }
+
+    /// Convert the adapter into a [`LenderAdapter`] over the same inner iterator, without
+    /// moving the inner iterator.
+    #[cfg(feature = "lender")]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "lender", feature = "lending-iterator"))))]
+    #[inline]
+    #[must_use]
+    pub fn into_lender_adapter(self) -> LenderAdapter<I> {
+        LenderAdapter::new(self.0)
+    }
 }
 
 impl<I: CursorLendingIterator> LendingIteratorAdapter<I> {
@@ -15,8 +15,16 @@
 //! [`CursorPooledIterator`]: CursorPooledIterator
 //!
 //! [`Seekable`]: Seekable
+//! [`TrySeekable`]: TrySeekable
 //! [`Comparator`]: Comparator
+//! [`ComparatorExt`]: ComparatorExt
 //! [`OrdComparator`]: OrdComparator
+//! [`UnitComparator`]: UnitComparator
+//! [`CountingComparator`]: CountingComparator
+//! [`SeekableLendingIteratorExt`]: SeekableLendingIteratorExt
+//! [`IndexedCursor`]: IndexedCursor
+//! [`SortedSliceCursor`]: SortedSliceCursor
+//! [`OwnedSliceIter`]: OwnedSliceIter
 //!
 //! [`Ord`]: Ord
 //! [`FusedIterator`]: core::iter::FusedIterator
@@ -29,6 +37,18 @@
     feature = "alloc",
     doc = " [`MergingIter`]: merging_iter::MergingIter",
 )]
+#![cfg_attr(
+    feature = "alloc",
+    doc = " [`ProjectedMergingIter`]: projected_merging_iter::ProjectedMergingIter",
+)]
+#![cfg_attr(
+    feature = "alloc",
+    doc = " [`KeyCachingIter`]: key_caching_iter::KeyCachingIter",
+)]
+#![cfg_attr(
+    feature = "alloc",
+    doc = " [`LinearSeekable`]: linear_seekable::LinearSeekable",
+)]
 #![cfg_attr(
     feature = "alloc",
     doc = " [`PooledIter`]: pooled_iter::PooledIter",
@@ -60,16 +80,94 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+// `futures-executor` is only used by the tests of the `futures` feature.
+#[cfg(all(test, not(feature = "futures")))]
+use futures_executor as _;
+
+mod clone_position;
 mod comparator;
+mod comparator_ext;
+mod counting_comparator;
 mod cursor;
+mod difference_iter;
+mod display_map_iter;
+mod excluding_iter;
+mod exhaustion_tracker;
+mod galloping_seekable;
+mod indexed;
+mod intersection_iter;
+mod key_cursor;
+mod key_delta_iter;
+mod key_remap_seekable;
+mod key_stride_iter;
+mod key_value_cursor;
+mod owned_slice_iter;
 mod pooled;
+mod range_scans;
+mod seek_only;
 mod seekable;
+mod seekable_ext;
 mod seekable_iterators;
+mod sorted_slice_cursor;
+mod symmetric_difference_iter;
+mod try_next;
+mod try_seekable;
+mod window_iter;
+mod with_meta;
+mod zip_positions_iter;
 
 mod lending_iterator_support;
 
+#[cfg(feature = "alloc")]
+mod chunks_iter;
+#[cfg(feature = "alloc")]
+mod coalesce_iter;
+#[cfg(feature = "alloc")]
+mod cursor_ext;
+#[cfg(feature = "alloc")]
+mod dedup_iter;
+#[cfg(feature = "alloc")]
+mod distinct_keys_iter;
+#[cfg(feature = "alloc")]
+mod distinct_runs_iter;
+#[cfg(feature = "alloc")]
+mod forked_iter;
+#[cfg(feature = "alloc")]
+mod key_caching_iter;
+#[cfg(feature = "alloc")]
+mod labeled_merging_iter;
+#[cfg(feature = "alloc")]
+mod lazy_merging_iter;
+#[cfg(feature = "alloc")]
+mod linear_seekable;
+#[cfg(feature = "alloc")]
+mod memory_bounded_pooled_iter;
+#[cfg(feature = "alloc")]
+mod merge_core;
+#[cfg(feature = "alloc")]
+mod merge_sorted;
 #[cfg(feature = "alloc")]
 mod merging_iter;
+#[cfg(feature = "alloc")]
+mod overlay_iter;
+#[cfg(feature = "alloc")]
+mod projected_merging_iter;
+#[cfg(feature = "alloc")]
+mod saturating;
+#[cfg(feature = "alloc")]
+mod sort_checked_iter;
+#[cfg(feature = "alloc")]
+mod testing;
+#[cfg(feature = "alloc")]
+mod tombstone_merging_iter;
+#[cfg(feature = "alloc")]
+mod try_merging_iter;
+#[cfg(feature = "alloc")]
+mod ttl_dedup_iter;
+#[cfg(feature = "futures")]
+mod cursor_stream;
+#[cfg(feature = "serde")]
+mod position_snapshot;
 #[cfg(feature = "std")]
 mod pooled_iter;
 #[cfg(feature = "std")]
@@ -88,12 +186,41 @@ mod lending_iterator_adapter;
 
 
 pub use self::{
-    comparator::{Comparator, OrdComparator},
+    clone_position::ClonePosition,
+    comparator::{
+        ByteKeyComparator, CollatedSliceComparator, Comparator, FnComparator, OrdComparator,
+        UnitComparator,
+    },
+    comparator_ext::{ComparatorExt, MapKey, Reversed, Then},
+    counting_comparator::CountingComparator,
     cursor::{CursorIterator, CursorLendingIterator, CursorPooledIterator},
+    difference_iter::DifferenceIter,
+    display_map_iter::DisplayMapIter,
+    excluding_iter::ExcludingIter,
+    exhaustion_tracker::{ExhaustionTracker, TracksExhaustion},
+    galloping_seekable::GallopingSeekable,
+    indexed::{CountBetweenHint, IndexedCursor},
+    intersection_iter::{intersection_count, IntersectionIter},
+    key_cursor::KeyCursor,
+    key_delta_iter::KeyDeltaIter,
+    key_remap_seekable::KeyRemapSeekable,
+    key_stride_iter::KeyStrideIter,
+    key_value_cursor::KeyValueCursor,
     lending_iterator_support::{ImplyBound, LendItem, LentItem},
+    owned_slice_iter::OwnedSliceIter,
     pooled::{OutOfBuffers, PooledIterator},
+    range_scans::{RangeScan, RangeScans},
+    seek_only::SeekOnly,
     seekable::{ItemToKey, Seekable},
+    seekable_ext::{SeekableLendingIteratorExt, SeekOutcome},
     seekable_iterators::{SeekableIterator, SeekableLendingIterator, SeekablePooledIterator},
+    sorted_slice_cursor::{SortedCursor, SortedSliceCursor},
+    symmetric_difference_iter::SymmetricDifferenceIter,
+    try_next::{NotReady, TryNext},
+    try_seekable::TrySeekable,
+    window_iter::WindowIter,
+    with_meta::WithMeta,
+    zip_positions_iter::ZipPositionsIter,
 };
 
 #[cfg(feature = "lender")]
@@ -105,10 +232,44 @@ pub use self::lending_iterator_adapter::{LendingIteratorAdapter, PooledLendingIt
 
 #[cfg(feature = "alloc")]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-pub use self::merging_iter::MergingIter;
+pub use self::{
+    chunks_iter::ChunksIter,
+    coalesce_iter::CoalesceIter,
+    cursor::BoxedPooledIter,
+    cursor_ext::CursorLendingIteratorExt,
+    dedup_iter::DedupIter,
+    distinct_keys_iter::DistinctKeysIter,
+    distinct_runs_iter::DistinctRunsIter,
+    forked_iter::{ForkedIter, ForkHandle},
+    key_caching_iter::KeyCachingIter,
+    labeled_merging_iter::LabeledMergingIter,
+    lazy_merging_iter::LazyMergingIter,
+    linear_seekable::LinearSeekable,
+    memory_bounded_pooled_iter::{BoundedItem, MemoryBoundedPooledIter},
+    merge_sorted::merge_sorted,
+    merging_iter::{MergeStrategy, MergingIter},
+    overlay_iter::OverlayIter,
+    projected_merging_iter::ProjectedMergingIter,
+    saturating::Saturating,
+    sort_checked_iter::SortCheckedIter,
+    sorted_slice_cursor::SortedVecCursor,
+    testing::{assert_all_keys_seekable, assert_seek_idempotent},
+    tombstone_merging_iter::TombstoneMergingIter,
+    try_merging_iter::TryMergingIter,
+    ttl_dedup_iter::TtlDedupIter,
+};
+#[cfg(feature = "futures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures")))]
+pub use self::cursor_stream::CursorStream;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use self::position_snapshot::{PositionSnapshot, SavePosition};
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub use self::{
-    pooled_iter::{PooledIter, PoolItem},
-    threadsafe_pooled_iter::{ThreadsafePooledIter, ThreadsafePoolItem},
+    pooled_iter::{CachingPooledIter, PooledIter, PoolItem, SharedPoolItem},
+    testing::assert_merge_matches_btreemap,
+    threadsafe_pooled_iter::{
+        ReservedBuffers, SharedPoolGroup, ThreadsafePooledIter, ThreadsafePoolItem,
+    },
 };
@@ -0,0 +1,264 @@
+use core::{borrow::Borrow, cmp::Ordering, iter::Fuse};
+use alloc::vec::Vec;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// A seekable cursor over a plain [`Iterator`] whose items are sorted according to a
+/// [`Comparator`].
+///
+/// Items are pulled from the inner iterator lazily, and every consumed item is kept in a cache;
+/// the cursor lends references into that cache. Moving forwards past the cached items pulls more
+/// items from the inner iterator, while moving backwards (and seeking to cached positions) only
+/// uses the cache. Seeking forwards is done by a linear scan over newly pulled items.
+///
+/// This makes any sorted iterator usable where [`Seekable`] is required, at the cost of
+/// buffering.
+///
+/// # Memory usage
+/// The cache is never shrunk: once an item has been pulled from the inner iterator, it is kept
+/// until the `LinearSeekable` is dropped. Memory usage therefore grows up to the size of the
+/// furthest-forward position ever reached. Note that [`prev`] from a `!valid()` position,
+/// [`seek_to_last`], and seeking past the last item all buffer the entire inner iterator.
+///
+/// The inner iterator must yield items sorted according to the comparator, for every key type it
+/// is used with; otherwise, seeks may position the cursor incorrectly. Duplicate entries are
+/// permitted.
+///
+/// [`prev`]: CursorLendingIterator::prev
+/// [`seek_to_last`]: Seekable::seek_to_last
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct LinearSeekable<I: Iterator, Cmp> {
+    iter:   Fuse<I>,
+    cache:  Vec<I::Item>,
+    cmp:    Cmp,
+    cursor: Option<usize>,
+}
+
+impl<I: Iterator, Cmp> LinearSeekable<I, Cmp> {
+    /// Create a cursor over the items of `iter`, which must be sorted according to `cmp`.
+    ///
+    /// The cursor starts out `!valid()`, and no items are pulled from `iter` until needed.
+    #[inline]
+    #[must_use]
+    pub fn new(iter: I, cmp: Cmp) -> Self {
+        Self {
+            iter: iter.fuse(),
+            cache: Vec::new(),
+            cmp,
+            cursor: None,
+        }
+    }
+
+    /// Get the comparator the items are sorted by.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Get the number of items which have been pulled from the inner iterator and cached.
+    #[inline]
+    #[must_use]
+    pub fn buffered_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Pull one item from the inner iterator into the cache, returning whether there was one.
+    fn pull(&mut self) -> bool {
+        if let Some(item) = self.iter.next() {
+            self.cache.push(item);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Pull every remaining item from the inner iterator into the cache.
+    fn buffer_all(&mut self) {
+        self.cache.extend(&mut self.iter);
+    }
+
+    /// Pull items into the cache until an item which is not `Less` than `key` has been cached
+    /// (or the inner iterator is exhausted), and then get the index of the first such item, or
+    /// the length of the cache if there is no such item.
+    fn buffer_to<Key>(&mut self, key: &Key) -> usize
+    where
+        I::Item: Borrow<Key>,
+        Key:     ?Sized,
+        Cmp:     Comparator<Key>,
+    {
+        while !self.cache.last().is_some_and(|last| {
+            self.cmp.cmp(last.borrow(), key) != Ordering::Less
+        }) {
+            if !self.pull() {
+                break;
+            }
+        }
+
+        self.cache.partition_point(|entry| self.cmp.cmp(entry.borrow(), key) == Ordering::Less)
+    }
+}
+
+impl<'lend, I: Iterator, Cmp> LendItem<'lend> for LinearSeekable<I, Cmp> {
+    type Item = &'lend I::Item;
+}
+
+impl<I: Iterator, Cmp> CursorLendingIterator for LinearSeekable<I, Cmp> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        let next_idx = self.cursor.map_or(0, |idx| idx + 1);
+        if next_idx >= self.cache.len() {
+            self.pull();
+        }
+        self.cursor = (next_idx < self.cache.len()).then_some(next_idx);
+        self.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.cache.get(self.cursor?)
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.cursor = if let Some(idx) = self.cursor {
+            idx.checked_sub(1)
+        } else {
+            self.buffer_all();
+            self.cache.len().checked_sub(1)
+        };
+        self.current()
+    }
+}
+
+impl<I, Cmp, Key> ItemToKey<Key> for LinearSeekable<I, Cmp>
+where
+    I:       Iterator,
+    I::Item: Borrow<Key>,
+    Key:     ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        item.borrow()
+    }
+}
+
+impl<I, Cmp, Key> Seekable<Key, Cmp> for LinearSeekable<I, Cmp>
+where
+    I:       Iterator,
+    I::Item: Borrow<Key>,
+    Key:     ?Sized,
+    Cmp:     Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.cursor = None;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        let index = self.buffer_to(min_bound);
+        self.cursor = (index < self.cache.len()).then_some(index);
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.cursor = self.buffer_to(strict_upper_bound).checked_sub(1);
+    }
+
+    fn seek_to_first(&mut self) {
+        if self.cache.is_empty() {
+            self.pull();
+        }
+        self.cursor = (!self.cache.is_empty()).then_some(0);
+    }
+
+    fn seek_to_last(&mut self) {
+        self.buffer_all();
+        self.cursor = self.cache.len().checked_sub(1);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::comparator::OrdComparator;
+    use super::*;
+
+
+    #[test]
+    fn seek_forwards_and_backwards() {
+        // 0, 2, 4, ..., 18
+        let mut iter = LinearSeekable::new((0_u8..10).map(|x| x * 2), OrdComparator);
+        assert_eq!(iter.buffered_len(), 0);
+
+        iter.seek(&5);
+        assert_eq!(iter.current(), Some(&6));
+        assert_eq!(iter.buffered_len(), 4);
+
+        iter.seek(&6);
+        assert_eq!(iter.current(), Some(&6));
+        assert_eq!(iter.buffered_len(), 4);
+
+        iter.seek_before(&3);
+        assert_eq!(iter.current(), Some(&2));
+        assert_eq!(iter.prev(), Some(&0));
+        assert_eq!(iter.prev(), None);
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next(), Some(&2));
+
+        iter.seek_before(&11);
+        assert_eq!(iter.current(), Some(&10));
+        assert_eq!(iter.buffered_len(), 7);
+        assert_eq!(iter.next(), Some(&12));
+        assert_eq!(iter.buffered_len(), 7);
+
+        iter.seek(&19);
+        assert!(!iter.valid());
+        assert_eq!(iter.buffered_len(), 10);
+
+        iter.seek_before(&0);
+        assert!(!iter.valid());
+
+        iter.seek_to_first();
+        assert_eq!(iter.current(), Some(&0));
+        iter.seek_to_last();
+        assert_eq!(iter.current(), Some(&18));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(&0));
+    }
+
+    #[test]
+    fn prev_buffers_everything() {
+        let mut iter = LinearSeekable::new([1_u8, 1, 3].into_iter(), OrdComparator);
+
+        assert_eq!(iter.prev(), Some(&3));
+        assert_eq!(iter.buffered_len(), 3);
+        assert_eq!(iter.prev(), Some(&1));
+        assert_eq!(iter.prev(), Some(&1));
+        assert_eq!(iter.prev(), None);
+
+        iter.seek(&1);
+        assert_eq!(iter.current(), Some(&1));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&3));
+    }
+
+    #[test]
+    fn empty() {
+        let mut iter = LinearSeekable::new([0_u8; 0].into_iter(), OrdComparator);
+        iter.seek_to_first();
+        assert!(!iter.valid());
+        iter.seek(&0);
+        assert!(!iter.valid());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.prev(), None);
+    }
+}
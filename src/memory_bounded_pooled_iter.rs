@@ -0,0 +1,369 @@
+use core::{borrow::Borrow, cell::Cell, marker::PhantomData, ops::Deref};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use alloc::{borrow::ToOwned, rc::Rc};
+
+use crate::{comparator::Comparator, lending_iterator_support::LentItem, seekable::Seekable};
+use crate::{
+    pooled::{OutOfBuffers, PooledIterator},
+    cursor::{CursorLendingIterator, CursorPooledIterator},
+};
+
+
+/// Convert a [`CursorLendingIterator`] into a [`CursorPooledIterator`] whose outstanding items
+/// are bounded by their total size in bytes, rather than by their number.
+///
+/// Capping the number of buffers, as [`PooledIter`] does, does not bound memory usage when items
+/// have variable sizes (like `Vec<u8>`). A `MemoryBoundedPooledIter` instead measures each item
+/// with a user-provided `size_of` function, and refuses to lend out an item if the total size of
+/// the outstanding items would then exceed a byte budget. Small items thus allow more
+/// concurrently outstanding items than large ones.
+///
+/// Items are measured through their borrowed form (for example, a `Vec<u8>` item is measured as
+/// a `[u8]`), before an owned copy is made. The owned copies are not reused; each item is freshly
+/// allocated, and its size is returned to the budget when it is dropped.
+///
+/// Since there is no separate pool of buffers, [`buffer_pool_size`] returns the byte budget and
+/// [`available_buffers`] returns the number of bytes still available.
+///
+/// Like [`PooledIter`], a `MemoryBoundedPooledIter` can only be used from a single thread, so
+/// the non-`try_` methods panic if an item does not fit in the remaining budget. The `try_`
+/// methods instead return [`OutOfBuffers`], and leave the iterator at its previous position.
+/// An item larger than the entire budget can never be lent out.
+///
+/// [`PooledIter`]: crate::PooledIter
+/// [`buffer_pool_size`]: PooledIterator::buffer_pool_size
+/// [`available_buffers`]: PooledIterator::available_buffers
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct MemoryBoundedPooledIter<I, BorrowedItem: ?Sized, F> {
+    iter:      I,
+    size_of:   F,
+    budget:    usize,
+    /// The total size of the outstanding items.
+    used:      Rc<Cell<usize>>,
+    _borrowed: PhantomData<fn(&BorrowedItem)>,
+}
+
+impl<I, BorrowedItem, F> MemoryBoundedPooledIter<I, BorrowedItem, F>
+where
+    I:                             CursorLendingIterator,
+    BorrowedItem:                  ?Sized + ToOwned,
+    F:                             Fn(&BorrowedItem) -> usize,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    /// Create a `MemoryBoundedPooledIter` whose outstanding items may have a total size of at
+    /// most `budget` bytes, as measured by `size_of`.
+    #[must_use]
+    pub fn new(iter: I, budget: usize, size_of: F) -> Self {
+        Self {
+            iter,
+            size_of,
+            budget,
+            used:      Rc::new(Cell::new(0)),
+            _borrowed: PhantomData,
+        }
+    }
+
+    /// Get the total size, in bytes, of the items which are currently lent out.
+    #[inline]
+    #[must_use]
+    pub fn used_bytes(&self) -> usize {
+        self.used.get()
+    }
+
+    /// Copy `item` into a new [`BoundedItem`] if it fits in the remaining budget.
+    ///
+    /// This is an associated function, rather than a method, so that `item` may borrow from
+    /// `self.iter`.
+    #[expect(clippy::needless_pass_by_value, reason = "lent item usually consists of references")]
+    fn try_fill(
+        size_of: &F,
+        budget:  usize,
+        used:    &Rc<Cell<usize>>,
+        item:    LentItem<'_, I>,
+    ) -> Option<BoundedItem<BorrowedItem::Owned>> {
+        let borrowed = item.borrow();
+        let size = size_of(borrowed);
+        let new_used = used.get().checked_add(size).filter(|&new_used| new_used <= budget)?;
+
+        used.set(new_used);
+        Some(BoundedItem {
+            item: borrowed.to_owned(),
+            size,
+            used: Rc::clone(used),
+        })
+    }
+}
+
+impl<I, BorrowedItem, F> PooledIterator for MemoryBoundedPooledIter<I, BorrowedItem, F>
+where
+    I:                             CursorLendingIterator,
+    BorrowedItem:                  ?Sized + ToOwned,
+    F:                             Fn(&BorrowedItem) -> usize,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    type Item = BoundedItem<BorrowedItem::Owned>;
+
+    /// Move the iterator one position forwards, and return the entry at that position.
+    /// Returns `None` if the iterator was at the last entry.
+    ///
+    /// # Panics
+    /// Panics if the entry does not fit in the remaining byte budget.
+    fn next(&mut self) -> Option<Self::Item> {
+        #[expect(clippy::expect_used, reason = "documented panic")]
+        self.try_next().expect("MemoryBoundedPooledIter: byte budget exceeded")
+    }
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        let Some(item) = self.iter.next() else {
+            return Ok(None);
+        };
+
+        if let Some(item) = Self::try_fill(&self.size_of, self.budget, &self.used, item) {
+            Ok(Some(item))
+        } else {
+            // Return to the previous position.
+            self.iter.prev();
+            Err(OutOfBuffers)
+        }
+    }
+
+    #[inline]
+    fn buffer_pool_size(&self) -> usize {
+        self.budget
+    }
+
+    fn available_buffers(&self) -> usize {
+        self.budget.saturating_sub(self.used.get())
+    }
+}
+
+impl<I, BorrowedItem, F> CursorPooledIterator for MemoryBoundedPooledIter<I, BorrowedItem, F>
+where
+    I:                             CursorLendingIterator,
+    BorrowedItem:                  ?Sized + ToOwned,
+    F:                             Fn(&BorrowedItem) -> usize,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    /// Get the current value the iterator is at, if the iterator is [valid].
+    ///
+    /// # Panics
+    /// Panics if the entry does not fit in the remaining byte budget.
+    ///
+    /// [valid]: CursorPooledIterator::valid
+    fn current(&self) -> Option<Self::Item> {
+        #[expect(clippy::expect_used, reason = "documented panic")]
+        self.try_current().expect("MemoryBoundedPooledIter: byte budget exceeded")
+    }
+
+    fn try_current(&self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        let Some(item) = self.iter.current() else {
+            return Ok(None);
+        };
+
+        Self::try_fill(&self.size_of, self.budget, &self.used, item)
+            .map(Some)
+            .ok_or(OutOfBuffers)
+    }
+
+    /// Move the iterator one position back, and return the entry at that position.
+    /// Returns `None` if the iterator was at the first entry.
+    ///
+    /// # Panics
+    /// Panics if the entry does not fit in the remaining byte budget.
+    fn prev(&mut self) -> Option<Self::Item> {
+        #[expect(clippy::expect_used, reason = "documented panic")]
+        self.try_prev().expect("MemoryBoundedPooledIter: byte budget exceeded")
+    }
+
+    fn try_prev(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        let Some(item) = self.iter.prev() else {
+            return Ok(None);
+        };
+
+        if let Some(item) = Self::try_fill(&self.size_of, self.budget, &self.used, item) {
+            Ok(Some(item))
+        } else {
+            // Return to the previous position.
+            self.iter.next();
+            Err(OutOfBuffers)
+        }
+    }
+}
+
+impl<I, BorrowedItem, F, Key, Cmp> Seekable<Key, Cmp>
+for MemoryBoundedPooledIter<I, BorrowedItem, F>
+where
+    I:                             CursorLendingIterator + Seekable<Key, Cmp>,
+    BorrowedItem:                  ?Sized + ToOwned,
+    F:                             Fn(&BorrowedItem) -> usize,
+    Key:                           ?Sized,
+    Cmp:                           Comparator<Key>,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.iter.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+    }
+}
+
+impl<I: Debug, BorrowedItem: ?Sized, F> Debug for MemoryBoundedPooledIter<I, BorrowedItem, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("MemoryBoundedPooledIter")
+            .field("iter", &self.iter)
+            .field("size_of", &"..")
+            .field("budget", &self.budget)
+            .field("used", &self.used.get())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The type of an item returned by [`MemoryBoundedPooledIter`].
+///
+/// The item's size is returned to the byte budget of the [`MemoryBoundedPooledIter`] when the
+/// `BoundedItem` is dropped.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct BoundedItem<OwnedItem> {
+    item: OwnedItem,
+    size: usize,
+    used: Rc<Cell<usize>>,
+}
+
+impl<OwnedItem> BoundedItem<OwnedItem> {
+    /// Get the size of this item, in bytes, as measured by the iterator's `size_of` function.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl<OwnedItem> Drop for BoundedItem<OwnedItem> {
+    fn drop(&mut self) {
+        self.used.set(self.used.get().saturating_sub(self.size));
+    }
+}
+
+impl<OwnedItem> Deref for BoundedItem<OwnedItem> {
+    type Target = OwnedItem;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+
+impl<OwnedItem> Borrow<OwnedItem> for BoundedItem<OwnedItem> {
+    #[inline]
+    fn borrow(&self) -> &OwnedItem {
+        &self.item
+    }
+}
+
+impl<OwnedItem> AsRef<OwnedItem> for BoundedItem<OwnedItem> {
+    #[inline]
+    fn as_ref(&self) -> &OwnedItem {
+        &self.item
+    }
+}
+
+impl<OwnedItem: Debug> Debug for BoundedItem<OwnedItem> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("BoundedItem")
+            .field("item", &self.item)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use crate::test_iter::TestIter;
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn byte_budget_respected() {
+        static DATA: [&[u8]; 5] = [&[0; 4], &[1; 4], &[2; 4], &[3; 12], &[4; 1]];
+        // Byte strings of varying lengths.
+        let blobs = SortedSliceCursor::new(DATA.as_slice(), OrdComparator);
+        let mut iter = MemoryBoundedPooledIter::<_, &[u8], _>::new(blobs, 12, |blob| blob.len());
+
+        // Three small items fit at once.
+        let mut held = Vec::new();
+        for _ in 0..3 {
+            held.push(iter.try_next().unwrap().unwrap());
+        }
+        assert_eq!(held.iter().map(BoundedItem::size).sum::<usize>(), 12);
+        assert_eq!(iter.used_bytes(), 12);
+        assert_eq!(iter.available_buffers(), 0);
+
+        // The large item does not fit, and the iterator stays put.
+        assert_eq!(iter.try_next().unwrap_err(), OutOfBuffers);
+        assert_eq!(iter.try_current().unwrap_err(), OutOfBuffers);
+        held.pop();
+        assert_eq!(iter.try_next().unwrap_err(), OutOfBuffers);
+
+        // Once every item is returned, the large item fits, but only by itself.
+        held.clear();
+        assert_eq!(iter.used_bytes(), 0);
+        let large = iter.next().unwrap();
+        assert_eq!(large.size(), 12);
+        assert_eq!(*large, [3; 12].as_slice());
+        assert_eq!(iter.try_next().unwrap_err(), OutOfBuffers);
+        assert_eq!(iter.try_prev().unwrap_err(), OutOfBuffers);
+        drop(large);
+
+        assert_eq!(*iter.next().unwrap(), [4].as_slice());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn small_items_allow_more_buffers() {
+        let data: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let count_held = |item_size: usize| {
+            let mut iter = MemoryBoundedPooledIter::<_, u8, _>::new(
+                TestIter::new(data).unwrap(),
+                8,
+                move |_: &u8| item_size,
+            );
+            let mut held = Vec::new();
+            while let Ok(Some(item)) = iter.try_next() {
+                held.push(item);
+            }
+            held.len()
+        };
+
+        assert_eq!(count_held(1), 8);
+        assert_eq!(count_held(2), 4);
+        assert_eq!(count_held(8), 1);
+    }
+}
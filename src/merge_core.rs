@@ -0,0 +1,205 @@
+#![expect(clippy::redundant_pub_crate, reason = "emphasize that this is internal")]
+
+use core::cmp::Ordering;
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::seekable::ItemToKey;
+
+
+// The parts of merging several sorted sources which are shared by `MergingIter` and
+// `TryMergingIter`. Repositioning the sources is done through a provided function, so that the
+// same logic works for both infallible and fallible seeks.
+
+/// Find the index of the source with the smallest `current` key, among the sources which are
+/// valid and for which `include` returns `true`.
+///
+/// Ties are broken in favor of the lowest index. This always terminates after one pass, even if
+/// every key compares equal.
+pub(crate) fn find_smallest<Key, Cmp, Iter>(
+    iterators:   &[Iter],
+    cmp:         &Cmp,
+    mut include: impl FnMut(usize) -> bool,
+) -> Option<usize>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: CursorLendingIterator + ItemToKey<Key>,
+{
+    let mut smallest: Option<(usize, &Key)> = None;
+
+    for (idx, iter) in iterators.iter().enumerate() {
+        if !include(idx) {
+            continue;
+        }
+        if let Some(curr_item) = iter.current() {
+            let curr_key = Iter::item_to_key(curr_item);
+            if let Some((_, smallest_key)) = smallest {
+                if cmp.cmp(curr_key, smallest_key) == Ordering::Less {
+                    // `curr_key` is smaller than the previous `smallest`'s key
+                    smallest = Some((idx, curr_key));
+                }
+            } else {
+                // de-facto `smallest`, nothing was previously found
+                smallest = Some((idx, curr_key));
+            }
+        } else {
+            // The iterator was `!valid()`, so continue.
+        }
+    }
+
+    smallest.map(|(idx, _)| idx)
+}
+
+/// Find the index of the source with the largest `current` key, among the sources which are
+/// valid.
+///
+/// Ties are broken in favor of the highest index.
+pub(crate) fn find_largest<Key, Cmp, Iter>(iterators: &[Iter], cmp: &Cmp) -> Option<usize>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: CursorLendingIterator + ItemToKey<Key>,
+{
+    let mut largest: Option<(usize, &Key)> = None;
+
+    for (idx, iter) in iterators.iter().enumerate().rev() {
+        if let Some(curr_item) = iter.current() {
+            let curr_key = Iter::item_to_key(curr_item);
+            if let Some((_, largest_key)) = largest {
+                if cmp.cmp(curr_key, largest_key) == Ordering::Greater {
+                    // `curr_key` is larger than the previous `largest`'s key
+                    largest = Some((idx, curr_key));
+                }
+            } else {
+                // de-facto `largest`, nothing was previously found
+                largest = Some((idx, curr_key));
+            }
+        } else {
+            // The iterator was `!valid()`, so continue.
+        }
+    }
+
+    largest.map(|(idx, _)| idx)
+}
+
+/// Move all sources other than the source at `current_idx` one entry strictly in front of the
+/// current entry, positioning each of them with `seek` (which should behave like
+/// [`Seekable::seek`]).
+///
+/// In strict duplicates mode, sources after the current source are instead moved non-strictly in
+/// front of it, as their entries with the current key come after the current entry.
+///
+/// Does nothing if the source at `current_idx` is not valid.
+///
+/// # Errors
+/// Returns the first error returned by `seek`. The current source is never moved, so the current
+/// entry is unaffected; since each source is positioned independently of its previous position,
+/// calling this function again repositions every source.
+///
+/// [`Seekable::seek`]: crate::Seekable::seek
+pub(crate) fn switch_to_forwards<Key, Cmp, Iter, E>(
+    iterators:         &mut [Iter],
+    cmp:               &Cmp,
+    current_idx:       usize,
+    strict_duplicates: bool,
+    mut seek:          impl FnMut(&mut Iter, &Key) -> Result<(), E>,
+) -> Result<(), E>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: CursorLendingIterator + ItemToKey<Key>,
+{
+    // Do a little game to satisfy borrowck and aliasing rules
+    let (iters, current_and_later) = iterators.split_at_mut(current_idx);
+    let Some((current_iter, other_iters)) = current_and_later.split_first_mut() else {
+        return Ok(());
+    };
+    let Some(current_item) = current_iter.current() else {
+        return Ok(());
+    };
+    let current_key = Iter::item_to_key(current_item);
+
+    for iter in iters {
+        seek(iter, current_key)?;
+
+        // `seek` provides a `geq` order, we want a strict greater-than order.
+        if iter.current().is_some_and(|item| {
+            keys_equal(cmp, current_key, Iter::item_to_key(item))
+        }) {
+            if strict_duplicates {
+                // Move past every entry with the current key.
+                while iter.next().is_some_and(|item| {
+                    keys_equal(cmp, current_key, Iter::item_to_key(item))
+                }) {}
+            } else {
+                iter.next();
+            }
+        }
+    }
+
+    for iter in other_iters {
+        seek(iter, current_key)?;
+
+        if !strict_duplicates && iter.current().is_some_and(|item| {
+            keys_equal(cmp, current_key, Iter::item_to_key(item))
+        }) {
+            iter.next();
+        }
+    }
+
+    Ok(())
+}
+
+/// Move all sources other than the source at `current_idx` one entry strictly behind the current
+/// entry, positioning each of them with `seek_before` (which should behave like
+/// [`Seekable::seek_before`]).
+///
+/// In strict duplicates mode, sources before the current source are instead moved non-strictly
+/// behind it, as their entries with the current key come before the current entry.
+///
+/// Does nothing if the source at `current_idx` is not valid.
+///
+/// # Errors
+/// Returns the first error returned by `seek_before`, with the same guarantees as
+/// [`switch_to_forwards`].
+///
+/// [`Seekable::seek_before`]: crate::Seekable::seek_before
+pub(crate) fn switch_to_backwards<Key, Cmp, Iter, E>(
+    iterators:         &mut [Iter],
+    cmp:               &Cmp,
+    current_idx:       usize,
+    strict_duplicates: bool,
+    mut seek_before:   impl FnMut(&mut Iter, &Key) -> Result<(), E>,
+) -> Result<(), E>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: CursorLendingIterator + ItemToKey<Key>,
+{
+    // Do a little game to satisfy borrowck and aliasing rules
+    let (iters, current_and_later) = iterators.split_at_mut(current_idx);
+    let Some((current_iter, other_iters)) = current_and_later.split_first_mut() else {
+        return Ok(());
+    };
+    let Some(current_item) = current_iter.current() else {
+        return Ok(());
+    };
+    let current_key = Iter::item_to_key(current_item);
+
+    for iter in iters {
+        seek_before(iter, current_key)?;
+
+        if strict_duplicates {
+            // Move to the last entry whose key is at most `current_key`.
+            while iter.next().is_some_and(|item| {
+                keys_equal(cmp, current_key, Iter::item_to_key(item))
+            }) {}
+            iter.prev();
+        }
+    }
+    for iter in other_iters {
+        seek_before(iter, current_key)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use core::iter::FusedIterator;
+use alloc::vec::Vec;
+
+use crate::{comparator::OrdComparator, merging_iter::MergingIter};
+use crate::sorted_slice_cursor::SortedVecCursor;
+
+
+/// Merge several sorted iterators of `Copy` items into one sorted iterator.
+///
+/// This is a simple façade over [`MergingIter`]: each iterator is collected into a buffer, the
+/// buffers are merged with [`OrdComparator`], and the merged items are yielded by value.
+///
+/// Each iterator must yield its items in sorted order; otherwise, the output is unspecified
+/// (though not unsafe). Items which compare equal are all yielded; among them, items from
+/// earlier iterators in `iters` are yielded first. Unlike the circular [`MergingIter`], the
+/// returned iterator is fused, and does not wrap around once every item has been yielded.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[must_use]
+pub fn merge_sorted<T, I>(iters: Vec<I>) -> impl FusedIterator<Item = T>
+where
+    T: Ord + Copy,
+    I: Iterator<Item = T>,
+{
+    let sources = iters
+        .into_iter()
+        .map(|iter| SortedVecCursor::new(iter.collect(), OrdComparator))
+        .collect();
+
+    MergingIter::<T, _, SortedVecCursor<T, _>>::new(sources, OrdComparator)
+        .into_fused_iter::<T>()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use super::*;
+
+
+    #[test]
+    fn merge_three_vecs() {
+        let merged: Vec<u32> = merge_sorted(vec![
+            vec![1, 4, 7, 10].into_iter(),
+            vec![2, 4, 8].into_iter(),
+            vec![0, 3, 11, 12].into_iter(),
+        ]).collect();
+
+        assert_eq!(merged, [0, 1, 2, 3, 4, 4, 7, 8, 10, 11, 12]);
+    }
+
+    #[test]
+    fn merge_empty() {
+        assert_eq!(merge_sorted::<u8, vec::IntoIter<u8>>(vec![]).next(), None);
+        assert_eq!(merge_sorted(vec![Vec::<u8>::new().into_iter()]).next(), None);
+    }
+
+    #[test]
+    fn merge_is_fused() {
+        let mut merged = merge_sorted(vec![vec![1_u8, 3].into_iter(), vec![2].into_iter()]);
+        assert_eq!(merged.by_ref().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(merged.next(), None);
+        assert_eq!(merged.next(), None);
+    }
+}
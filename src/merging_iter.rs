@@ -1,17 +1,58 @@
-use core::{cmp::Ordering, marker::PhantomData, num::NonZero};
-use alloc::vec::Vec;
+use core::{borrow::Borrow, cmp::{Ordering, Reverse}, convert::Infallible, marker::PhantomData};
+use core::{mem, num::NonZero};
+use core::{fmt::{Debug, Formatter, Result as FmtResult}, iter::{self, FusedIterator}};
+use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
 
-use crate::comparator::Comparator;
-use crate::cursor::CursorLendingIterator;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IntoParallelRefMutIterator as _, ParallelIterator as _};
+
+#[cfg(feature = "std")]
+use std::{panic, thread};
+
+#[cfg(feature = "std")]
+use crate::{pooled_iter::PooledIter, sorted_slice_cursor::SortedVecCursor};
+
+use crate::{comparator::{keys_equal, Comparator}, distinct_keys_iter::DistinctKeysIter};
+use crate::comparator_ext::{ComparatorExt as _, Reversed};
+use crate::{cursor::{drain_from_current, CursorLendingIterator}, indexed::CountBetweenHint};
+use crate::merge_core;
 use crate::lending_iterator_support::{LendItem, LentItem};
 use crate::seekable::{ItemToKey, Seekable};
 use crate::seekable_iterators::SeekableLendingIterator;
+use crate::try_next::{NotReady, TryNext};
 
 
 #[derive(Debug, Clone, Copy)]
 enum Direction {
     Forwards,
     Backwards,
+    /// Backwards, as positioned by `MergingIter::settle_backwards` immediately after a seek.
+    /// Switching forwards from this state only needs to undo that positioning.
+    SettledBackwards,
+}
+
+/// The callback set by [`MergingIter::set_on_source_exhausted`].
+struct ExhaustedCallback(Box<dyn FnMut(usize) + Send + Sync>);
+
+impl Debug for ExhaustedCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("..")
+    }
+}
+
+/// The strategy a [`MergingIter`] uses to combine its sources.
+///
+/// See [`MergingIter::new_auto`] and [`MergingIter::strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub enum MergeStrategy {
+    /// Every source is positioned and compared against the others, as described in the
+    /// [`MergingIter`] documentation. This works for any sources.
+    Merge,
+    /// The sources are known to be sorted by their key ranges, and their key ranges are known to
+    /// be disjoint; only one source is accessed at a time, and the sources are iterated over one
+    /// after another.
+    Concat,
 }
 
 /// A [`MergingIter`] takes several [`SeekableLendingIterator`]s as input, and iterates over the
@@ -59,8 +100,8 @@ enum Direction {
 /// - Set direction to forwards:
 ///   - [`MergingIter::new`]
 ///   - [`MergingIter::reset`]
-///   - [`MergingIter::seek`]
-///   - [`MergingIter::seek_to_first`]
+///   - [`MergingIter::seek`] (see [`MergingIter::set_prefer_backward`])
+///   - [`MergingIter::seek_to_first`] (see [`MergingIter::set_prefer_backward`])
 /// - Set direction to backwards:
 ///   - [`MergingIter::seek_before`]
 ///   - [`MergingIter::seek_to_last`]
@@ -68,14 +109,33 @@ enum Direction {
 /// The following methods do not impact and are not impacted by the direction:
 /// - [`MergingIter::valid`]
 /// - [`MergingIter::current`]
+///
+/// # Strict duplicates mode
+/// A `MergingIter` created with [`MergingIter::new_strict_duplicates`] never skips items with
+/// duplicate keys when switching direction. In this mode, the merged collection is ordered by
+/// key, then by the index of the source iterator (see [`MergingIter::current_source`]), then by
+/// position within that source; `next` and `prev` always move exactly one position in that order.
+///
+/// This comes at the cost of extra work when switching direction: each source iterator whose
+/// index is less than that of the current source walks one entry at a time over its entries
+/// whose keys equal the current key.
+///
+/// # Ties between sources
+/// Among source iterators whose current keys compare equal, forwards iteration selects the
+/// iterator with the lowest index first, and backwards iteration selects the iterator with the
+/// highest index first. In particular, if every key compares equal (as with a
+/// [`UnitComparator`]), then iterating forwards yields each source's items in turn, in the order
+/// of the sources.
+///
+/// [`UnitComparator`]: crate::UnitComparator
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 pub struct MergingIter<Key: ?Sized, Cmp, Iter> {
-    iterators:    Vec<Iter>,
-    cmp:          Cmp,
+    iterators:         Vec<Iter>,
+    cmp:               Cmp,
     /// Ensures that the implementation of the iterator and comparator aren't switched
-    /// mid-iteration by a pathological user
-    _key:         PhantomData<Key>,
+    /// mid-iteration by a pathological user.
+    _key:              PhantomData<Key>,
     /// If `Some`, the value should be 1 more than the index of the current iterator.
     ///
     /// Additionally, an invariant is: after calling any public method of `Self` (notably
@@ -83,13 +143,46 @@ pub struct MergingIter<Key: ?Sized, Cmp, Iter> {
     /// is `None`, or the iterator it refers to is `valid()`.
     ///
     /// In the former case, no iterator in `self.iterators` should be `valid()`.
-    current_iter: Option<NonZero<usize>>,
+    current_iter:      Option<NonZero<usize>>,
     /// If `current_iter` is `Some` and `direction` is `Forwards`, then the non-`current_iter`
     /// iterators are non-strictly in front of `current_iter`. If `Backwards`, the
     /// non-`current_iter` iterators are non-strictly behind `current_iter`.
     ///
     /// (Non-strictly is specified to clarify behavior for duplicate keys.)
-    direction:    Direction,
+    direction:         Direction,
+    /// Whether items with duplicate keys must not be skipped when switching direction.
+    /// See the type-level documentation.
+    strict_duplicates: bool,
+    /// If `Concat`, then `self.iterators` is sorted by key range, and the key ranges are
+    /// disjoint.
+    strategy:          MergeStrategy,
+    /// A reusable buffer for the sources returned by [`MergingIter::next_with_contributors`].
+    contributors:      Vec<usize>,
+    /// The maximum number of items that [`MergingIter::try_next`] may yield while a source is
+    /// not ready.
+    readahead_cap:     usize,
+    /// Either empty, or has one entry per source. An entry is `Some` if and only if the source's
+    /// current item was already yielded, but the source was not yet ready to move past it; the
+    /// entry then holds the number of items yielded since.
+    ///
+    /// Lagging sources are ignored when finding the smallest iterator. Only
+    /// [`MergingIter::try_next`] leaves sources lagging, and only when moving forwards.
+    lagging:           Vec<Option<usize>>,
+    /// The number of times each iterator has been selected as the smallest iterator.
+    selection_counts:  Vec<usize>,
+    /// Called with the index of each source which is found to be exhausted while moving
+    /// forwards.
+    on_exhausted:      Option<ExhaustedCallback>,
+    /// Either empty, or has one entry per source, indicating whether `on_exhausted` has already
+    /// been called for that source.
+    exhausted:         Vec<bool>,
+    /// The ordinal most recently returned by [`MergingIter::next_with_ordinal`].
+    run_ordinal:       usize,
+    /// Whether `seek` and `seek_to_first` should leave the iterator in the backwards direction.
+    /// See [`MergingIter::set_prefer_backward`].
+    prefer_backward:   bool,
+    /// The number of times the iterator has switched direction in `next` or `prev`.
+    switches:          usize,
 }
 
 impl<Key, Cmp, Iter> MergingIter<Key, Cmp, Iter>
@@ -119,11 +212,660 @@ where
         );
 
         Self {
+            selection_counts:  vec![0; iterators.len()],
             iterators,
             cmp,
-            _key:         PhantomData,
-            current_iter: None,
-            direction:    Direction::Forwards,
+            _key:              PhantomData,
+            current_iter:      None,
+            direction:         Direction::Forwards,
+            strict_duplicates: false,
+            strategy:          MergeStrategy::Merge,
+            contributors:      Vec::new(),
+            readahead_cap:     usize::MAX,
+            lagging:           Vec::new(),
+            on_exhausted:      None,
+            exhausted:         Vec::new(),
+            run_ordinal:       0,
+            prefer_backward:   false,
+            switches:          0,
+        }
+    }
+
+    /// Create a new [`MergingIter`] whose [`try_next`] method yields at most `readahead_cap`
+    /// items past a source which is not ready, before waiting for that source.
+    ///
+    /// This bounds how far ahead of a slow source the merge may read. With a cap of `0`,
+    /// [`try_next`] never skips a source which is not ready. Other constructors do not cap the
+    /// read-ahead. See [`MergingIter::new`] for the requirements on the comparators.
+    ///
+    /// # Panics
+    /// Panics if the length of `iterators` is `usize::MAX`.
+    ///
+    /// [`try_next`]: MergingIter::try_next
+    #[inline]
+    #[must_use]
+    pub fn new_with_readahead_cap(iterators: Vec<Iter>, cmp: Cmp, readahead_cap: usize) -> Self {
+        Self {
+            readahead_cap,
+            ..Self::new(iterators, cmp)
+        }
+    }
+
+    /// Create a new [`MergingIter`] which never skips items with duplicate keys when switching
+    /// direction.
+    ///
+    /// See the type-level documentation on strict duplicates mode, and see
+    /// [`MergingIter::new`] for the requirements on the comparators.
+    ///
+    /// # Panics
+    /// Panics if the length of `iterators` is `usize::MAX`.
+    #[inline]
+    #[must_use]
+    pub fn new_strict_duplicates(iterators: Vec<Iter>, cmp: Cmp) -> Self {
+        Self {
+            strict_duplicates: true,
+            ..Self::new(iterators, cmp)
+        }
+    }
+
+    /// Create a new [`MergingIter`], which uses a faster strategy if the key ranges of the
+    /// provided iterators turn out to be disjoint.
+    ///
+    /// At construction, the first and last key of each iterator are found (with `seek_to_first`
+    /// and `seek_to_last`). If no two iterators have overlapping key ranges, the iterators are
+    /// reordered by their key ranges, and the [`MergeStrategy::Concat`] strategy is used: only
+    /// one iterator is accessed at a time, and iteration proceeds from one iterator to the next.
+    /// Source indices (as in [`MergingIter::current_source`]) then refer to the reordered
+    /// sources.
+    /// Otherwise, the iterators are left in the provided order, and the usual
+    /// [`MergeStrategy::Merge`] strategy is used. The chosen strategy can be queried with
+    /// [`MergingIter::strategy`].
+    ///
+    /// Empty iterators do not overlap with any other iterator. Every iterator is reset before
+    /// this function returns.
+    ///
+    /// See [`MergingIter::new`] for the requirements on the comparators.
+    ///
+    /// # Panics
+    /// Panics if the length of `iterators` is `usize::MAX`.
+    #[must_use]
+    pub fn new_auto(mut iterators: Vec<Iter>, cmp: Cmp) -> Self {
+        for iter in &mut iterators {
+            iter.seek_to_first();
+        }
+
+        // Sort the indices of the iterators by their first keys, with empty iterators last.
+        let mut order: Vec<usize> = (0..iterators.len()).collect();
+        order.sort_by(|&lhs, &rhs| {
+            let lhs = iterators.get(lhs).and_then(Iter::current);
+            let rhs = iterators.get(rhs).and_then(Iter::current);
+            match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => cmp.cmp(Iter::item_to_key(lhs), Iter::item_to_key(rhs)),
+                (Some(_), None)        => Ordering::Less,
+                (None, Some(_))        => Ordering::Greater,
+                (None, None)           => Ordering::Equal,
+            }
+        });
+
+        let disjoint = order.windows(2).all(|pair| {
+            let &[earlier, later] = pair else {
+                return true;
+            };
+            if let Some(earlier) = iterators.get_mut(earlier) {
+                earlier.seek_to_last();
+            }
+            if let Some(later) = iterators.get_mut(later) {
+                later.seek_to_first();
+            }
+
+            let earlier = iterators.get(earlier).and_then(Iter::current);
+            let later = iterators.get(later).and_then(Iter::current);
+            match (earlier, later) {
+                (Some(earlier), Some(later)) => {
+                    cmp.cmp(Iter::item_to_key(earlier), Iter::item_to_key(later))
+                        == Ordering::Less
+                }
+                // `later` is empty (and so is every iterator after it).
+                _ => true,
+            }
+        });
+
+        for iter in &mut iterators {
+            iter.reset();
+        }
+
+        if disjoint {
+            let mut slots: Vec<Option<Iter>> = iterators.into_iter().map(Some).collect();
+            let sorted_iterators = order
+                .into_iter()
+                .filter_map(|idx| slots.get_mut(idx).and_then(Option::take))
+                .collect();
+
+            Self {
+                strategy: MergeStrategy::Concat,
+                ..Self::new(sorted_iterators, cmp)
+            }
+        } else {
+            Self::new(iterators, cmp)
+        }
+    }
+
+    /// Get the strategy used to combine the sources.
+    ///
+    /// This is [`MergeStrategy::Merge`] unless the `MergingIter` was created by
+    /// [`MergingIter::new_auto`] and its sources' key ranges were disjoint.
+    #[inline]
+    #[must_use]
+    pub const fn strategy(&self) -> MergeStrategy {
+        self.strategy
+    }
+
+    /// Get the maximum number of items which [`MergingIter::try_next`] may yield past a source
+    /// which is not ready.
+    ///
+    /// See [`MergingIter::new_with_readahead_cap`].
+    #[inline]
+    #[must_use]
+    pub const fn readahead_cap(&self) -> usize {
+        self.readahead_cap
+    }
+
+    /// Get the index of the iterator whose entry the `MergingIter` is currently at, if the
+    /// `MergingIter` is [valid].
+    ///
+    /// Sources are indexed in their current order. This is the order of the `Vec` of iterators
+    /// provided to the constructor, unless the sources were reordered: by
+    /// [`MergingIter::new_auto`] when it chooses the [`MergeStrategy::Concat`] strategy, by
+    /// [`MergingIter::reorder_sources_by_frequency`], or by [`MergingIter::push_sorted`].
+    ///
+    /// [valid]: MergingIter::valid
+    #[inline]
+    #[must_use]
+    pub fn current_source(&self) -> Option<usize> {
+        self.current_iter.map(|current_idx| current_idx.get() - 1)
+    }
+
+    /// Get the number of times each source has provided the smallest entry while moving
+    /// forwards, indexed in the current order of the sources.
+    ///
+    /// Only [`next`] and [`try_next`] are counted; seeking to an entry does not count as
+    /// selecting its source.
+    ///
+    /// See [`MergingIter::reorder_sources_by_frequency`].
+    ///
+    /// [`next`]: MergingIter::next
+    /// [`try_next`]: MergingIter::try_next
+    #[inline]
+    #[must_use]
+    pub fn selection_counts(&self) -> &[usize] {
+        &self.selection_counts
+    }
+
+    /// Get the number of times that [`next`] or [`prev`] has had to switch the direction of
+    /// iteration, which is the main overhead of iterating in both directions.
+    ///
+    /// See the type-level documentation and [`MergingIter::set_prefer_backward`].
+    ///
+    /// [`next`]: MergingIter::next
+    /// [`prev`]: MergingIter::prev
+    #[inline]
+    #[must_use]
+    pub const fn direction_switches(&self) -> usize {
+        self.switches
+    }
+
+    /// Set whether [`seek`], [`seek_to_first`], and `seek_parallel` (with the `rayon` feature)
+    /// leave the iterator in the backwards direction, so that a following [`prev`] does not need
+    /// to switch direction. This is disabled by default.
+    ///
+    /// By default, `seek`, `seek_to_first`, and `seek_parallel` position the source iterators for
+    /// forwards iteration, and the first `prev` afterwards must reposition every source with
+    /// [`seek_before`]. When enabled, they instead reposition the sources for backwards
+    /// iteration as part of the seek; since the current entry has the smallest key which is at
+    /// least the seek's bound, this only requires moving each other source back by one entry,
+    /// which is cheaper than a `seek_before`. This suits backward-heavy workloads which seek and
+    /// then iterate with `prev`; in exchange, a `next` immediately after a seek must switch
+    /// direction, which moves each of those sources forwards by one entry again. The merged
+    /// collection and its order are unchanged (unlike a merge with reversed comparators, such as
+    /// [`MergingIter::new_descending`]), and [`reset`], [`seek_before`], and [`seek_to_last`] are
+    /// unaffected. This has no effect with [`MergeStrategy::Concat`], which does not switch
+    /// direction.
+    ///
+    /// # Duplicate keys
+    /// A `next` immediately after a seek yields the same entries as it would by default,
+    /// including every entry with the current key.
+    ///
+    /// Outside strict duplicates mode, a source whose entry after the seek has a key equal to the
+    /// current key is left at that entry. As a result, `prev` after a seek first yields the other
+    /// entries with the current key, whereas by default they are skipped by the switch in
+    /// direction (see the type-level documentation). In strict duplicates mode, such a source is
+    /// moved strictly behind the current key instead, as its entries with the current key come
+    /// after the current entry; this matches the default behavior.
+    ///
+    /// [`seek`]: MergingIter::seek
+    /// [`seek_before`]: MergingIter::seek_before
+    /// [`seek_to_first`]: MergingIter::seek_to_first
+    /// [`seek_to_last`]: MergingIter::seek_to_last
+    /// [`prev`]: MergingIter::prev
+    /// [`reset`]: MergingIter::reset
+    #[inline]
+    pub const fn set_prefer_backward(&mut self, prefer_backward: bool) {
+        self.prefer_backward = prefer_backward;
+    }
+
+    /// Get whether [`seek`] and [`seek_to_first`] leave the iterator in the backwards direction.
+    /// See [`MergingIter::set_prefer_backward`].
+    ///
+    /// [`seek`]: MergingIter::seek
+    /// [`seek_to_first`]: MergingIter::seek_to_first
+    #[inline]
+    #[must_use]
+    pub const fn prefers_backward(&self) -> bool {
+        self.prefer_backward
+    }
+
+    /// Reorder the sources so that the sources which have most often provided the smallest entry
+    /// are checked first, which can improve cache locality when one source dominates.
+    ///
+    /// The sources are stably sorted by their [`selection_counts`], in decreasing order. The
+    /// position of the iterator is preserved, and the counts are kept.
+    ///
+    /// Source indices refer to the new order afterwards, which affects
+    /// [`MergingIter::current_source`] and [`MergingIter::next_with_contributors`]. Since ties
+    /// between sources are broken by source index, the order in which items with duplicate keys
+    /// are yielded may change (which matters for adapters like [`DistinctKeysIter`] that give
+    /// priority to lower-indexed sources); items with distinct keys are yielded in the same
+    /// order as before.
+    ///
+    /// This has no effect with the [`MergeStrategy::Concat`] strategy, whose sources must stay
+    /// sorted by their key ranges.
+    ///
+    /// [`selection_counts`]: MergingIter::selection_counts
+    pub fn reorder_sources_by_frequency(&mut self) {
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            return;
+        }
+
+        let mut order: Vec<usize> = (0..self.iterators.len()).collect();
+        order.sort_by_key(|&idx| Reverse(self.selection_counts.get(idx).copied()));
+
+        let mut slots: Vec<Option<Iter>> = mem::take(&mut self.iterators)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.iterators = order
+            .iter()
+            .filter_map(|&idx| slots.get_mut(idx).and_then(Option::take))
+            .collect();
+        self.selection_counts = order
+            .iter()
+            .filter_map(|&idx| self.selection_counts.get(idx).copied())
+            .collect();
+        if !self.lagging.is_empty() {
+            self.lagging = order
+                .iter()
+                .filter_map(|&idx| self.lagging.get(idx).copied())
+                .collect();
+        }
+        if !self.exhausted.is_empty() {
+            self.exhausted = order
+                .iter()
+                .filter_map(|&idx| self.exhausted.get(idx).copied())
+                .collect();
+        }
+
+        if let Some(current_source) = self.current_source() {
+            let new_idx = order.iter().position(|&idx| idx == current_source);
+            self.current_iter = new_idx.and_then(|new_idx| NonZero::new(new_idx + 1));
+        }
+    }
+
+    /// Add a source, inserting it among the sources so that they stay sorted by the first key of
+    /// each source.
+    ///
+    /// `min_key` must compare equal to the key of the first entry of `iter`, if `iter` is
+    /// nonempty. The position is found by binary search, seeking each probed source to its first
+    /// entry; if the sources were already sorted by their first keys (as when every source was
+    /// added with `push_sorted`, or ordered by [`MergingIter::new_auto`]), they remain sorted,
+    /// with empty sources last. The new source is placed after any source with an equal first
+    /// key, so among items with equal keys, the new source's items come after theirs (see the
+    /// type-level documentation on ties). If the sources were not sorted, the position is
+    /// unspecified, but the merge is still correct.
+    ///
+    /// With the [`MergeStrategy::Concat`] strategy, the strategy is kept if the new source's key
+    /// range is disjoint from those of its neighbors (which requires seeking the new source to
+    /// its last entry); otherwise, the strategy becomes [`MergeStrategy::Merge`].
+    ///
+    /// Source indices at or after the new source's index are shifted by one, which affects
+    /// [`MergingIter::current_source`] and [`MergingIter::selection_counts`]; the new source
+    /// starts with a selection count of `0`. The `MergingIter` and every source are [`reset`]
+    /// before this method returns.
+    ///
+    /// # Panics
+    /// Panics if there are already `usize::MAX - 1` sources.
+    ///
+    /// [`reset`]: Seekable::reset
+    pub fn push_sorted(&mut self, mut iter: Iter, min_key: &Key) {
+        assert_ne!(
+            self.iterators.len(),
+            usize::MAX - 1,
+            "Cannot create a MergingIter over `usize::MAX`-many iterators",
+        );
+
+        // Find the first source whose first key is greater than `min_key`, treating empty
+        // sources as greater than every key.
+        let mut low = 0;
+        let mut high = self.iterators.len();
+        while low < high {
+            let mid = low + (high - low).div_euclid(2);
+            let goes_before_mid = self.iterators.get_mut(mid).is_none_or(|source| {
+                source.seek_to_first();
+                source.current().is_none_or(|item| {
+                    self.cmp.cmp(Iter::item_to_key(item), min_key) == Ordering::Greater
+                })
+            });
+
+            if goes_before_mid {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        let insert_idx = low;
+
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            let after_previous = insert_idx.checked_sub(1)
+                .and_then(|prev_idx| self.iterators.get_mut(prev_idx))
+                .is_none_or(|previous| {
+                    previous.seek_to_last();
+                    previous.current().is_none_or(|item| {
+                        self.cmp.cmp(Iter::item_to_key(item), min_key) == Ordering::Less
+                    })
+                });
+
+            iter.seek_to_last();
+            let before_next = match (iter.current(), self.iterators.get_mut(insert_idx)) {
+                (Some(new_item), Some(next)) => {
+                    next.seek_to_first();
+                    next.current().is_none_or(|next_item| {
+                        let new_key = Iter::item_to_key(new_item);
+                        self.cmp.cmp(new_key, Iter::item_to_key(next_item)) == Ordering::Less
+                    })
+                }
+                _ => true,
+            };
+
+            if !(after_previous && before_next) {
+                self.strategy = MergeStrategy::Merge;
+            }
+        }
+
+        self.iterators.insert(insert_idx, iter);
+        self.selection_counts.insert(insert_idx, 0);
+        if !self.exhausted.is_empty() {
+            self.exhausted.insert(insert_idx, false);
+        }
+        self.reset();
+    }
+
+    /// Set a callback which is called with the index of each source once that source is
+    /// exhausted, such as to release resources held for the source as early as possible.
+    ///
+    /// A source is exhausted once the `MergingIter` has moved forwards (with `next` or
+    /// [`try_next`]) past every entry of that source, so that continuing forwards would never
+    /// reach the source again before wrapping around. Empty sources are exhausted by the first
+    /// forwards motion. The check is performed after each forwards motion, which takes time
+    /// linear in the number of sources; no check is performed while no callback is set.
+    ///
+    /// The callback is called at most once per source over the lifetime of the `MergingIter`,
+    /// even if the source is revisited by a seek, a reset, or `prev`. Setting a new callback
+    /// replaces the previous one, but does not cause sources which were already reported to be
+    /// reported again. Source indices refer to the current order of the sources (see
+    /// [`MergingIter::reorder_sources_by_frequency`]).
+    ///
+    /// The callback must be `Send` and `Sync` so that the `MergingIter` remains so.
+    ///
+    /// [`try_next`]: MergingIter::try_next
+    pub fn set_on_source_exhausted<F>(&mut self, callback: F)
+    where
+        F: FnMut(usize) + Send + Sync + 'static,
+    {
+        self.on_exhausted = Some(ExhaustedCallback(Box::new(callback)));
+    }
+
+    /// Get the smallest key among the current entries of the sources, without moving any
+    /// source.
+    ///
+    /// If the `MergingIter` was last moved forwards (or by a method which sets the direction to
+    /// forwards, like `seek` without [`set_prefer_backward`]), this is the key of [`current`].
+    /// After moving backwards, the other sources are positioned behind the current entry, so
+    /// this may be smaller. With the [`MergeStrategy::Concat`] strategy, only the current source
+    /// is positioned, so this is always the key of [`current`].
+    ///
+    /// Returns `None` if the `MergingIter` is not [valid].
+    ///
+    /// [`current`]: MergingIter::current
+    /// [`set_prefer_backward`]: MergingIter::set_prefer_backward
+    /// [valid]: MergingIter::valid
+    #[must_use]
+    pub fn peek_min_key(&self) -> Option<&Key> {
+        self.peek_extreme_key(Ordering::Less)
+    }
+
+    /// Get the largest key among the current entries of the sources, without moving any source.
+    ///
+    /// If the `MergingIter` was last moved backwards (or by a method which sets the direction to
+    /// backwards, like `seek_before`), this is the key of [`current`]. After moving forwards, the
+    /// other sources are positioned in front of the current entry, so this may be larger. With
+    /// the [`MergeStrategy::Concat`] strategy, only the current source is positioned, so this is
+    /// always the key of [`current`].
+    ///
+    /// Returns `None` if the `MergingIter` is not [valid].
+    ///
+    /// [`current`]: MergingIter::current
+    /// [valid]: MergingIter::valid
+    #[must_use]
+    pub fn peek_max_key(&self) -> Option<&Key> {
+        self.peek_extreme_key(Ordering::Greater)
+    }
+
+    /// Get the key among the current entries of the sources which is most `extreme` (`Less` for
+    /// the minimum, `Greater` for the maximum), if `self` is valid.
+    fn peek_extreme_key(&self, extreme: Ordering) -> Option<&Key> {
+        let current_key = Iter::item_to_key(self.get_current_iter_ref()?.current()?);
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            return Some(current_key);
+        }
+
+        self.iterators
+            .iter()
+            .filter_map(|iter| iter.current().map(Iter::item_to_key))
+            .reduce(|best, key| {
+                if self.cmp.cmp(key, best) == extreme {
+                    key
+                } else {
+                    best
+                }
+            })
+    }
+
+    /// Get the comparator which the `MergingIter` uses to order the keys of its sources.
+    ///
+    /// Adapters built on top of a `MergingIter` can use this to compare keys consistently with
+    /// the merge.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+}
+
+impl<Key, Cmp, Iter> MergingIter<Key, Reversed<Cmp>, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Reversed<Cmp>> + ItemToKey<Key>,
+{
+    /// Create a new [`MergingIter`] which iterates forwards over the union of its sources in
+    /// descending order of `cmp`, by merging with the [`Reversed`] comparator.
+    ///
+    /// This avoids iterating backwards (with the slower [`prev`] path) over an ascending merge.
+    /// The sources must themselves be sorted in descending order of `cmp`; that is, they must be
+    /// seekable with `Reversed<Cmp>`, as with a [`SortedSliceCursor`] over a descending slice
+    /// created with `cmp.reversed()`. Consequently, every seek is also reversed: for instance,
+    /// `seek(key)` moves to the greatest key less than or equal to `key`.
+    ///
+    /// See [`MergingIter::new`] for the requirements on the comparators.
+    ///
+    /// # Panics
+    /// Panics if the length of `iterators` is `usize::MAX`.
+    ///
+    /// [`prev`]: MergingIter::prev
+    /// [`SortedSliceCursor`]: crate::SortedSliceCursor
+    #[inline]
+    #[must_use]
+    pub fn new_descending(iterators: Vec<Iter>, cmp: Cmp) -> Self {
+        Self::new(iterators, cmp.reversed())
+    }
+}
+
+impl<Key, Cmp, Iter> MergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized + ToOwned,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    /// Convert the `MergingIter` into an iterator which, when iterating forwards, yields only
+    /// the first item of each group of items with equal keys.
+    ///
+    /// Among items with equal keys, the item yielded is the one from the lowest-indexed source
+    /// (in the current order of the sources; see [`MergingIter::current_source`]) containing
+    /// that key. If that source contains the key multiple times, its first such item is yielded.
+    ///
+    /// See [`DistinctKeysIter`] for more.
+    #[inline]
+    #[must_use]
+    pub fn distinct_keys(self) -> DistinctKeysIter<Key, Cmp, Iter> {
+        DistinctKeysIter::new(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Key, Cmp, Iter> MergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{
+    /// Wrap the `MergingIter` in a [`PooledIter`] which can lend out up to `num_buffers` owned
+    /// items at a time.
+    ///
+    /// This is equivalent to [`PooledIter::new`]; the `BorrowedItem` type of the pool is
+    /// inferred from the items of the sources (or may be provided explicitly). See
+    /// [`PooledIter::new`] for the requirements on the number of buffers used.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    #[must_use]
+    pub fn into_pooled<BorrowedItem>(self, num_buffers: usize) -> PooledIter<Self, BorrowedItem>
+    where
+        BorrowedItem:                     ToOwned,
+        BorrowedItem::Owned:              Default,
+        for<'lend> LentItem<'lend, Iter>: Borrow<BorrowedItem>,
+    {
+        PooledIter::new(self, num_buffers)
+    }
+
+    /// Merge the provided sources in parallel, by splitting them into groups which are each
+    /// merged on a separate thread, and then merging the results.
+    ///
+    /// The sources are split into at most `num_threads` contiguous groups of nearly equal size
+    /// (a `num_threads` of `0` is treated as `1`). Each group is merged on a scoped worker thread
+    /// into an intermediate `Vec` of owned copies of its items, and the returned `MergingIter`
+    /// merges those intermediates, each wrapped in a [`SortedVecCursor`] (which seeks by binary
+    /// search). Since the groups are contiguous, ties between equal keys are broken in the same
+    /// order as by a serial merge of `iterators`, and the returned iterator yields the same items
+    /// in the same order.
+    ///
+    /// Every item of every source is copied once, so this is only worthwhile if moving through
+    /// the sources is expensive compared to copying their items.
+    ///
+    /// # Panics
+    /// If a worker thread panics, the panic is propagated.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[must_use]
+    pub fn parallel_premerge<Borrowed>(
+        iterators:   Vec<Iter>,
+        cmp:         Cmp,
+        num_threads: usize,
+    ) -> MergingIter<Key, Cmp, SortedVecCursor<Borrowed::Owned, Cmp>>
+    where
+        Cmp:                              Clone + Send,
+        Iter:                             Send,
+        Borrowed:                         ?Sized + ToOwned,
+        Borrowed::Owned:                  Borrow<Key> + Send,
+        for<'lend> LentItem<'lend, Iter>: Borrow<Borrowed>,
+    {
+        let group_size = iterators.len().div_ceil(num_threads.max(1)).max(1);
+
+        let mut groups = Vec::new();
+        let mut sources = iterators.into_iter().peekable();
+        while sources.peek().is_some() {
+            groups.push(sources.by_ref().take(group_size).collect::<Vec<_>>());
+        }
+
+        let intermediates: Vec<Vec<Borrowed::Owned>> = thread::scope(|scope| {
+            let handles: Vec<_> = groups
+                .into_iter()
+                .map(|group| {
+                    let group_cmp = cmp.clone();
+                    scope.spawn(move || {
+                        let mut out = Vec::new();
+                        Self::new(group, group_cmp).drain_into::<Borrowed>(&mut out);
+                        out
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap_or_else(|payload| panic::resume_unwind(payload)))
+                .collect()
+        });
+
+        let intermediates = intermediates
+            .into_iter()
+            .map(|items| SortedVecCursor::new(items, cmp.clone()))
+            .collect();
+        MergingIter::new(intermediates, cmp)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<Key, Cmp, Iter> MergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized + Sync,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key> + Send,
+{
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`, seeking all of the source iterators in parallel with [`rayon`].
+    ///
+    /// The resulting position is identical to the position that [`MergingIter::seek`] would
+    /// reach. Seeking in parallel is only worthwhile if there are many source iterators, or if
+    /// their `seek` implementations are expensive; otherwise, prefer [`MergingIter::seek`].
+    ///
+    /// [`rayon`]: https://docs.rs/rayon/1
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    pub fn seek_parallel(&mut self, min_bound: &Key) {
+        self.lagging.clear();
+        self.iterators.par_iter_mut().for_each(|iter| iter.seek(min_bound));
+
+        self.find_smallest_iter();
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            self.direction = Direction::Forwards;
+            self.concat_reset_others();
+        } else {
+            self.settle_direction_after_seek();
         }
     }
 }
@@ -134,6 +876,408 @@ where
     Cmp:  Comparator<Key>,
     Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
 {
+    /// Move the iterator to the smallest key which is greater or equal than the current key of
+    /// `other`.
+    ///
+    /// This is useful for aligning the `MergingIter` with a driving iterator, as when joining
+    /// against it. If `other` is not [valid], then this iterator is [reset].
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    /// [reset]: Seekable::reset
+    pub fn seek_to_current_of<J>(&mut self, other: &J)
+    where
+        J: CursorLendingIterator + ItemToKey<Key>,
+    {
+        if let Some(item) = other.current() {
+            self.seek(J::item_to_key(item));
+        } else {
+            self.reset();
+        }
+    }
+
+    /// Count the entries whose keys are in `lower..upper`.
+    ///
+    /// If every source provides a [`count_between_hint`], the hints are summed, and no source is
+    /// moved. Otherwise, the merged collection is scanned, as with
+    /// [`SeekableLendingIteratorExt::count_between`]; the iterator is then left at the first
+    /// entry whose key is at or after `upper`, if any.
+    ///
+    /// Entries with equal keys in different sources are each counted, matching the entries
+    /// yielded by iteration.
+    ///
+    /// [`count_between_hint`]: CountBetweenHint::count_between_hint
+    /// [`SeekableLendingIteratorExt::count_between`]: crate::SeekableLendingIteratorExt::count_between
+    #[must_use]
+    pub fn count_between(&mut self, lower: &Key, upper: &Key) -> usize
+    where
+        Iter: CountBetweenHint<Key, Cmp>,
+    {
+        let hinted = self.iterators
+            .iter()
+            .map(|iter| iter.count_between_hint(&self.cmp, lower, upper))
+            .sum::<Option<usize>>();
+        if let Some(count) = hinted {
+            return count;
+        }
+
+        self.seek(lower);
+        let mut count = 0;
+        while self.current().is_some_and(|item| {
+            self.cmp.cmp(Self::item_to_key(item), upper) == Ordering::Less
+        }) {
+            count += 1;
+            self.next();
+        }
+        count
+    }
+
+    /// Clear `out`, and then move forwards through the rest of the merged collection, appending
+    /// an owned copy of each item to `out`.
+    ///
+    /// If the iterator is [valid], the current item is the first item appended; otherwise,
+    /// iteration starts from the first entry of the collection. Afterwards, the iterator is
+    /// `!valid()`.
+    ///
+    /// The allocation of `out`, and of any items already in `out`, is reused where possible,
+    /// which avoids allocating a fresh `Vec` for each query in a hot loop.
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    pub fn drain_into<Borrowed>(&mut self, out: &mut Vec<Borrowed::Owned>)
+    where
+        Borrowed:                         ?Sized + ToOwned,
+        for<'lend> LentItem<'lend, Iter>: Borrow<Borrowed>,
+    {
+        let mut len = 0;
+
+        if !self.valid() {
+            self.next();
+        }
+
+        loop {
+            let Some(item) = self.current() else {
+                break;
+            };
+            if let Some(slot) = out.get_mut(len) {
+                item.borrow().clone_into(slot);
+            } else {
+                out.push(item.borrow().to_owned());
+            }
+            len += 1;
+
+            // The lent item might implement `Drop`, so it must be dropped before moving on.
+            drop(item);
+            self.next();
+        }
+
+        out.truncate(len);
+    }
+
+    /// Move forwards through the rest of the merged collection, and return each run of
+    /// consecutive equal keys as a pair of an owned copy of the key and the run's length.
+    ///
+    /// Since the merge is sorted, each key appears in exactly one pair, whose count is the
+    /// multiplicity of that key across all sources; this is useful for histogram-like analysis
+    /// of duplicates. Keys are compared with the `MergingIter`'s comparator.
+    ///
+    /// This consumes the rest of the iterator: if the iterator is [valid], the current item is
+    /// the first item counted; otherwise, counting starts from the first entry of the
+    /// collection. Afterwards, the iterator is `!valid()`.
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[must_use]
+    pub fn run_length_encode(&mut self) -> Vec<(Key::Owned, usize)>
+    where
+        Key: ToOwned,
+    {
+        let mut runs: Vec<(Key::Owned, usize)> = Vec::new();
+
+        drain_from_current(self, |this| {
+            let Some(item) = this.current() else {
+                return;
+            };
+            let key = Iter::item_to_key(item);
+            match runs.last_mut() {
+                Some((run_key, count)) if keys_equal(&this.cmp, (*run_key).borrow(), key) => {
+                    *count += 1;
+                }
+                _ => runs.push((key.to_owned(), 1)),
+            }
+        });
+
+        runs
+    }
+
+    /// Move forwards through the rest of the merged collection, and return each distinct key as
+    /// a pair of an owned copy of the key and the number of sources which contain it.
+    ///
+    /// This is like [`MergingIter::run_length_encode`], except that each count is the breadth of
+    /// the key across sources rather than its multiplicity; a source with several entries with
+    /// the same key is counted once. This is useful for analyzing how keys are distributed
+    /// across sources, such as how much sources overlap. Keys are compared with the
+    /// `MergingIter`'s comparator.
+    ///
+    /// This consumes the rest of the iterator: if the iterator is [valid], the current item is
+    /// the first item counted (so sources whose entries with the current key were already passed
+    /// are not counted); otherwise, counting starts from the first entry of the collection.
+    /// Afterwards, the iterator is `!valid()`.
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[must_use]
+    pub fn coverage(&mut self) -> Vec<(Key::Owned, usize)>
+    where
+        Key: ToOwned,
+    {
+        let mut runs: Vec<(Key::Owned, usize)> = Vec::new();
+        // The index in `runs` of the last run in which each source was counted.
+        let mut counted_in = vec![None; self.iterators.len()];
+
+        drain_from_current(self, |this| {
+            let (Some(item), Some(source)) = (this.current(), this.current_source()) else {
+                return;
+            };
+            let key = Iter::item_to_key(item);
+            match runs.last_mut() {
+                Some((run_key, _)) if keys_equal(&this.cmp, (*run_key).borrow(), key) => {}
+                _ => runs.push((key.to_owned(), 0)),
+            }
+
+            let run_idx = runs.len() - 1;
+            if let (Some(count), Some(counted)) = (runs.last_mut(), counted_in.get_mut(source)) {
+                if *counted != Some(run_idx) {
+                    *counted = Some(run_idx);
+                    count.1 += 1;
+                }
+            }
+        });
+
+        runs
+    }
+
+    /// Move the iterator one position forwards, and return the entry at that position together
+    /// with the indices of every source whose current entry has a key equal to that entry's key.
+    ///
+    /// The indices are in increasing order, and include the [`current_source`]. Since the other
+    /// sources are positioned in front of the current entry, this is every source which contains
+    /// the key when the returned entry is the first entry with its key; otherwise, sources whose
+    /// entries with that key were already passed are not included.
+    ///
+    /// Both the item and the slice of indices borrow the `MergingIter`, so neither may be held
+    /// across another call which moves the iterator. The slice is stored in a buffer which is
+    /// reused by each call.
+    ///
+    /// Returns `None` if the iterator was at the last entry, as with
+    /// [`CursorLendingIterator::next`].
+    ///
+    /// [`current_source`]: MergingIter::current_source
+    pub fn next_with_contributors(&mut self) -> Option<(LentItem<'_, Iter>, &[usize])> {
+        self.next();
+        self.contributors.clear();
+
+        let current_idx = self.current_source()?;
+        let current_item = self.iterators.get(current_idx)?.current()?;
+        let current_key = Iter::item_to_key(current_item);
+
+        for (idx, iter) in self.iterators.iter().enumerate() {
+            if iter.current().is_some_and(|item| {
+                keys_equal(&self.cmp, current_key, Iter::item_to_key(item))
+            }) {
+                self.contributors.push(idx);
+            }
+        }
+
+        Some((self.current()?, &self.contributors))
+    }
+
+    /// Move the iterator one position forwards, and return the entry at that position; if the
+    /// entry's key is present in more than one source, also append an owned copy of the key to
+    /// `conflicts`.
+    ///
+    /// This combines iteration with auditing for keys which should have been unique across
+    /// sources. Sources are checked as in [`MergingIter::next_with_contributors`], so a conflict
+    /// is detected at the first entry with the conflicting key. Since entries with equal keys are
+    /// adjacent, a key is not appended if it is equal to the last key in `conflicts`; thus, if
+    /// `conflicts` is not otherwise modified, each conflicting key is appended once.
+    ///
+    /// Returns `None` if the iterator was at the last entry, as with
+    /// [`CursorLendingIterator::next`].
+    pub fn next_reporting_conflicts(
+        &mut self,
+        conflicts: &mut Vec<Key::Owned>,
+    ) -> Option<LentItem<'_, Iter>>
+    where
+        Key: ToOwned,
+    {
+        let is_conflict = self.next_with_contributors()
+            .is_some_and(|(_, contributors)| contributors.len() > 1);
+
+        if is_conflict {
+            if let Some(item) = self.current() {
+                let key = Iter::item_to_key(item);
+                if !conflicts.last().is_some_and(|last| keys_equal(&self.cmp, last.borrow(), key)) {
+                    conflicts.push(key.to_owned());
+                }
+            }
+        }
+
+        self.current()
+    }
+
+    /// Move the iterator one position forwards, and return the entry at that position together
+    /// with its ordinal within its run of equal keys.
+    ///
+    /// The ordinal is `0` for the first entry of each run of entries with equal keys, `1` for the
+    /// second, and so on, which lets consumers deterministically distinguish the versions of a
+    /// duplicated key. Runs may span several sources.
+    ///
+    /// Ordinals are counted by consecutive calls to this method: the ordinal is `0` if the
+    /// iterator was `!valid()` or at an entry with a different key before the call, and is
+    /// otherwise one more than the ordinal returned by the previous call. An owned copy of the
+    /// previous key is made for the comparison.
+    ///
+    /// # Other motions and direction switches
+    /// Ordinals are only meaningful for forwards iteration performed entirely by this method,
+    /// starting from a `!valid()` position (such as after a [`reset`]) or from the first entry of
+    /// a run. Other motions, including `next`, `prev`, and every seek, do not update the count,
+    /// so an ordinal returned after them continues from the last ordinal this method returned. In
+    /// particular, after moving backwards into a run, calling this method switches the direction
+    /// as [`next`] does (which, outside strict duplicates mode, may skip entries of the run), and
+    /// the resulting ordinals do not reflect positions within the run.
+    ///
+    /// Returns `None` if the iterator was at the last entry, as with
+    /// [`CursorLendingIterator::next`].
+    ///
+    /// [`reset`]: Seekable::reset
+    /// [`next`]: CursorLendingIterator::next
+    pub fn next_with_ordinal(&mut self) -> Option<(usize, LentItem<'_, Iter>)>
+    where
+        Key: ToOwned,
+    {
+        let prev_key = self.current().map(|item| Iter::item_to_key(item).to_owned());
+
+        self.next();
+        let continues_run = match (&prev_key, self.current()) {
+            (Some(prev_key), Some(item)) => {
+                keys_equal(&self.cmp, prev_key.borrow(), Iter::item_to_key(item))
+            }
+            _ => false,
+        };
+
+        self.run_ordinal = if continues_run {
+            self.run_ordinal + 1
+        } else {
+            0
+        };
+
+        let ordinal = self.run_ordinal;
+        self.current().map(|item| (ordinal, item))
+    }
+
+    /// Move the iterator forwards without blocking on sources which are not ready, and return the
+    /// smallest item among the sources which are ready.
+    ///
+    /// Each source which needs to move forwards is advanced with [`TryNext::try_next`]. A source
+    /// which is not ready is skipped, and is retried on later calls; until it is ready, items
+    /// from the other sources are yielded without regard to that source's items. Once every
+    /// source is ready again, items are yielded in sorted order as usual. If a source has been
+    /// skipped for [`readahead_cap`] items, it is no longer skipped, and `Err(NotReady)` is
+    /// returned until it is ready.
+    ///
+    /// Returns `Ok(None)` once every source has run out of items. If the iterator is not valid
+    /// (and is not waiting on a source), it moves to the first entry of the collection.
+    ///
+    /// Any other method which moves the iterator first brings skipped sources up to date, using
+    /// their blocking `next` (or a seek) instead.
+    ///
+    /// # Errors
+    /// Returns [`NotReady`] if no source is ready to provide an item, or if a skipped source has
+    /// reached the [`readahead_cap`]. The iterator is then `!valid()`, but remembers which
+    /// sources it is waiting on; calling `try_next` again retries them.
+    ///
+    /// [`readahead_cap`]: MergingIter::readahead_cap
+    pub fn try_next(&mut self) -> Result<Option<LentItem<'_, Iter>>, NotReady>
+    where
+        Iter: TryNext,
+    {
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            return self.concat_try_next();
+        }
+
+        self.lagging.resize(self.iterators.len(), None);
+        if let Some(current_idx) = self.current_iter {
+            match self.direction {
+                Direction::Backwards        => {
+                    self.switch_to_forwards(current_idx);
+                }
+                Direction::SettledBackwards => {
+                    self.unsettle_forwards(current_idx);
+                }
+                Direction::Forwards         => {}
+            }
+            // The current item has been yielded, so the current iterator must move forwards.
+            if let Some(lagging) = self.lagging.get_mut(current_idx.get() - 1) {
+                *lagging = Some(0);
+            }
+        } else if !self.lagging.iter().any(Option::is_some) {
+            // Every iterator is at its initial position, and must move to its first entry.
+            self.lagging.fill(Some(0));
+            self.direction = Direction::Forwards;
+        } else {
+            // The iterator is waiting on the lagging iterators.
+        }
+
+        for (iter, lagging) in self.iterators.iter_mut().zip(&mut self.lagging) {
+            if lagging.is_some() && iter.try_next().is_ok() {
+                *lagging = None;
+            }
+        }
+
+        let readahead_cap = self.readahead_cap;
+        if self.lagging.iter().flatten().any(|&skipped| skipped >= readahead_cap) {
+            self.current_iter = None;
+            return Err(NotReady);
+        }
+
+        self.find_smallest_iter();
+        self.count_selection();
+        self.report_exhausted_sources();
+        if self.current_iter.is_some() {
+            for skipped in self.lagging.iter_mut().flatten() {
+                *skipped += 1;
+            }
+            Ok(self.current())
+        } else if self.lagging.iter().any(Option::is_some) {
+            Err(NotReady)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Convert the [`MergingIter`] into a standard [`Iterator`] which moves forwards through the
+    /// rest of the merged collection, yielding an owned copy of each item.
+    ///
+    /// If the iterator is [valid], the current item is the first item yielded; otherwise,
+    /// iteration starts from the first entry of the collection. Unlike the circular `MergingIter`,
+    /// the returned iterator is fused: once the end of the collection is reached, it does not
+    /// wrap around, and only ever returns `None`.
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    pub fn into_fused_iter<Borrowed>(mut self) -> impl FusedIterator<Item = Borrowed::Owned>
+    where
+        Borrowed:                         ?Sized + ToOwned,
+        for<'lend> LentItem<'lend, Iter>: Borrow<Borrowed>,
+    {
+        let mut first = true;
+
+        iter::from_fn(move || {
+            let item = if mem::replace(&mut first, false) && self.valid() {
+                self.current()
+            } else {
+                self.next()
+            }?;
+            Some(item.borrow().to_owned())
+        }).fuse()
+    }
+
     #[must_use]
     fn get_current_iter_ref(&self) -> Option<&Iter> {
         let current_idx = self.current_iter?.get() - 1;
@@ -146,112 +1290,312 @@ where
         Some(&self.iterators[current_idx])
     }
 
-    /// Set `self.current_iter` to the iterator with the smallest `current` key, among the
-    /// iterators in `self.iterators` which are valid.
-    fn find_smallest_iter(&mut self) {
-        let mut smallest: Option<(usize, &Key)> = None;
+    /// For use with the `Concat` strategy.
+    ///
+    /// Set `self.current_iter` to the first entry of the first nonempty iterator whose index is at
+    /// least `start`.
+    fn concat_first_from(&mut self, start: usize) {
+        self.current_iter = None;
+        for (idx, iter) in self.iterators.iter_mut().enumerate().skip(start) {
+            iter.seek_to_first();
+            if iter.valid() {
+                self.current_iter = NonZero::new(idx + 1);
+                return;
+            }
+        }
+    }
 
-        for (idx, iter) in self.iterators.iter().enumerate() {
-            if let Some(curr_item) = iter.current() {
-                let curr_key = Iter::item_to_key(curr_item);
-                if let Some((_, smallest_key)) = smallest {
-                    if self.cmp.cmp(curr_key, smallest_key) == Ordering::Less {
-                        // `curr_key` is smaller than the previous `smallest`'s key
-                        smallest = Some((idx, curr_key));
-                    }
-                } else {
-                    // de-facto `smallest`, nothing was previously found
-                    smallest = Some((idx, curr_key));
+    /// For use with the `Concat` strategy.
+    ///
+    /// Set `self.current_iter` to the last entry of the last nonempty iterator whose index is
+    /// less than `end`.
+    fn concat_last_before(&mut self, end: usize) {
+        self.current_iter = None;
+        for (idx, iter) in self.iterators.iter_mut().enumerate().take(end).rev() {
+            iter.seek_to_last();
+            if iter.valid() {
+                self.current_iter = NonZero::new(idx + 1);
+                return;
+            }
+        }
+    }
+
+    /// For use with the `Concat` strategy.
+    ///
+    /// Reset every iterator other than the current iterator. A seek only positions the
+    /// iterators it visits, and the others may have been left valid by earlier movement; after
+    /// this, only the current iterator is valid, which moving with `next` and `prev` preserves.
+    fn concat_reset_others(&mut self) {
+        let current_source = self.current_source();
+        for (idx, iter) in self.iterators.iter_mut().enumerate() {
+            if Some(idx) != current_source {
+                iter.reset();
+            }
+        }
+    }
+
+    /// For use with the `Concat` strategy.
+    ///
+    /// Only the current iterator is ever accessed, so there is nothing to skip; this waits on
+    /// the current iterator.
+    fn concat_try_next(&mut self) -> Result<Option<LentItem<'_, Iter>>, NotReady>
+    where
+        Iter: TryNext,
+    {
+        if let Some(current_idx) = self.current_iter {
+            let current_idx = current_idx.get() - 1;
+            if let Some(iter) = self.iterators.get_mut(current_idx) {
+                if iter.try_next()?.is_some() {
+                    return Ok(self.current());
                 }
-            } else {
-                // The iterator was `!valid()`, so continue.
+            }
+            self.concat_first_from(current_idx + 1);
+        } else {
+            self.concat_first_from(0);
+        }
+
+        self.report_exhausted_sources();
+        Ok(self.current())
+    }
+
+    /// If any iterator is lagging behind (see `self.lagging`), move it forwards with its blocking
+    /// `next`. Returns whether any iterator was lagging.
+    fn catch_up_lagging(&mut self) -> bool {
+        let mut any_lagging = false;
+        for (iter, lagging) in self.iterators.iter_mut().zip(&mut self.lagging) {
+            if lagging.take().is_some() {
+                iter.next();
+                any_lagging = true;
+            }
+        }
+        any_lagging
+    }
+
+    /// If a callback was set with [`MergingIter::set_on_source_exhausted`], call it for each
+    /// newly-exhausted source. Should be called after moving forwards.
+    fn report_exhausted_sources(&mut self) {
+        let Some(ExhaustedCallback(callback)) = &mut self.on_exhausted else {
+            return;
+        };
+        self.exhausted.resize(self.iterators.len(), false);
+
+        // With the `Concat` strategy, the sources before the current source have been passed,
+        // and later sources may not have been positioned yet.
+        let concat_end = self.current_iter.map_or(self.iterators.len(), |idx| idx.get() - 1);
+
+        let sources = self.iterators.iter().zip(&mut self.exhausted).enumerate();
+        for (idx, (iter, already_reported)) in sources {
+            let is_exhausted = match self.strategy {
+                MergeStrategy::Concat => idx < concat_end,
+                MergeStrategy::Merge  => {
+                    !iter.valid() && !self.lagging.get(idx).is_some_and(Option::is_some)
+                }
+            };
+
+            if is_exhausted && !*already_reported {
+                *already_reported = true;
+                callback(idx);
             }
         }
+    }
+
+    /// Determine whether the iterator at the given index is lagging behind (see
+    /// `self.lagging`).
+    fn is_lagging(&self, idx: usize) -> bool {
+        self.lagging.get(idx).is_some_and(Option::is_some)
+    }
+
+    /// For use with the `Concat` strategy.
+    fn concat_next(&mut self) {
+        let start = if let Some(current_idx) = self.current_iter {
+            let current_idx = current_idx.get() - 1;
+            if let Some(iter) = self.iterators.get_mut(current_idx) {
+                iter.next();
+                if iter.valid() {
+                    return;
+                }
+            }
+            current_idx + 1
+        } else {
+            0
+        };
+
+        self.concat_first_from(start);
+    }
+
+    /// For use with the `Concat` strategy.
+    fn concat_prev(&mut self) {
+        let end = if let Some(current_idx) = self.current_iter {
+            let current_idx = current_idx.get() - 1;
+            if let Some(iter) = self.iterators.get_mut(current_idx) {
+                iter.prev();
+                if iter.valid() {
+                    return;
+                }
+            }
+            current_idx
+        } else {
+            self.iterators.len()
+        };
+
+        self.concat_last_before(end);
+    }
+
+    /// Set `self.current_iter` to the iterator with the smallest `current` key, among the
+    /// iterators in `self.iterators` which are valid.
+    ///
+    /// Ties are broken in favor of the lowest index. This always terminates after one pass,
+    /// even if every key compares equal.
+    fn find_smallest_iter(&mut self) {
+        // The current items of lagging iterators were already yielded by `try_next`.
+        let smallest = merge_core::find_smallest(&self.iterators, &self.cmp, |idx| {
+            !self.is_lagging(idx)
+        });
 
         #[expect(clippy::unwrap_used, reason = "MergingIter cannot have `usize::MAX` iterators")]
         {
-            self.current_iter = smallest.map(|(idx, _)| NonZero::new(idx + 1).unwrap());
+            self.current_iter = smallest.map(|idx| NonZero::new(idx + 1).unwrap());
+        }
+    }
+
+    /// For use in `self.next()` and `self.try_next()`, and nothing else, after the smallest
+    /// iterator has been found.
+    ///
+    /// Record in `self.selection_counts` that `current_iter` provided the smallest entry.
+    fn count_selection(&mut self) {
+        if let Some(count) = self
+            .current_source()
+            .and_then(|current_idx| self.selection_counts.get_mut(current_idx))
+        {
+            *count += 1;
         }
     }
 
     /// Set `self.current_iter` to the iterator with the largest `current` key, among the
     /// iterators in `self.iterators` which are valid.
+    ///
+    /// Ties are broken in favor of the highest index.
     fn find_largest_iter(&mut self) {
-        let mut largest: Option<(usize, &Key)> = None;
-
-        for (idx, iter) in self.iterators.iter().enumerate().rev() {
-            if let Some(curr_item) = iter.current() {
-                let curr_key = Iter::item_to_key(curr_item);
-                if let Some((_, largest_key)) = largest {
-                    if self.cmp.cmp(curr_key, largest_key) == Ordering::Greater {
-                        // `curr_key` is smaller than the previous `largest`'s key
-                        largest = Some((idx, curr_key));
-                    }
-                } else {
-                    // de-facto `largest`, nothing was previously found
-                    largest = Some((idx, curr_key));
-                }
-            } else {
-                // The iterator was `!valid()`, so continue.
-            }
-        }
+        let largest = merge_core::find_largest(&self.iterators, &self.cmp);
 
         #[expect(clippy::unwrap_used, reason = "MergingIter cannot have `usize::MAX` iterators")]
         {
-            self.current_iter = largest.map(|(idx, _)| NonZero::new(idx + 1).unwrap());
+            self.current_iter = largest.map(|idx| NonZero::new(idx + 1).unwrap());
         }
     }
 
     /// For use in `self.next()`, and nothing else.
     ///
     /// Move all non-`current_iter` iterators one entry strictly in front of `current_iter`.
+    ///
+    /// In strict duplicates mode, iterators after `current_iter` in `self.iterators` are instead
+    /// moved non-strictly in front of `current_iter`, as their items with the current key come
+    /// after the current item.
     fn switch_to_forwards(&mut self, current_idx: NonZero<usize>) -> &mut Iter {
         let current_idx = current_idx.get() - 1;
 
-        // Do a little game to satisfy borrowck and aliasing rules
+        let Ok(()) = merge_core::switch_to_forwards(
+            &mut self.iterators,
+            &self.cmp,
+            current_idx,
+            self.strict_duplicates,
+            |iter, key| {
+                iter.seek(key);
+                Ok::<_, Infallible>(())
+            },
+        );
+
+        self.direction = Direction::Forwards;
+        self.switches += 1;
+
+        #[expect(clippy::indexing_slicing, reason = "`current_idx` is a valid index")]
+        &mut self.iterators[current_idx]
+    }
+
+    /// For use in `self.prev()`, and nothing else.
+    ///
+    /// Move all non-`current_iter` iterators one entry strictly behind `current_iter`.
+    ///
+    /// In strict duplicates mode, iterators before `current_iter` in `self.iterators` are instead
+    /// moved non-strictly behind `current_iter`, as their items with the current key come before
+    /// the current item.
+    fn switch_to_backwards(&mut self, current_idx: NonZero<usize>) -> &mut Iter {
+        let current_idx = current_idx.get() - 1;
+
+        let Ok(()) = merge_core::switch_to_backwards(
+            &mut self.iterators,
+            &self.cmp,
+            current_idx,
+            self.strict_duplicates,
+            |iter, key| {
+                iter.seek_before(key);
+                Ok::<_, Infallible>(())
+            },
+        );
+
+        self.direction = Direction::Backwards;
+        self.switches += 1;
+
+        #[expect(clippy::indexing_slicing, reason = "`current_idx` is a valid index")]
+        &mut self.iterators[current_idx]
+    }
+
+    /// For use in `self.seek()`, `self.seek_to_first()`, and `self.seek_parallel()`, and nothing
+    /// else, after the sources have been positioned for forwards iteration and the smallest
+    /// iterator has been found.
+    ///
+    /// Sets the direction to forwards, unless backwards iteration is preferred.
+    fn settle_direction_after_seek(&mut self) {
+        if let (true, Some(current_idx)) = (self.prefer_backward, self.current_iter) {
+            self.settle_backwards(current_idx);
+        } else {
+            self.direction = Direction::Forwards;
+        }
+    }
+
+    /// For use in `self.settle_direction_after_seek()`, and nothing else.
+    ///
+    /// Move all non-`current_iter` iterators non-strictly behind `current_iter`, without the
+    /// seeks performed by `self.switch_to_backwards()`. Iterators at an entry with the current
+    /// key are left in place, unless in strict duplicates mode, in which case those iterators
+    /// (which must come after `current_iter` in `self.iterators`) are moved strictly behind it.
+    fn settle_backwards(&mut self, current_idx: NonZero<usize>) {
+        let current_idx = current_idx.get() - 1;
+
         let (iters, current_and_later) = self.iterators.split_at_mut(current_idx);
         let (current_iter, other_iters) = current_and_later.split_at_mut(1);
         #[expect(clippy::indexing_slicing, reason = "`current_idx` is a valid index")]
-        let current_iter = &mut current_iter[0];
+        let current_iter = &current_iter[0];
         #[expect(
             clippy::unwrap_used,
             reason = "the current iterator is `valid()` as an invariant",
         )]
         let current_key = Iter::item_to_key(current_iter.current().unwrap());
 
-        for iter in iters {
-            iter.seek(current_key);
-
-            // `seek` provides a `geq` order, we want a strict greater-than order.
-            if iter.current().is_some_and(|item| {
-                self.cmp.cmp(current_key, Iter::item_to_key(item)) == Ordering::Equal
-            }) {
-                iter.next();
-            }
-        }
-
-        for iter in other_iters {
-            iter.seek(current_key);
+        for iter in iters.iter_mut().chain(other_iters) {
+            let at_current_key = iter.current().is_some_and(|item| {
+                keys_equal(&self.cmp, current_key, Iter::item_to_key(item))
+            });
 
-            if iter.current().is_some_and(|item| {
-                self.cmp.cmp(current_key, Iter::item_to_key(item)) == Ordering::Equal
-            }) {
-                iter.next();
+            // Each iterator is at its first entry whose key is at least the seek's bound, so
+            // moving back one entry moves strictly behind `current_key`.
+            if self.strict_duplicates || !at_current_key {
+                iter.prev();
             }
         }
 
-        self.direction = Direction::Forwards;
-
-        current_iter
+        self.direction = Direction::SettledBackwards;
     }
 
-    /// For use in `self.prev()`, and nothing else.
+    /// For use in `self.next()` and `self.try_next()`, and nothing else, when the direction is
+    /// `SettledBackwards`.
     ///
-    /// Move all non-`current_iter` iterators one entry strictly behind `current_iter`.
-    fn switch_to_backwards(&mut self, current_idx: NonZero<usize>) -> &mut Iter {
+    /// Undo `self.settle_backwards()`, restoring the positions which the sources had
+    /// immediately after the seek. This is equivalent to `self.switch_to_forwards()`, except that
+    /// it does not skip entries with duplicate keys, and needs no seeks.
+    fn unsettle_forwards(&mut self, current_idx: NonZero<usize>) -> &mut Iter {
         let current_idx = current_idx.get() - 1;
 
-        // Do a little game to satisfy borrowck and aliasing rules
         let (iters, current_and_later) = self.iterators.split_at_mut(current_idx);
         let (current_iter, other_iters) = current_and_later.split_at_mut(1);
         #[expect(clippy::indexing_slicing, reason = "`current_idx` is a valid index")]
@@ -262,14 +1606,20 @@ where
         )]
         let current_key = Iter::item_to_key(current_iter.current().unwrap());
 
-        for iter in iters {
-            iter.seek_before(current_key);
-        }
-        for iter in other_iters {
-            iter.seek_before(current_key);
+        for iter in iters.iter_mut().chain(other_iters) {
+            let at_current_key = iter.current().is_some_and(|item| {
+                keys_equal(&self.cmp, current_key, Iter::item_to_key(item))
+            });
+
+            // Exactly the iterators which `settle_backwards` moved back by one entry are not at
+            // the current key, and moving forwards by one entry undoes that.
+            if self.strict_duplicates || !at_current_key {
+                iter.next();
+            }
         }
 
-        self.direction = Direction::Backwards;
+        self.direction = Direction::Forwards;
+        self.switches += 1;
 
         current_iter
     }
@@ -295,12 +1645,25 @@ where
     }
 
     fn next(&mut self) -> Option<LentItem<'_, Self>> {
-        if let Some(current_idx) = self.current_iter {
-            let current_iter = if matches!(self.direction, Direction::Backwards) {
-                self.switch_to_forwards(current_idx)
-            } else {
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            self.concat_next();
+        } else if self.lagging.iter().any(Option::is_some) {
+            // Some iterators' current items were already yielded by `try_next`, as was the
+            // current iterator's current item.
+            if let Some(current_iter) = self.current_iter {
+                if let Some(lagging) = self.lagging.get_mut(current_iter.get() - 1) {
+                    *lagging = Some(0);
+                }
+            }
+            self.catch_up_lagging();
+            self.find_smallest_iter();
+            self.count_selection();
+        } else if let Some(current_idx) = self.current_iter {
+            let current_iter = match self.direction {
+                Direction::Backwards        => self.switch_to_forwards(current_idx),
+                Direction::SettledBackwards => self.unsettle_forwards(current_idx),
                 #[expect(clippy::indexing_slicing, reason = "we know that it's a valid index")]
-                &mut self.iterators[current_idx.get() - 1]
+                Direction::Forwards         => &mut self.iterators[current_idx.get() - 1],
             };
 
             // Before this call, `current_iter` is the (non-strictly) smallest iter.
@@ -308,6 +1671,7 @@ where
             current_iter.next();
             // And find the new smallest iter.
             self.find_smallest_iter();
+            self.count_selection();
 
         } else {
             // In this branch, we're `!valid()`. This means that _every_ iterator is currently
@@ -318,9 +1682,11 @@ where
             }
 
             self.find_smallest_iter();
+            self.count_selection();
             self.direction = Direction::Forwards;
         }
 
+        self.report_exhausted_sources();
         self.current()
     }
 
@@ -337,7 +1703,14 @@ where
     /// for switching between backwards and forwards iteration; check the type-level documentation
     /// if you wish to use `prev`.
     fn prev(&mut self) -> Option<LentItem<'_, Self>> {
-        if let Some(current_idx) = self.current_iter {
+        if self.catch_up_lagging() && self.current_iter.is_none() {
+            // Every item before the lagging iterators' new positions has been yielded.
+            self.find_smallest_iter();
+        }
+
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            self.concat_prev();
+        } else if let Some(current_idx) = self.current_iter {
             let current_iter = if matches!(self.direction, Direction::Forwards) {
                 self.switch_to_backwards(current_idx)
             } else {
@@ -349,6 +1722,7 @@ where
             current_iter.prev();
             // And find the new largest iter.
             self.find_largest_iter();
+            self.direction = Direction::Backwards;
 
         } else {
             // In this branch, we're `!valid()`. This means that _every_ iterator is currently
@@ -364,6 +1738,13 @@ where
 
         self.current()
     }
+
+    /// Keys are known to be unique only if there is at most one source, or if the sources have
+    /// disjoint key ranges (see [`MergeStrategy::Concat`]), and each source has unique keys.
+    fn keys_are_unique(&self) -> bool {
+        (self.iterators.len() <= 1 || matches!(self.strategy, MergeStrategy::Concat))
+            && self.iterators.iter().all(Iter::keys_are_unique)
+    }
 }
 
 impl<Key, Cmp, Iter> ItemToKey<Key> for MergingIter<Key, Cmp, Iter>
@@ -387,17 +1768,33 @@ where
         for iter in &mut self.iterators {
             iter.reset();
         }
+        self.lagging.clear();
         self.current_iter = None;
         self.direction = Direction::Forwards;
     }
 
     fn seek(&mut self, min_bound: &Key) {
+        self.lagging.clear();
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            // The first iterator with any key at least `min_bound` has the desired entry.
+            self.current_iter = None;
+            for (idx, iter) in self.iterators.iter_mut().enumerate() {
+                iter.seek(min_bound);
+                if iter.valid() {
+                    self.current_iter = NonZero::new(idx + 1);
+                    break;
+                }
+            }
+            self.concat_reset_others();
+            return;
+        }
+
         for iter in &mut self.iterators {
             iter.seek(min_bound);
         }
 
         self.find_smallest_iter();
-        self.direction = Direction::Forwards;
+        self.settle_direction_after_seek();
     }
 
     /// Move the iterator to the greatest key which is strictly less than the provided
@@ -413,6 +1810,22 @@ where
     ///
     /// [`seek`]: MergingIter::seek
     fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.lagging.clear();
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            // The last iterator with any key less than `strict_upper_bound` has the desired
+            // entry.
+            self.current_iter = None;
+            for (idx, iter) in self.iterators.iter_mut().enumerate().rev() {
+                iter.seek_before(strict_upper_bound);
+                if iter.valid() {
+                    self.current_iter = NonZero::new(idx + 1);
+                    break;
+                }
+            }
+            self.concat_reset_others();
+            return;
+        }
+
         for iter in &mut self.iterators {
             iter.seek_before(strict_upper_bound);
         }
@@ -422,12 +1835,19 @@ where
     }
 
     fn seek_to_first(&mut self) {
+        self.lagging.clear();
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            self.concat_first_from(0);
+            self.concat_reset_others();
+            return;
+        }
+
         for iter in &mut self.iterators {
             iter.seek_to_first();
         }
 
         self.find_smallest_iter();
-        self.direction = Direction::Forwards;
+        self.settle_direction_after_seek();
     }
 
     /// Move the iterator to the greatest key in the collection.
@@ -437,6 +1857,13 @@ where
     /// [`MergingIter`] has overhead for switching between backwards and forwards
     /// iteration; check the type-level documentation if you wish to use `seek_before`.
     fn seek_to_last(&mut self) {
+        self.lagging.clear();
+        if matches!(self.strategy, MergeStrategy::Concat) {
+            self.concat_last_before(self.iterators.len());
+            self.concat_reset_others();
+            return;
+        }
+
         for iter in &mut self.iterators {
             iter.seek_to_last();
         }
@@ -448,12 +1875,22 @@ where
 
 
 #[cfg(test)]
+#[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+#[expect(
+    clippy::doc_paragraphs_missing_punctuation,
+    reason = "test descriptions are not full sentences",
+)]
+#[expect(clippy::shadow_unrelated, reason = "each check in the test starts from fresh bindings")]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
 mod tests {
-    use alloc::vec;
+    use core::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+    use alloc::{sync::Arc, vec};
     use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use crate::seekable_ext::SeekableLendingIteratorExt;
     use super::*;
 
     /// The iterator must iterate over `[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]`
+    #[expect(clippy::nonminimal_bool, reason = "the assertion states the property being tested")]
     fn iteration_without_duplicates(iter: &mut MergingIter<u8, OrdComparator, TestIter<'_>>) {
         assert_eq!(*iter.next().unwrap(), 0);
 
@@ -603,42 +2040,511 @@ mod tests {
             OrdComparator,
         );
 
-        seek_tests(merged_data, &mut iter);
+        seek_tests(merged_data, &mut iter);
+    }
+
+    #[test]
+    fn three_merged_chained() {
+        let data_one:    &[u8] = [0, 1, 2, 3].as_slice();
+        let data_two:    &[u8] = [7, 8, 9].as_slice();
+        let data_three:  &[u8] = [4, 5, 6].as_slice();
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        iteration_without_duplicates(&mut iter);
+    }
+
+    #[test]
+    fn seek_three_merged_chained() {
+        let data_one:    &[u8] = [0, 1, 2, 3].as_slice();
+        let data_two:    &[u8] = [7, 8, 9, 99].as_slice();
+        let data_three:  &[u8] = [4, 5, 6].as_slice();
+        let merged_data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 99].as_slice();
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        seek_tests(merged_data, &mut iter);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn seek_parallel_matches_seek() {
+        let data_one:   &[u8] = [0, 3, 6, 7, 7, 20].as_slice();
+        let data_two:   &[u8] = [1, 5, 7, 8].as_slice();
+        let data_three: &[u8] = [2, 4, 9, 99].as_slice();
+        let new_iter = || MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        for prefer_backward in [false, true] {
+            let mut serial = new_iter();
+            let mut parallel = new_iter();
+            serial.set_prefer_backward(prefer_backward);
+            parallel.set_prefer_backward(prefer_backward);
+
+            for bound in [0, 7, 3, 100, 21, 8, 1, 99] {
+                serial.seek(&bound);
+                parallel.seek_parallel(&bound);
+
+                assert_eq!(serial.current(), parallel.current());
+                assert_eq!(serial.current_source(), parallel.current_source());
+
+                // Subsequent iteration should also agree, including the cost of moving backwards
+                assert_eq!(serial.prev(), parallel.prev());
+                assert_eq!(serial.direction_switches(), parallel.direction_switches());
+                for _ in 0..3 {
+                    assert_eq!(serial.next(), parallel.next());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn seek_to_current_of_driver() {
+        let data_one: &[u8] = [0, 3, 6, 9].as_slice();
+        let data_two: &[u8] = [1, 4, 7].as_slice();
+        let driver_data: &[u8] = [2, 3, 7, 8].as_slice();
+        let mut iter = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        let mut driver = TestIter::new(driver_data).unwrap();
+
+        // The driver is not valid
+        iter.seek_to_first();
+        iter.seek_to_current_of(&driver);
+        assert!(!iter.valid());
+
+        for expected in [3, 3, 7, 9] {
+            driver.next();
+            iter.seek_to_current_of(&driver);
+            assert_eq!(iter.current(), Some(&expected));
+        }
+
+        driver.next();
+        iter.seek_to_current_of(&driver);
+        assert!(!iter.valid());
+        assert_eq!(iter.next(), Some(&0));
+    }
+
+    #[test]
+    fn drain_into_reuses_vec() {
+        let data_one: &[u8] = [0, 3, 6, 9].as_slice();
+        let data_two: &[u8] = [1, 4, 7].as_slice();
+        let mut iter = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        let mut out = Vec::new();
+
+        iter.drain_into::<u8>(&mut out);
+        assert_eq!(out, [0, 1, 3, 4, 6, 7, 9]);
+        assert!(!iter.valid());
+        let capacity = out.capacity();
+        let buffer = out.as_ptr();
+
+        iter.seek(&4);
+        iter.drain_into::<u8>(&mut out);
+        assert_eq!(out, [4, 6, 7, 9]);
+        assert_eq!(out.capacity(), capacity);
+        assert_eq!(out.as_ptr(), buffer);
+    }
+
+    #[test]
+    fn hinted_count_matches_scan() {
+        let data_one: &[u8] = [0, 3, 6, 9].as_slice();
+        let data_two: &[u8] = [1, 3, 4, 7].as_slice();
+        let merge = || MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        let mut hinted = merge();
+        let mut scanned = merge();
+
+        for (lower, upper) in [(0, 10), (2, 5), (3, 4), (5, 6), (8, 2), (10, 20)] {
+            let count = hinted.count_between(&lower, &upper);
+            let scan_count = SeekableLendingIteratorExt::count_between(
+                &mut scanned, &OrdComparator, &lower, &upper,
+            );
+            assert_eq!(count, scan_count);
+            // The sources' hints were used, so the iterator was not moved by a scan.
+            assert!(!hinted.valid());
+        }
+        assert_eq!(hinted.count_between(&3, &7), 4);
+
+        hinted.seek(&4);
+        assert_eq!(hinted.count_between(&0, &10), 8);
+        assert_eq!(hinted.current(), Some(&4));
+    }
+
+    /// A source whose first `delay` calls to `try_next` report that it is not ready.
+    struct SlowSource {
+        iter:  TestIter<'static>,
+        delay: usize,
+    }
+
+    impl<'lend> LendItem<'lend> for SlowSource {
+        type Item = &'lend u8;
+    }
+
+    impl CursorLendingIterator for SlowSource {
+        fn valid(&self) -> bool {
+            self.iter.valid()
+        }
+
+        fn next(&mut self) -> Option<LentItem<'_, Self>> {
+            self.iter.next()
+        }
+
+        fn current(&self) -> Option<LentItem<'_, Self>> {
+            self.iter.current()
+        }
+
+        fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+            self.iter.prev()
+        }
+    }
+
+    impl ItemToKey<u8> for SlowSource {
+        fn item_to_key(item: LentItem<'_, Self>) -> &'_ u8 {
+            item
+        }
+    }
+
+    impl Seekable<u8, OrdComparator> for SlowSource {
+        fn reset(&mut self) {
+            self.iter.reset();
+        }
+
+        fn seek(&mut self, min_bound: &u8) {
+            self.iter.seek(min_bound);
+        }
+
+        fn seek_before(&mut self, strict_upper_bound: &u8) {
+            self.iter.seek_before(strict_upper_bound);
+        }
+
+        fn seek_to_first(&mut self) {
+            self.iter.seek_to_first();
+        }
+
+        fn seek_to_last(&mut self) {
+            self.iter.seek_to_last();
+        }
+    }
+
+    impl TryNext for SlowSource {
+        fn try_next(&mut self) -> Result<Option<LentItem<'_, Self>>, NotReady> {
+            if let Some(delay) = self.delay.checked_sub(1) {
+                self.delay = delay;
+                Err(NotReady)
+            } else {
+                Ok(self.iter.next())
+            }
+        }
+    }
+
+    fn slow(data: &'static [u8], delay: usize) -> SlowSource {
+        SlowSource {
+            iter: TestIter::new(data).unwrap(),
+            delay,
+        }
+    }
+
+    #[test]
+    fn slow_source_is_skipped_until_ready() {
+        let mut iter = MergingIter::new_with_readahead_cap(
+            vec![slow(&[1, 3, 5, 7], 0), slow(&[2, 4, 6], 2)],
+            OrdComparator,
+            10,
+        );
+
+        // The slow source is skipped twice, and its items are yielded once they arrive.
+        for expected in [1, 3, 2, 4, 5, 6, 7] {
+            assert_eq!(iter.try_next(), Ok(Some(&expected)));
+        }
+        assert_eq!(iter.try_next(), Ok(None));
+        assert!(!iter.valid());
+
+        // Once every source is ready, the order is correct from the start.
+        for expected in 1..=7 {
+            assert_eq!(iter.try_next(), Ok(Some(&expected)));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn seek_parallel_after_try_next() {
+        let mut iter = MergingIter::new_with_readahead_cap(
+            vec![slow(&[1, 3, 5], 0), slow(&[2, 4], 1)],
+            OrdComparator,
+            1,
+        );
+
+        // The slow source is left lagging, which the seek must forget.
+        assert_eq!(iter.try_next(), Ok(Some(&1)));
+        iter.seek_parallel(&0);
+        assert_eq!(iter.current(), Some(&1));
+        for expected in 2..=5 {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn readahead_cap_waits_for_slow_source() {
+        let mut iter = MergingIter::new_with_readahead_cap(
+            vec![slow(&[1, 3, 5], 0), slow(&[2, 4], 2)],
+            OrdComparator,
+            1,
+        );
+
+        assert_eq!(iter.try_next(), Ok(Some(&1)));
+        assert_eq!(iter.try_next(), Err(NotReady));
+        assert!(!iter.valid());
+        for expected in [2, 3, 4, 5] {
+            assert_eq!(iter.try_next(), Ok(Some(&expected)));
+        }
+        assert_eq!(iter.try_next(), Ok(None));
+
+        // Blocking motions catch up with a source that is not ready.
+        let mut iter = MergingIter::new_with_readahead_cap(
+            vec![slow(&[1, 3, 5], 0), slow(&[2, 4], 5)],
+            OrdComparator,
+            0,
+        );
+        assert_eq!(iter.try_next(), Err(NotReady));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.try_next(), Err(NotReady));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.prev(), Some(&2));
+    }
+
+    /// Set a callback which counts how many times each of `num_sources` sources is reported as
+    /// exhausted.
+    fn count_exhausted(
+        iter:        &mut MergingIter<u8, OrdComparator, TestIter<'static>>,
+        num_sources: usize,
+    ) -> Arc<[AtomicUsize]> {
+        let counts: Arc<[AtomicUsize]> = iter::repeat_with(|| AtomicUsize::new(0))
+            .take(num_sources)
+            .collect();
+        let callback_counts = Arc::clone(&counts);
+        iter.set_on_source_exhausted(move |idx| {
+            callback_counts.get(idx).unwrap().fetch_add(1, AtomicOrdering::Relaxed);
+        });
+        counts
+    }
+
+    fn load_counts(counts: &[AtomicUsize]) -> Vec<usize> {
+        counts.iter().map(|count| count.load(AtomicOrdering::Relaxed)).collect()
+    }
+
+    #[test]
+    fn exhausted_callback_fires_once_per_source() {
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 2]).unwrap(),
+                TestIter::new(&[3]).unwrap(),
+                TestIter::new(&[]).unwrap(),
+                TestIter::new(&[0, 5]).unwrap(),
+            ],
+            OrdComparator,
+        );
+        let counts = count_exhausted(&mut iter, 4);
+
+        // The empty source is exhausted immediately.
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(load_counts(&counts), [0, 0, 1, 0]);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(load_counts(&counts), [0, 0, 1, 0]);
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(load_counts(&counts), [1, 0, 1, 0]);
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(load_counts(&counts), [1, 1, 1, 0]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(load_counts(&counts), [1, 1, 1, 1]);
+
+        // Sources are not reported again after wrapping around.
+        iter.reset();
+        while iter.next().is_some() {}
+        assert_eq!(load_counts(&counts), [1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn exhausted_callback_with_concat() {
+        let mut iter = MergingIter::new_auto(
+            vec![
+                TestIter::new(&[4, 5]).unwrap(),
+                TestIter::new(&[]).unwrap(),
+                TestIter::new(&[1, 2]).unwrap(),
+            ],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Concat);
+        let counts = count_exhausted(&mut iter, 3);
+
+        // The sources are reordered to `[[1, 2], [4, 5], []]`.
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(load_counts(&counts), [0, 0, 0]);
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(load_counts(&counts), [1, 0, 0]);
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), None);
+        assert_eq!(load_counts(&counts), [1, 1, 1]);
+    }
+
+    #[test]
+    fn descending_union() {
+        use crate::sorted_slice_cursor::SortedSliceCursor;
+
+        let first: &[u8] = [9, 6, 2].as_slice();
+        let second: &[u8] = [8, 6, 5, 1].as_slice();
+        let mut iter = MergingIter::new_descending(
+            vec![
+                SortedSliceCursor::new(first, Reversed::new(OrdComparator)),
+                SortedSliceCursor::new(second, Reversed::new(OrdComparator)),
+            ],
+            OrdComparator,
+        );
+
+        for expected in [9, 8, 6, 6, 5, 2, 1] {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.next(), None);
+
+        // Seeks are reversed as well.
+        iter.seek(&7);
+        assert_eq!(iter.current(), Some(&6));
+        iter.seek_to_first();
+        assert_eq!(iter.current(), Some(&9));
+    }
+
+    #[test]
+    fn conflicts_across_sources() {
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 3, 5, 7, 7]).unwrap(),
+                TestIter::new(&[3, 4, 7]).unwrap(),
+                TestIter::new(&[6, 7, 8]).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        let mut conflicts = Vec::new();
+        let mut yielded = Vec::new();
+        while let Some(&item) = iter.next_reporting_conflicts(&mut conflicts) {
+            yielded.push(item);
+        }
+
+        assert_eq!(yielded, [1, 3, 3, 4, 5, 6, 7, 7, 7, 7, 8]);
+        assert_eq!(conflicts, [3, 7]);
+
+        // Duplicates within a single source are not conflicts.
+        let mut iter = MergingIter::new(
+            vec![TestIter::new(&[2, 2]).unwrap(), TestIter::new(&[1]).unwrap()],
+            OrdComparator,
+        );
+        conflicts.clear();
+        while iter.next_reporting_conflicts(&mut conflicts).is_some() {}
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn run_length_encoding() {
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 1, 2, 5, 5, 5]).unwrap(),
+                TestIter::new(&[1, 3, 5]).unwrap(),
+                TestIter::new(&[2, 2, 9]).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        let runs = iter.run_length_encode();
+        assert_eq!(runs, [(1, 3), (2, 3), (3, 1), (5, 4), (9, 1)]);
+        assert!(!iter.valid());
+
+        // Counting starts from the current entry.
+        iter.seek(&3);
+        assert_eq!(iter.run_length_encode(), [(3, 1), (5, 4), (9, 1)]);
+
+        let mut empty = MergingIter::<u8, _, TestIter<'_>>::new(vec![], OrdComparator);
+        assert!(empty.run_length_encode().is_empty());
     }
 
     #[test]
-    fn three_merged_chained() {
-        let data_one:    &[u8] = [0, 1, 2, 3].as_slice();
-        let data_two:    &[u8] = [7, 8, 9].as_slice();
-        let data_three:  &[u8] = [4, 5, 6].as_slice();
+    fn reordering_preserves_output() {
+        let rare: &[u8] = [7, 8].as_slice();
+        let frequent: &[u8] = [0, 1, 2, 3, 4, 5, 6, 9].as_slice();
         let mut iter = MergingIter::new(
-            vec![
-                TestIter::new(data_one).unwrap(),
-                TestIter::new(data_two).unwrap(),
-                TestIter::new(data_three).unwrap(),
-            ],
+            vec![TestIter::new(rare).unwrap(), TestIter::new(frequent).unwrap()],
             OrdComparator,
         );
 
-        iteration_without_duplicates(&mut iter);
+        for expected in 0..=4 {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.selection_counts(), [0, 5]);
+        assert_eq!(iter.current_source(), Some(1));
+
+        // Seeks do not count as selections.
+        iter.seek(&7);
+        iter.seek_to_first();
+        iter.seek(&4);
+        assert_eq!(iter.selection_counts(), [0, 5]);
+
+        iter.reorder_sources_by_frequency();
+        assert_eq!(iter.selection_counts(), [5, 0]);
+        assert_eq!(iter.current_source(), Some(0));
+        assert_eq!(iter.current(), Some(&4));
+
+        for expected in 5..=9 {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.next(), None);
+
+        let mut rest = Vec::new();
+        iter.drain_into::<u8>(&mut rest);
+        assert_eq!(rest, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(iter.into_fused_iter::<u8>().count(), 10);
     }
 
     #[test]
-    fn seek_three_merged_chained() {
-        let data_one:    &[u8] = [0, 1, 2, 3].as_slice();
-        let data_two:    &[u8] = [7, 8, 9, 99].as_slice();
-        let data_three:  &[u8] = [4, 5, 6].as_slice();
-        let merged_data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 99].as_slice();
+    fn comparator_matches_merge_order() {
+        let data_one: &[u8] = [0, 3, 6, 9].as_slice();
+        let data_two: &[u8] = [1, 3, 4, 7].as_slice();
         let mut iter = MergingIter::new(
-            vec![
-                TestIter::new(data_one).unwrap(),
-                TestIter::new(data_two).unwrap(),
-                TestIter::new(data_three).unwrap(),
-            ],
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
             OrdComparator,
         );
 
-        seek_tests(merged_data, &mut iter);
+        let mut prev = *iter.next().unwrap();
+        while let Some(&current) = iter.next() {
+            assert_ne!(Comparator::cmp(iter.comparator(), &prev, &current), Ordering::Greater);
+            assert_ne!(Comparator::cmp(iter.comparator(), &current, &prev), Ordering::Less);
+            prev = current;
+        }
     }
 
     /// The things this test checks can be relied on by users, but are edge cases
@@ -699,6 +2605,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn two_duplicates_strict() {
+        use crate::{indexed::IndexedCursor as _, sorted_slice_cursor::SortedSliceCursor};
+
+        let data_one: &[u8] = &[1, 2, 2, 3];
+        let data_two: &[u8] = &[0, 2, 2, 5];
+        // `(key, source, index in source)`, in the strict order
+        let merged = [
+            (0, 1, 0), (1, 0, 0), (2, 0, 1), (2, 0, 2), (2, 1, 1), (2, 1, 2), (3, 0, 3), (5, 1, 3),
+        ];
+        let mut iter = MergingIter::new_strict_duplicates(
+            vec![
+                SortedSliceCursor::new(data_one, OrdComparator),
+                SortedSliceCursor::new(data_two, OrdComparator),
+            ],
+            OrdComparator,
+        );
+
+        let position = |iter: &MergingIter<_, _, SortedSliceCursor<'_, u8, OrdComparator>>| {
+            let source = iter.current_source()?;
+            let index = iter.iterators.get(source)?.current_index()?;
+            Some((*iter.current()?, source, index))
+        };
+
+        // The same motions as in `two_duplicates_unspecified`, and then some.
+        let motions = [
+            true, true, true, true, false, false, true, true, true, false, true, true, true, true,
+            true, false, false, false, false, false, true, true, true, true, false, false, false,
+            true, false, true, false, true, true, true, true, true, true, false, false,
+        ];
+        let mut model: Option<usize> = None;
+
+        for (step, forwards) in motions.into_iter().enumerate() {
+            if forwards {
+                iter.next();
+                model = match model {
+                    None => Some(0),
+                    Some(idx) => (idx + 1 < merged.len()).then_some(idx + 1),
+                };
+            } else {
+                iter.prev();
+                model = match model {
+                    None => Some(merged.len() - 1),
+                    Some(idx) => idx.checked_sub(1),
+                };
+            }
+
+            assert_eq!(position(&iter), model.and_then(|idx| merged.get(idx).copied()), "step {step}");
+        }
+    }
+
     /// The things this test checks can be relied on by users, but are edge cases
     #[test]
     fn two_duplicates_defined() {
@@ -808,6 +2765,7 @@ mod tests {
 
     /// This test checks an implementation detail that users should not rely on
     #[test]
+    #[expect(clippy::cognitive_complexity, reason = "the test checks many positions in sequence")]
     fn two_duplicates_unspecified() {
         let data_one    = &[1, 2, 2, 3];
         let data_two    = &[0, 2, 2, 5];
@@ -879,4 +2837,548 @@ mod tests {
         assert_eq!(*iter.next().unwrap(), 2);
         assert_eq!(*iter.next().unwrap(), 3);
     }
+
+    #[test]
+    fn unit_keys_in_source_order() {
+        use crate::{comparator::UnitComparator, sorted_slice_cursor::SortedSliceCursor};
+
+        let data: &[()] = &[()];
+        let mut iter = MergingIter::<(), _, _>::new(
+            vec![
+                SortedSliceCursor::new(data, UnitComparator),
+                SortedSliceCursor::new(data, UnitComparator),
+            ],
+            UnitComparator,
+        );
+
+        for _ in 0..2 {
+            assert!(iter.next().is_some());
+            assert_eq!(iter.current_source(), Some(0));
+            assert!(iter.next().is_some());
+            assert_eq!(iter.current_source(), Some(1));
+            assert!(iter.next().is_none());
+            assert!(!iter.valid());
+        }
+
+        assert!(iter.prev().is_some());
+        assert_eq!(iter.current_source(), Some(1));
+        assert!(iter.prev().is_some());
+        assert_eq!(iter.current_source(), Some(0));
+        assert!(iter.prev().is_none());
+
+        iter.seek(&());
+        assert_eq!(iter.current_source(), Some(0));
+        iter.seek_to_last();
+        assert_eq!(iter.current_source(), Some(1));
+    }
+
+    #[test]
+    fn unit_keys_strict() {
+        use crate::{comparator::UnitComparator, sorted_slice_cursor::SortedSliceCursor};
+
+        let data: &[()] = &[()];
+        let mut iter = MergingIter::<(), _, _>::new_strict_duplicates(
+            vec![
+                SortedSliceCursor::new(data, UnitComparator),
+                SortedSliceCursor::new(data, UnitComparator),
+            ],
+            UnitComparator,
+        );
+
+        assert!(iter.next().is_some());
+        assert_eq!(iter.current_source(), Some(0));
+        assert!(iter.next().is_some());
+        assert_eq!(iter.current_source(), Some(1));
+        assert!(iter.prev().is_some());
+        assert_eq!(iter.current_source(), Some(0));
+        assert!(iter.next().is_some());
+        assert_eq!(iter.current_source(), Some(1));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn into_fused_iter_stops_at_end() {
+        let data_one: &[u8] = &[0, 3, 4, 8];
+        let data_two: &[u8] = &[1, 2, 5, 6, 7, 9];
+        let iters = || vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()];
+
+        let merged: Vec<u8> = MergingIter::new(iters(), OrdComparator)
+            .into_fused_iter::<u8>()
+            .collect();
+        assert_eq!(merged, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let mut iter = MergingIter::new(iters(), OrdComparator);
+        iter.seek(&7);
+        let mut fused = iter.into_fused_iter::<u8>();
+        assert_eq!(fused.next(), Some(7));
+        assert_eq!(fused.next(), Some(8));
+        assert_eq!(fused.next(), Some(9));
+        assert_eq!(fused.next(), None);
+        assert_eq!(fused.next(), None);
+    }
+
+    #[test]
+    fn auto_strategy_disjoint() {
+        let data_one: &[u8] = &[5, 6, 7, 8, 9];
+        let data_two: &[u8] = &[];
+        let data_three: &[u8] = &[0, 1, 2, 3, 4];
+
+        let mut iter = MergingIter::new_auto(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Concat);
+        assert!(!iter.valid());
+
+        iteration_without_duplicates(&mut iter);
+
+        iter.seek(&7);
+        assert_eq!(iter.current(), Some(&7));
+        assert_eq!(iter.current_source(), Some(1));
+        iter.seek_before(&5);
+        assert_eq!(iter.current(), Some(&4));
+        assert_eq!(iter.current_source(), Some(0));
+        assert_eq!(iter.next(), Some(&5));
+        iter.seek(&10);
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn concat_seek_resets_other_sources() {
+        let data_one: &[u8] = &[1, 2, 3];
+        let data_two: &[u8] = &[7, 8, 9];
+        let data_three: &[u8] = &[4, 5];
+
+        let mut iter = MergingIter::new_auto(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Concat);
+
+        let valid_sources = |iter: &MergingIter<u8, OrdComparator, TestIter<'_>>| {
+            iter.iterators.iter().map(CursorLendingIterator::valid).collect::<Vec<_>>()
+        };
+
+        iter.seek_to_last();
+        iter.seek(&2);
+        assert_eq!(valid_sources(&iter), [true, false, false]);
+        assert_eq!(iter.next_with_contributors(), Some((&3, [0].as_slice())));
+
+        iter.seek_to_first();
+        iter.seek_before(&8);
+        assert_eq!(valid_sources(&iter), [false, false, true]);
+
+        iter.seek(&5);
+        iter.seek_to_first();
+        assert_eq!(valid_sources(&iter), [true, false, false]);
+        iter.seek_to_last();
+        assert_eq!(valid_sources(&iter), [false, false, true]);
+    }
+
+    #[test]
+    fn auto_strategy_overlapping() {
+        let data_one: &[u8] = &[0, 3, 4, 5, 9];
+        let data_two: &[u8] = &[1, 2, 6, 7, 8];
+
+        let mut iter = MergingIter::new_auto(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Merge);
+        assert!(!iter.valid());
+
+        iteration_without_duplicates(&mut iter);
+
+        // Touching key ranges overlap.
+        let data_one: &[u8] = &[0, 1, 2];
+        let data_two: &[u8] = &[2, 3];
+        let iter = MergingIter::new_auto(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Merge);
+    }
+
+    #[test]
+    fn peek_min_and_max_keys() {
+        let data_one: &[u8] = &[0, 3, 4, 8];
+        let data_two: &[u8] = &[1, 2, 5, 6, 7, 9];
+        let mut iter = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+
+        assert_eq!(iter.peek_min_key(), None);
+        assert_eq!(iter.peek_max_key(), None);
+
+        while let Some(&current) = iter.next() {
+            assert_eq!(iter.peek_min_key(), Some(&current));
+            assert!(iter.peek_max_key().is_some_and(|&max| max >= current));
+        }
+        assert_eq!(iter.peek_min_key(), None);
+
+        while let Some(&current) = iter.prev() {
+            assert_eq!(iter.peek_max_key(), Some(&current));
+            assert!(iter.peek_min_key().is_some_and(|&min| min <= current));
+        }
+
+        iter.seek(&4);
+        assert_eq!(iter.peek_min_key(), Some(&4));
+        assert_eq!(iter.peek_max_key(), Some(&5));
+        assert_eq!(iter.next(), Some(&5));
+
+        // After moving backwards, the other source is behind the current entry.
+        assert_eq!(iter.prev(), Some(&4));
+        assert_eq!(iter.prev(), Some(&3));
+        assert_eq!(iter.peek_min_key(), Some(&2));
+        assert_eq!(iter.peek_max_key(), Some(&3));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.peek_min_key(), Some(&4));
+        assert_eq!(iter.peek_max_key(), Some(&5));
+
+        iter.set_prefer_backward(true);
+        iter.seek(&4);
+        assert_eq!(iter.peek_min_key(), Some(&2));
+        assert_eq!(iter.peek_max_key(), Some(&4));
+    }
+
+    #[test]
+    fn peek_keys_with_concat() {
+        let data_one: &[u8] = &[1, 2, 3];
+        let data_two: &[u8] = &[7, 8, 9];
+        let mut iter = MergingIter::new_auto(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Concat);
+
+        iter.seek_to_last();
+        iter.seek(&2);
+        assert_eq!(iter.peek_min_key(), Some(&2));
+        assert_eq!(iter.peek_max_key(), Some(&2));
+
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&7));
+        assert_eq!(iter.prev(), Some(&3));
+        assert_eq!(iter.peek_min_key(), Some(&3));
+        assert_eq!(iter.peek_max_key(), Some(&3));
+    }
+
+    #[test]
+    fn contributors_of_overlapping_sources() {
+        let data_one: &[u8] = &[1, 3, 5];
+        let data_two: &[u8] = &[3, 4, 5];
+        let data_three: &[u8] = &[5, 5, 6];
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        let expected: &[(u8, &[usize])] = &[
+            (1, &[0]),
+            (3, &[0, 1]),
+            (3, &[1]),
+            (4, &[1]),
+            (5, &[0, 1, 2]),
+            (5, &[1, 2]),
+            (5, &[2]),
+            (5, &[2]),
+            (6, &[2]),
+        ];
+        for &(key, contributors) in expected {
+            assert_eq!(iter.next_with_contributors(), Some((&key, contributors)));
+        }
+        assert_eq!(iter.next_with_contributors(), None);
+
+        // Switching direction repositions the other sources in front of the current entry.
+        iter.seek_to_last();
+        assert_eq!(iter.prev(), Some(&5));
+        assert_eq!(iter.next_with_contributors(), Some((&6, [2].as_slice())));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[expect(
+        clippy::assertions_on_result_states,
+        reason = "only whether the call succeeds is checked",
+    )]
+    fn into_pooled_yields_owned_union() {
+        use crate::pooled::PooledIterator as _;
+
+        let data_one: &[u8] = &[0, 3, 4, 8];
+        let data_two: &[u8] = &[1, 3, 9];
+        let merging = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        let mut pooled = merging.into_pooled::<u8>(2);
+
+        let first = pooled.next().unwrap();
+        let mut owned = vec![*first];
+        while let Some(item) = pooled.next() {
+            owned.push(*item);
+        }
+        drop(first);
+
+        assert_eq!(owned, [0, 1, 3, 3, 4, 8, 9]);
+        assert!(pooled.try_next().is_ok());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn parallel_premerge_matches_serial() {
+        let data: [&[u8]; 5] = [&[0, 3, 4, 8], &[1, 3, 9], &[], &[2, 3, 3, 10], &[5, 6, 9]];
+        let sources = || data.iter().map(|data| TestIter::new(data).unwrap()).collect();
+
+        let mut serial = Vec::new();
+        MergingIter::new(sources(), OrdComparator).drain_into::<u8>(&mut serial);
+
+        for num_threads in [0, 1, 2, 3, 5, 8] {
+            let mut merge = MergingIter::parallel_premerge::<u8>(
+                sources(),
+                OrdComparator,
+                num_threads,
+            );
+            assert!(merge.iterators.len() <= num_threads.max(1));
+
+            let mut premerged = Vec::new();
+            merge.drain_into::<u8>(&mut premerged);
+            assert_eq!(premerged, serial);
+
+            merge.seek(&7);
+            assert_eq!(merge.current(), Some(&8));
+            merge.seek_before(&3);
+            assert_eq!(merge.current(), Some(&2));
+            merge.seek_to_last();
+            assert_eq!(merge.current(), Some(&10));
+        }
+    }
+
+    #[test]
+    fn ordinals_within_runs() {
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 3, 3, 6]).unwrap(),
+                TestIter::new(&[3, 3, 5]).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        let mut tagged = Vec::new();
+        while let Some((ordinal, &item)) = iter.next_with_ordinal() {
+            tagged.push((ordinal, item));
+        }
+        assert_eq!(tagged, [(0, 1), (0, 3), (1, 3), (2, 3), (3, 3), (0, 5), (0, 6)]);
+
+        // After wrapping around, the run starts over.
+        assert_eq!(iter.next_with_ordinal(), Some((0, &1)));
+    }
+
+    #[test]
+    fn prefer_backward_avoids_switch() {
+        let data_one: &[u8] = [0, 2, 4, 6, 8].as_slice();
+        let data_two: &[u8] = [1, 3, 5, 7, 9].as_slice();
+        let sources = || vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()];
+
+        let mut default_iter = MergingIter::new(sources(), OrdComparator);
+        let mut backward_iter = MergingIter::new(sources(), OrdComparator);
+        backward_iter.set_prefer_backward(true);
+
+        for iter in [&mut default_iter, &mut backward_iter] {
+            iter.seek(&7);
+            assert_eq!(iter.current(), Some(&7));
+            for expected in [6, 5, 4] {
+                assert_eq!(iter.prev(), Some(&expected));
+            }
+        }
+        assert_eq!(default_iter.direction_switches(), 1);
+        assert_eq!(backward_iter.direction_switches(), 0);
+
+        // Moving forwards after a seek now incurs the switch instead.
+        backward_iter.seek_to_first();
+        assert_eq!(backward_iter.next(), Some(&1));
+        assert_eq!(backward_iter.direction_switches(), 1);
+        backward_iter.seek_to_first();
+        assert_eq!(backward_iter.prev(), None);
+
+        iteration_without_duplicates(&mut backward_iter);
+    }
+
+    #[test]
+    fn seek_prefer_backward() {
+        let data_one:    &[u8] = [0, 3, 6, 7].as_slice();
+        let data_two:    &[u8] = [1, 5, 8].as_slice();
+        let data_three:  &[u8] = [2, 4, 9, 99].as_slice();
+        let merged_data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 99].as_slice();
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(data_one).unwrap(),
+                TestIter::new(data_two).unwrap(),
+                TestIter::new(data_three).unwrap(),
+            ],
+            OrdComparator,
+        );
+        iter.set_prefer_backward(true);
+
+        seek_tests(merged_data, &mut iter);
+    }
+
+    #[test]
+    fn prefer_backward_duplicates() {
+        use crate::{indexed::IndexedCursor as _, sorted_slice_cursor::SortedSliceCursor};
+
+        let data_one: &[u8] = [1, 2, 3].as_slice();
+        let data_two: &[u8] = [2, 4].as_slice();
+        let mut iter = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        iter.set_prefer_backward(true);
+
+        // The other entry with the current key is not skipped.
+        iter.seek(&2);
+        assert_eq!(iter.current_source(), Some(0));
+        assert_eq!(iter.prev(), Some(&2));
+        assert_eq!(iter.current_source(), Some(1));
+        assert_eq!(iter.prev(), Some(&1));
+
+        // Moving forwards after a seek does not skip the other entry with the current key.
+        let mut default_iter = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        for iter in [&mut default_iter, &mut iter] {
+            iter.seek(&2);
+            let mut collected = vec![*iter.current().unwrap()];
+            while let Some(&item) = iter.next() {
+                collected.push(item);
+            }
+            assert_eq!(collected, [2, 2, 3, 4]);
+        }
+
+        // Backwards iteration after a seek can be turned off again.
+        iter.set_prefer_backward(false);
+        assert!(!iter.prefers_backward());
+        iter.seek(&2);
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.direction_switches(), 1);
+
+        let data_one: &[u8] = [1, 2, 2, 3].as_slice();
+        let data_two: &[u8] = [0, 2, 2, 5].as_slice();
+        let mut iter = MergingIter::new_strict_duplicates(
+            vec![
+                SortedSliceCursor::new(data_one, OrdComparator),
+                SortedSliceCursor::new(data_two, OrdComparator),
+            ],
+            OrdComparator,
+        );
+        iter.set_prefer_backward(true);
+
+        let position = |iter: &MergingIter<_, _, SortedSliceCursor<'_, u8, OrdComparator>>| {
+            let source = iter.current_source()?;
+            let index = iter.iterators.get(source)?.current_index()?;
+            Some((*iter.current()?, source, index))
+        };
+
+        // The strict order is still followed.
+        iter.seek(&2);
+        assert_eq!(position(&iter), Some((2, 0, 1)));
+        iter.prev();
+        assert_eq!(position(&iter), Some((1, 0, 0)));
+        iter.seek(&3);
+        iter.prev();
+        assert_eq!(position(&iter), Some((2, 1, 2)));
+        assert_eq!(iter.direction_switches(), 0);
+        iter.seek(&2);
+        iter.next();
+        assert_eq!(position(&iter), Some((2, 0, 2)));
+        assert_eq!(iter.direction_switches(), 1);
+    }
+
+    #[test]
+    fn coverage_counts_sources() {
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 2, 2, 4, 5]).unwrap(),
+                TestIter::new(&[2, 3, 5]).unwrap(),
+                TestIter::new(&[2, 5, 5, 6]).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        let coverage = iter.coverage();
+        assert_eq!(coverage, [(1, 1), (2, 3), (3, 1), (4, 1), (5, 3), (6, 1)]);
+        assert!(!iter.valid());
+
+        // Counting starts from the current entry.
+        iter.seek(&5);
+        iter.next();
+        assert_eq!(iter.coverage(), [(5, 2), (6, 1)]);
+    }
+
+    #[test]
+    fn push_sorted_keeps_sources_sorted() {
+        let mut iter = MergingIter::new(Vec::new(), OrdComparator);
+        for data in [&[5, 6][..], &[], &[1, 9], &[7], &[3, 4], &[5, 8], &[0]] {
+            let source = TestIter::new(data).unwrap();
+            iter.push_sorted(source, data.first().unwrap_or(&u8::MAX));
+        }
+
+        let first_keys: Vec<Option<u8>> = iter.iterators.iter_mut()
+            .map(|source| {
+                source.seek_to_first();
+                source.current().copied()
+            })
+            .collect();
+        assert_eq!(
+            first_keys,
+            [Some(0), Some(1), Some(3), Some(5), Some(5), Some(7), None],
+        );
+        // The later source with an equal first key was placed after the earlier one.
+        let last_keys: Vec<Option<u8>> = iter.iterators.iter_mut().skip(3).take(2)
+            .map(|source| {
+                source.seek_to_last();
+                source.current().copied()
+            })
+            .collect();
+        assert_eq!(last_keys, [Some(6), Some(8)]);
+
+        iter.reset();
+        let mut merged = Vec::new();
+        iter.drain_into::<u8>(&mut merged);
+        assert_eq!(merged, [0, 1, 3, 4, 5, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn push_sorted_concat() {
+        let mut iter = MergingIter::new_auto(
+            vec![TestIter::new(&[6, 7]).unwrap(), TestIter::new(&[0, 1]).unwrap()],
+            OrdComparator,
+        );
+        assert_eq!(iter.strategy(), MergeStrategy::Concat);
+
+        iter.push_sorted(TestIter::new(&[3, 4]).unwrap(), &3);
+        assert_eq!(iter.strategy(), MergeStrategy::Concat);
+        let mut merged = Vec::new();
+        iter.drain_into::<u8>(&mut merged);
+        assert_eq!(merged, [0, 1, 3, 4, 6, 7]);
+
+        // This overlaps with `[6, 7]`.
+        iter.push_sorted(TestIter::new(&[5, 6]).unwrap(), &5);
+        assert_eq!(iter.strategy(), MergeStrategy::Merge);
+        iter.drain_into::<u8>(&mut merged);
+        assert_eq!(merged, [0, 1, 3, 4, 5, 6, 6, 7]);
+    }
 }
@@ -0,0 +1,328 @@
+use core::{cmp::Ordering, ops::Bound};
+use alloc::collections::BTreeMap;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+    seekable_iterators::SeekableLendingIterator,
+};
+
+
+/// The source of the current entry of an [`OverlayIter`].
+#[derive(Debug, Clone)]
+enum Position<Key> {
+    Base,
+    Overlay(Key),
+}
+
+/// A [`SeekableLendingIterator`] over a base [`SeekableLendingIterator`] combined with an
+/// in-memory overlay of recently written items, where overlay items shadow base items with equal
+/// keys.
+///
+/// This is a two-source merge with a defined precedence: if the overlay has an item with some
+/// key, then every base item with that key is skipped. Items can be [inserted] into the overlay
+/// at any time, including between reads; the iterator does not move on insertion, and the new
+/// item is observed the next time the iterator moves or seeks. Since the iterator's position is
+/// tracked by key, moving or seeking always accounts for every insertion made so far.
+///
+/// The base iterator must lend `&T` items, and the overlay stores `T` items (keyed by an owned
+/// copy of each item's key, as extracted by the base iterator's [`ItemToKey`] implementation).
+/// The overlay is a [`BTreeMap`], ordered by the [`Ord`] implementation of `Key`; that order
+/// must agree with the provided comparator, which is used to compare base keys against overlay
+/// keys.
+///
+/// [inserted]: OverlayIter::insert
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct OverlayIter<Base, Cmp, Key, T> {
+    base:     Base,
+    cmp:      Cmp,
+    overlay:  BTreeMap<Key, T>,
+    /// `None` if `self` is not valid. If `Some(Position::Base)`, then `base` is valid.
+    position: Option<Position<Key>>,
+}
+
+impl<Base, Cmp, Key, T> OverlayIter<Base, Cmp, Key, T>
+where
+    Base: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>
+        + for<'lend> LendItem<'lend, Item = &'lend T>,
+    Cmp:  Comparator<Key>,
+    Key:  Ord + Clone,
+{
+    /// Create an iterator over `base`, with an initially-empty overlay.
+    ///
+    /// The new iterator is `!valid()`, regardless of the position of `base`.
+    #[must_use]
+    pub fn new(mut base: Base, cmp: Cmp) -> Self {
+        base.reset();
+        Self {
+            base,
+            cmp,
+            overlay:  BTreeMap::new(),
+            position: None,
+        }
+    }
+
+    /// Insert an item into the overlay, shadowing any base items with the same key.
+    ///
+    /// If the overlay already had an item with the same key, that item is replaced and returned.
+    /// The iterator does not move; see the type-level documentation.
+    pub fn insert(&mut self, item: T) -> Option<T> {
+        let key = Base::item_to_key(&item).clone();
+        self.overlay.insert(key, item)
+    }
+
+    /// Get the overlay of items written so far.
+    #[inline]
+    #[must_use]
+    pub const fn overlay(&self) -> &BTreeMap<Key, T> {
+        &self.overlay
+    }
+
+    /// Get the comparator used to compare base keys against overlay keys.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Convert the iterator back into the base iterator and the overlay.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (Base, BTreeMap<Key, T>) {
+        (self.base, self.overlay)
+    }
+
+    /// Get an owned copy of the current key.
+    fn current_key(&self) -> Option<Key> {
+        match self.position.as_ref()? {
+            Position::Base => {
+                self.base.current().map(|item| Base::item_to_key(item).clone())
+            }
+            Position::Overlay(key) => Some(key.clone()),
+        }
+    }
+
+    /// Choose between the base iterator's current item and the overlay item with key
+    /// `overlay_key`. When moving `forwards`, the lesser is chosen; otherwise, the greater is
+    /// chosen. When the keys are equal, the overlay item is chosen.
+    fn settle(&mut self, overlay_key: Option<Key>, forwards: bool) {
+        let base_key = self.base.current().map(Base::item_to_key);
+
+        self.position = match (base_key, overlay_key) {
+            (None, None)           => None,
+            (Some(_), None)        => Some(Position::Base),
+            (None, Some(overlay))  => Some(Position::Overlay(overlay)),
+            (Some(base), Some(overlay)) => {
+                let preferred = if forwards { Ordering::Less } else { Ordering::Greater };
+                if self.cmp.cmp(base, &overlay) == preferred {
+                    Some(Position::Base)
+                } else {
+                    Some(Position::Overlay(overlay))
+                }
+            }
+        };
+    }
+
+    /// Get the first overlay key within the given bounds.
+    fn first_overlay_key(&self, lower: Bound<&Key>) -> Option<Key> {
+        self.overlay.range((lower, Bound::Unbounded)).next().map(|(key, _)| key.clone())
+    }
+
+    /// Get the last overlay key within the given bounds.
+    fn last_overlay_key(&self, upper: Bound<&Key>) -> Option<Key> {
+        self.overlay.range((Bound::Unbounded, upper)).next_back().map(|(key, _)| key.clone())
+    }
+}
+
+impl<'lend, Base, Cmp, Key, T> LendItem<'lend> for OverlayIter<Base, Cmp, Key, T> {
+    type Item = &'lend T;
+}
+
+impl<Base, Cmp, Key, T> CursorLendingIterator for OverlayIter<Base, Cmp, Key, T>
+where
+    Base: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>
+        + for<'lend> LendItem<'lend, Item = &'lend T>,
+    Cmp:  Comparator<Key>,
+    Key:  Ord + Clone,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.position.is_some()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        let Some(current_key) = self.current_key() else {
+            self.seek_to_first();
+            return self.current();
+        };
+
+        if matches!(self.position, Some(Position::Base)) {
+            // The base items with the current key are not shadowed, so any duplicates should be
+            // yielded.
+            self.base.next();
+        } else {
+            // Skip the shadowed base items.
+            self.base.seek(&current_key);
+            while self.base.current().is_some_and(|item| {
+                self.cmp.cmp(Base::item_to_key(item), &current_key) == Ordering::Equal
+            }) {
+                self.base.next();
+            }
+        }
+
+        let overlay_key = self.first_overlay_key(Bound::Excluded(&current_key));
+        self.settle(overlay_key, true);
+        self.current()
+    }
+
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        match self.position.as_ref()? {
+            Position::Base         => self.base.current(),
+            Position::Overlay(key) => self.overlay.get(key),
+        }
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        let Some(current_key) = self.current_key() else {
+            self.seek_to_last();
+            return self.current();
+        };
+
+        if matches!(self.position, Some(Position::Base)) {
+            self.base.prev();
+        } else {
+            self.base.seek_before(&current_key);
+        }
+
+        let overlay_key = self.last_overlay_key(Bound::Excluded(&current_key));
+        self.settle(overlay_key, false);
+        self.current()
+    }
+
+    /// Overlay entries replace every base entry with an equal key, so keys are unique if the
+    /// base's keys are.
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.base.keys_are_unique()
+    }
+}
+
+impl<Base, Cmp, Key, T> ItemToKey<Key> for OverlayIter<Base, Cmp, Key, T>
+where
+    Base: ItemToKey<Key> + for<'lend> LendItem<'lend, Item = &'lend T>,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        Base::item_to_key(item)
+    }
+}
+
+impl<Base, Cmp, Key, T> Seekable<Key, Cmp> for OverlayIter<Base, Cmp, Key, T>
+where
+    Base: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>
+        + for<'lend> LendItem<'lend, Item = &'lend T>,
+    Cmp:  Comparator<Key>,
+    Key:  Ord + Clone,
+{
+    fn reset(&mut self) {
+        self.base.reset();
+        self.position = None;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.base.seek(min_bound);
+        let overlay_key = self.first_overlay_key(Bound::Included(min_bound));
+        self.settle(overlay_key, true);
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.base.seek_before(strict_upper_bound);
+        let overlay_key = self.last_overlay_key(Bound::Excluded(strict_upper_bound));
+        self.settle(overlay_key, false);
+    }
+
+    fn seek_to_first(&mut self) {
+        self.base.seek_to_first();
+        let overlay_key = self.first_overlay_key(Bound::Unbounded);
+        self.settle(overlay_key, true);
+    }
+
+    fn seek_to_last(&mut self) {
+        self.base.seek_to_last();
+        let overlay_key = self.last_overlay_key(Bound::Unbounded);
+        self.settle(overlay_key, false);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use super::*;
+
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Entry {
+        key:   u8,
+        value: &'static str,
+    }
+
+    impl Borrow<u8> for Entry {
+        fn borrow(&self) -> &u8 {
+            &self.key
+        }
+    }
+
+    const fn entry(key: u8, value: &'static str) -> Entry {
+        Entry { key, value }
+    }
+
+    #[test]
+    fn overlay_shadows_base() {
+        static BASE: [Entry; 4] = [entry(1, "a"), entry(3, "b"), entry(5, "c"), entry(7, "d")];
+        let base = SortedSliceCursor::new(BASE.as_slice(), OrdComparator);
+        let mut iter = OverlayIter::new(base, OrdComparator);
+
+        assert_eq!(iter.next().map(|entry| entry.value), Some("a"));
+        assert_eq!(iter.next().map(|entry| entry.value), Some("b"));
+
+        // Write mid-iteration.
+        assert_eq!(iter.insert(entry(5, "overlay c")), None);
+        assert_eq!(iter.insert(entry(4, "new")), None);
+        assert_eq!(iter.insert(entry(9, "end")), None);
+
+        assert_eq!(iter.next().map(|entry| entry.value), Some("new"));
+        assert_eq!(iter.next().map(|entry| entry.value), Some("overlay c"));
+        assert_eq!(iter.next().map(|entry| entry.value), Some("d"));
+        assert_eq!(iter.next().map(|entry| entry.value), Some("end"));
+        assert_eq!(iter.next(), None);
+
+        let values = ["end", "d", "overlay c", "new", "b", "a"];
+        for value in values {
+            assert_eq!(iter.prev().map(|entry| entry.value), Some(value));
+        }
+        assert_eq!(iter.prev(), None);
+
+        // Re-seek, and write over a base item which was already passed.
+        iter.seek(&3);
+        assert_eq!(iter.current().map(|entry| entry.value), Some("b"));
+        assert_eq!(iter.insert(entry(1, "overlay a")), None);
+        assert_eq!(iter.insert(entry(5, "overlay c again")), Some(entry(5, "overlay c")));
+
+        iter.seek(&0);
+        assert_eq!(iter.current().map(|entry| entry.value), Some("overlay a"));
+        iter.seek(&5);
+        assert_eq!(iter.current().map(|entry| entry.value), Some("overlay c again"));
+        iter.seek_before(&5);
+        assert_eq!(iter.current().map(|entry| entry.value), Some("new"));
+        iter.seek_to_last();
+        assert_eq!(iter.current().map(|entry| entry.value), Some("end"));
+        iter.seek_to_first();
+        assert_eq!(iter.current().map(|entry| entry.value), Some("overlay a"));
+        assert_eq!(iter.next().map(|entry| entry.value), Some("b"));
+    }
+}
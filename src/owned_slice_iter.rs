@@ -0,0 +1,158 @@
+use crate::{comparator::OrdComparator, cursor::{CursorIterator, CursorLendingIterator as _}};
+use crate::{indexed::{CountBetweenHint, IndexedCursor}, seekable::Seekable};
+use crate::sorted_slice_cursor::SortedSliceCursor;
+
+
+/// A [`SeekableIterator`] over a sorted slice of [`Copy`] items, which yields copies of the
+/// slice's entries.
+///
+/// This is a non-lending counterpart of [`SortedSliceCursor`], using [`OrdComparator`]. It
+/// implements [`Iterator`], [`CursorIterator`], and [`Seekable`], and can be used wherever an
+/// owning cursor is needed.
+///
+/// As with every [`CursorIterator`], it is circular, and is thus not a [`FusedIterator`].
+///
+/// [`SeekableIterator`]: crate::SeekableIterator
+/// [`FusedIterator`]: core::iter::FusedIterator
+#[derive(Debug, Clone)]
+pub struct OwnedSliceIter<'a, T> {
+    cursor: SortedSliceCursor<'a, T, OrdComparator>,
+}
+
+impl<'a, T> OwnedSliceIter<'a, T> {
+    /// Create an iterator over `data`, which must be sorted according to [`Ord`].
+    ///
+    /// The iterator starts out `!valid()`.
+    #[inline]
+    #[must_use]
+    pub const fn new(data: &'a [T]) -> Self {
+        Self {
+            cursor: SortedSliceCursor::new(data, OrdComparator),
+        }
+    }
+
+    /// Get the slice the iterator iterates over.
+    #[inline]
+    #[must_use]
+    pub const fn data(&self) -> &'a [T] {
+        self.cursor.data()
+    }
+}
+
+impl<T: Copy> Iterator for OwnedSliceIter<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next().copied()
+    }
+}
+
+impl<T: Copy> CursorIterator for OwnedSliceIter<'_, T> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.cursor.valid()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<Self::Item> {
+        self.cursor.current().copied()
+    }
+
+    #[inline]
+    fn prev(&mut self) -> Option<Self::Item> {
+        self.cursor.prev().copied()
+    }
+}
+
+impl<T> IndexedCursor for OwnedSliceIter<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.cursor.len()
+    }
+
+    #[inline]
+    fn current_index(&self) -> Option<usize> {
+        self.cursor.current_index()
+    }
+
+    #[inline]
+    fn seek_to_index(&mut self, index: usize) {
+        self.cursor.seek_to_index(index);
+    }
+}
+
+impl<T: Ord> CountBetweenHint<T, OrdComparator> for OwnedSliceIter<'_, T> {
+    #[inline]
+    fn count_between_hint(&self, cmp: &OrdComparator, lower: &T, upper: &T) -> Option<usize> {
+        self.cursor.count_between_hint(cmp, lower, upper)
+    }
+}
+
+impl<T: Ord> Seekable<T, OrdComparator> for OwnedSliceIter<'_, T> {
+    #[inline]
+    fn reset(&mut self) {
+        self.cursor.reset();
+    }
+
+    #[inline]
+    fn seek(&mut self, min_bound: &T) {
+        self.cursor.seek(min_bound);
+    }
+
+    #[inline]
+    fn seek_before(&mut self, strict_upper_bound: &T) {
+        self.cursor.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.cursor.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.cursor.seek_to_last();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::seekable_iterators::SeekableIterator;
+    use super::*;
+
+
+    const fn assert_seekable<I: SeekableIterator<u32, OrdComparator>>(_iter: &I) {}
+
+    #[test]
+    fn owning_cursor() {
+        let data: &[u32] = [2, 4, 4, 8].as_slice();
+        let mut iter = OwnedSliceIter::new(data);
+        assert_seekable(&iter);
+
+        assert!(!iter.valid());
+        assert_eq!(iter.current(), None);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.current(), Some(2));
+        assert_eq!(iter.prev(), None);
+        assert_eq!(iter.prev(), Some(8));
+
+        iter.seek(&3);
+        assert_eq!(iter.current(), Some(4));
+        assert_eq!(iter.current_index(), Some(1));
+        iter.seek_before(&4);
+        assert_eq!(iter.current(), Some(2));
+        iter.seek(&9);
+        assert!(!iter.valid());
+
+        iter.seek_to_last();
+        assert_eq!(iter.current(), Some(8));
+        assert_eq!(iter.prev(), Some(4));
+        assert_eq!(iter.current_index(), Some(2));
+
+        iter.reset();
+        assert!(!iter.valid());
+        assert!(iter.eq([2, 4, 4, 8]));
+    }
+}
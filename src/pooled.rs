@@ -52,6 +52,26 @@ pub trait PooledIterator {
     #[must_use]
     fn available_buffers(&self) -> usize;
 
+    /// Move the iterator forwards through the rest of the collection, calling `f` on each entry.
+    ///
+    /// Each item is dropped immediately after `f` is called on it, returning its buffer to the
+    /// buffer pool before the next item is obtained. Therefore, only one buffer is in use at a
+    /// time, so this is safe to use even if the buffer pool has only one buffer.
+    ///
+    /// # Potential Panics or Deadlocks
+    /// If `self.buffer_pool_size() == 0`, then this method is permitted to panic or deadlock.
+    /// This method may also panic or cause a deadlock if no buffers are currently available, and
+    /// the current thread needs to make progress in order to release a buffer.
+    fn for_each<F>(&mut self, mut f: F)
+    where
+        Self: Sized,
+        F:    FnMut(&Self::Item),
+    {
+        while let Some(item) = self.next() {
+            f(&item);
+        }
+    }
+
     /// Convert the `PooledIterator` into a [`lender::Lender`] lending iterator which only uses
     /// one buffer at a time.
     ///
@@ -89,7 +109,7 @@ pub trait PooledIterator {
 
 /// An error that may be returned if no buffer pools were available in a [`PooledIterator`],
 /// instead of waiting for a buffer to become available.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OutOfBuffers;
 
 impl Display for OutOfBuffers {
@@ -102,3 +122,27 @@ impl Display for OutOfBuffers {
 }
 
 impl Error for OutOfBuffers {}
+
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
+    use super::*;
+
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn out_of_buffers_in_set() {
+        let mut set = HashSet::new();
+        assert!(set.insert(OutOfBuffers));
+        assert!(!set.insert(OutOfBuffers));
+        assert!(set.contains(&OutOfBuffers));
+    }
+
+    #[test]
+    fn out_of_buffers_eq() {
+        assert_eq!(OutOfBuffers, OutOfBuffers);
+        assert_eq!(Err::<(), _>(OutOfBuffers), Err(OutOfBuffers));
+    }
+}
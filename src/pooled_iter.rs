@@ -1,10 +1,12 @@
 use core::{
     borrow::{Borrow, BorrowMut},
+    cell::{Cell, RefCell},
+    fmt::{Debug, Formatter, Result as FmtResult},
     ops::{Deref, DerefMut},
 };
-use alloc::borrow::ToOwned;
+use alloc::{borrow::ToOwned, rc::{Rc, Weak}, vec::Vec};
 
-use anchored_pool::{PooledResource, ResetNothing, ResourcePoolEmpty, BoundedPool};
+use anchored_pool::{PooledResource, ResetResource, ResourcePoolEmpty, BoundedPool};
 
 use crate::{comparator::Comparator, lending_iterator_support::LentItem, seekable::Seekable};
 use crate::{
@@ -29,8 +31,13 @@ use crate::{
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub struct PooledIter<I, BorrowedItem: ToOwned> {
-    iter: I,
-    pool: BoundedPool<BorrowedItem::Owned, ResetNothing>,
+    iter:      I,
+    pool:      BoundedPool<BorrowedItem::Owned, OnReturn<BorrowedItem::Owned>>,
+    /// The largest number of buffers observed to be in use at once.
+    peak_used: Cell<usize>,
+    /// Whether items are copied into buffers from the pool. See
+    /// [`PooledIter::set_recycling_enabled`].
+    recycling: bool,
 }
 
 impl<I, BorrowedItem> PooledIter<I, BorrowedItem>
@@ -47,9 +54,135 @@ where
     /// Therefore, `PooledIter` panics in such a scenario.
     #[must_use]
     pub fn new(iter: I, num_buffers: usize) -> Self {
-        let pool = BoundedPool::new_default_without_reset(num_buffers);
+        Self::new_with_reset(iter, num_buffers, OnReturn(None))
+    }
+
+    /// Create a `PooledIter` that can lend out up to `num_buffers` items at a time, and which
+    /// calls `on_return` on each buffer when its [`PoolItem`] is dropped and the buffer is
+    /// returned to the pool.
+    ///
+    /// This can be used to release resources held by the owned items, or to count returns.
+    /// See [`PooledIter::new`] for the requirements on the number of buffers used.
+    ///
+    /// # Panics
+    /// The returned `PooledIter`, and any of its [`PoolItem`]s, panic if a [`PoolItem`] is
+    /// dropped from within `on_return`.
+    #[must_use]
+    pub fn new_with_on_return<F>(iter: I, num_buffers: usize, on_return: F) -> Self
+    where
+        F: FnMut(&mut BorrowedItem::Owned) + 'static,
+    {
+        let on_return: SharedCallback<BorrowedItem::Owned> = Rc::new(RefCell::new(on_return));
+        Self::new_with_reset(iter, num_buffers, OnReturn(Some(on_return)))
+    }
+
+    /// Create a [`CachingPooledIter`] that can lend out up to `num_buffers` items at a time,
+    /// and whose [`current`] reuses the buffer most recently lent for the current position
+    /// instead of filling a new buffer.
+    ///
+    /// See [`CachingPooledIter`] for details, and [`PooledIter::new`] for the requirements on
+    /// the number of buffers used.
+    ///
+    /// [`current`]: CursorPooledIterator::current
+    #[must_use]
+    pub fn new_caching_current(iter: I, num_buffers: usize) -> CachingPooledIter<I, BorrowedItem> {
+        CachingPooledIter {
+            inner:  Self::new(iter, num_buffers),
+            cached: RefCell::new(Weak::new()),
+        }
+    }
 
-        Self { iter, pool }
+    #[must_use]
+    fn new_with_reset(iter: I, num_buffers: usize, reset: OnReturn<BorrowedItem::Owned>) -> Self {
+        let pool = BoundedPool::new_default(num_buffers, reset);
+
+        Self {
+            iter,
+            pool,
+            peak_used: Cell::new(0),
+            recycling: true,
+        }
+    }
+}
+
+impl<I, BorrowedItem: ToOwned> PooledIter<I, BorrowedItem> {
+    /// Create a `PooledIter` that can lend out up to `num_buffers` items at a time, whose buffers
+    /// are each created by `make_buffer` instead of with [`Default`].
+    ///
+    /// This can be used to preallocate buffers, so that copying items into them does not need to
+    /// allocate. See [`PooledIter::new`] for the requirements on the number of buffers used.
+    #[must_use]
+    pub fn new_with_factory<F>(iter: I, num_buffers: usize, make_buffer: F) -> Self
+    where
+        F: FnMut() -> BorrowedItem::Owned,
+    {
+        Self {
+            iter,
+            pool:      BoundedPool::new(num_buffers, make_buffer, OnReturn(None)),
+            peak_used: Cell::new(0),
+            recycling: true,
+        }
+    }
+
+    /// Create a `PooledIter` that can lend out up to `num_buffers` items at a time, whose `Vec`
+    /// buffers are each created with a capacity of `item_capacity`.
+    ///
+    /// Items of at most `item_capacity` elements are then copied into the buffers without
+    /// reallocating. See [`PooledIter::new`] for the requirements on the number of buffers used.
+    #[must_use]
+    pub fn new_with_capacity<T>(iter: I, num_buffers: usize, item_capacity: usize) -> Self
+    where
+        BorrowedItem: ToOwned<Owned = Vec<T>>,
+    {
+        Self::new_with_factory(iter, num_buffers, || Vec::with_capacity(item_capacity))
+    }
+
+    /// Get the largest number of buffers observed to be in use at the same time.
+    ///
+    /// Usage is recorded whenever this iterator lends out an item, and counts every buffer held
+    /// at that time. This can help to determine whether the `num_buffers` provided to
+    /// [`PooledIter::new`] is larger or smaller than needed.
+    #[inline]
+    #[must_use]
+    pub fn peak_buffers_used(&self) -> usize {
+        self.peak_used.get()
+    }
+
+    /// Set whether lent items are copied into buffers from the pool, which is enabled by default.
+    ///
+    /// While recycling is disabled, each lent item is a freshly allocated owned copy which does
+    /// not come from, and is not returned to, the pool: [`available_buffers`] is unaffected by
+    /// such items, `try_` methods cannot fail, [`PooledIter::peak_buffers_used`] does not count
+    /// them, and a [`CachingPooledIter`] does not share them between calls. Items lent
+    /// before recycling was disabled are still returned to the pool when dropped. Re-enabling
+    /// recycling resumes lending items in pooled buffers.
+    ///
+    /// This defeats the point of pooling, and is intended only for diagnostics, such as observing
+    /// the true allocation pressure of a workload when measuring worst-case memory usage.
+    ///
+    /// [`available_buffers`]: PooledIterator::available_buffers
+    #[inline]
+    pub const fn set_recycling_enabled(&mut self, enabled: bool) {
+        self.recycling = enabled;
+    }
+
+    /// Get whether lent items are copied into buffers from the pool. See
+    /// [`PooledIter::set_recycling_enabled`].
+    #[inline]
+    #[must_use]
+    pub const fn recycling_enabled(&self) -> bool {
+        self.recycling
+    }
+
+    /// Update the peak number of buffers in use with the current number of buffers in use.
+    ///
+    /// Takes the fields of `self` separately, so that `self.iter` may remain borrowed.
+    fn record_usage(
+        pool:      &BoundedPool<BorrowedItem::Owned, OnReturn<BorrowedItem::Owned>>,
+        peak_used: &Cell<usize>,
+    ) {
+        let used = pool.pool_size() - pool.available_resources();
+        peak_used.set(peak_used.get().max(used));
     }
 }
 
@@ -64,12 +197,20 @@ where
     #[expect(clippy::needless_pass_by_value, reason = "lent item usually consists of references")]
     #[inline]
     fn fill_buffer(
-        pool: &BoundedPool<BorrowedItem::Owned, ResetNothing>,
+        pool: &BoundedPool<BorrowedItem::Owned, OnReturn<BorrowedItem::Owned>>,
         item: LentItem<'_, I>,
     ) -> PoolItem<BorrowedItem::Owned> {
-        let mut pool_item = pool.get();
-        item.borrow().clone_into(&mut pool_item);
-        PoolItem(pool_item)
+        let mut buffer = pool.get();
+        item.borrow().clone_into(&mut buffer);
+        PoolItem(PoolItemBuffer::Unique(buffer))
+    }
+
+    /// Lend a freshly allocated copy of `item` which does not use the pool, for when recycling
+    /// is disabled.
+    #[expect(clippy::needless_pass_by_value, reason = "lent item usually consists of references")]
+    #[inline]
+    fn detach(item: LentItem<'_, I>) -> PoolItem<BorrowedItem::Owned> {
+        PoolItem(PoolItemBuffer::Detached(item.borrow().to_owned()))
     }
 }
 
@@ -87,16 +228,27 @@ where
     /// # Panics
     /// Panics if there are no buffers available.
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|item| Self::fill_buffer(&self.pool, item))
+        if !self.recycling {
+            return self.iter.next().map(Self::detach);
+        }
+
+        let item = self.iter.next().map(|item| Self::fill_buffer(&self.pool, item));
+        Self::record_usage(&self.pool, &self.peak_used);
+        item
     }
 
     fn try_next(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        if !self.recycling {
+            return Ok(self.iter.next().map(Self::detach));
+        }
+
         let mut buffer = self.pool.try_get()
             .map_err(|ResourcePoolEmpty| OutOfBuffers)?;
 
         if let Some(item) = self.iter.next() {
             item.borrow().clone_into(&mut buffer);
-            Ok(Some(PoolItem(buffer)))
+            Self::record_usage(&self.pool, &self.peak_used);
+            Ok(Some(PoolItem(PoolItemBuffer::Unique(buffer))))
         } else {
             Ok(None)
         }
@@ -131,16 +283,25 @@ where
     /// [valid]: CursorPooledIterator::valid
     #[inline]
     fn current(&self) -> Option<Self::Item> {
-        self.iter.current().map(|item| Self::fill_buffer(&self.pool, item))
+        if !self.recycling {
+            return self.iter.current().map(Self::detach);
+        }
+        let item = self.iter.current().map(|item| Self::fill_buffer(&self.pool, item));
+        Self::record_usage(&self.pool, &self.peak_used);
+        item
     }
 
     fn try_current(&self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        if !self.recycling {
+            return Ok(self.iter.current().map(Self::detach));
+        }
         let mut buffer = self.pool.try_get()
             .map_err(|ResourcePoolEmpty| OutOfBuffers)?;
 
         if let Some(item) = self.iter.current() {
             item.borrow().clone_into(&mut buffer);
-            Ok(Some(PoolItem(buffer)))
+            Self::record_usage(&self.pool, &self.peak_used);
+            Ok(Some(PoolItem(PoolItemBuffer::Unique(buffer))))
         } else {
             Ok(None)
         }
@@ -155,16 +316,27 @@ where
     /// # Panics
     /// Panics if there are no buffers available.
     fn prev(&mut self) -> Option<Self::Item> {
-        self.iter.prev().map(|item| Self::fill_buffer(&self.pool, item))
+        if !self.recycling {
+            return self.iter.prev().map(Self::detach);
+        }
+
+        let item = self.iter.prev().map(|item| Self::fill_buffer(&self.pool, item));
+        Self::record_usage(&self.pool, &self.peak_used);
+        item
     }
 
     fn try_prev(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        if !self.recycling {
+            return Ok(self.iter.prev().map(Self::detach));
+        }
+
         let mut buffer = self.pool.try_get()
             .map_err(|ResourcePoolEmpty| OutOfBuffers)?;
 
         if let Some(item) = self.iter.prev() {
             item.borrow().clone_into(&mut buffer);
-            Ok(Some(PoolItem(buffer)))
+            Self::record_usage(&self.pool, &self.peak_used);
+            Ok(Some(PoolItem(PoolItemBuffer::Unique(buffer))))
         } else {
             Ok(None)
         }
@@ -202,28 +374,286 @@ where
     }
 }
 
+/// A [`PooledIter`] whose [`current`] reuses the buffer most recently lent for the current
+/// position instead of filling a new buffer, created with [`PooledIter::new_caching_current`].
+///
+/// By default, each call to [`current`] fills a separate buffer, even immediately after
+/// [`next`] has lent out the same entry. With a `CachingPooledIter`:
+/// - Moving with [`next`] or [`prev`] (or their `try_` variants) fills a new buffer, as usual,
+///   and remembers it as the buffer of the new current position.
+/// - [`current`] and [`try_current`] return a [`SharedPoolItem`] sharing the remembered buffer,
+///   if a [`SharedPoolItem`] holding it is still alive; this takes no buffer from the pool, and
+///   `try_current` cannot fail. Otherwise (for instance, after a seek or [`reset`], or once every
+///   item lent at the current position has been dropped), a new buffer is filled and remembered
+///   in the same way.
+/// - The `CachingPooledIter` does not itself hold any buffer; a buffer is returned to the pool
+///   once every [`SharedPoolItem`] sharing it has been dropped.
+///
+/// Since their buffers may be shared, the lent [`SharedPoolItem`]s only give immutable access
+/// to their items.
+///
+/// [`current`]: CursorPooledIterator::current
+/// [`try_current`]: CursorPooledIterator::try_current
+/// [`next`]: PooledIterator::next
+/// [`prev`]: CursorPooledIterator::prev
+/// [`reset`]: Seekable::reset
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct CachingPooledIter<I, BorrowedItem: ToOwned> {
+    inner:  PooledIter<I, BorrowedItem>,
+    /// Refers to the item lent for the current position, if any [`SharedPoolItem`] still holds
+    /// it.
+    cached: RefCell<Weak<PoolItem<BorrowedItem::Owned>>>,
+}
+
+impl<I, BorrowedItem: ToOwned> CachingPooledIter<I, BorrowedItem> {
+    /// Get the largest number of buffers observed to be in use at the same time.
+    ///
+    /// See [`PooledIter::peak_buffers_used`].
+    #[inline]
+    #[must_use]
+    pub fn peak_buffers_used(&self) -> usize {
+        self.inner.peak_buffers_used()
+    }
+
+    /// Set whether lent items are copied into buffers from the pool, which is enabled by default.
+    ///
+    /// While recycling is disabled, items are not shared between calls. See
+    /// [`PooledIter::set_recycling_enabled`].
+    #[inline]
+    pub const fn set_recycling_enabled(&mut self, enabled: bool) {
+        self.inner.set_recycling_enabled(enabled);
+    }
+
+    /// Get whether lent items are copied into buffers from the pool. See
+    /// [`PooledIter::set_recycling_enabled`].
+    #[inline]
+    #[must_use]
+    pub const fn recycling_enabled(&self) -> bool {
+        self.inner.recycling_enabled()
+    }
+
+    /// Share a newly lent item for the current position, remembering it as the item of the
+    /// current position if recycling is enabled.
+    fn share(&self, item: PoolItem<BorrowedItem::Owned>) -> SharedPoolItem<BorrowedItem::Owned> {
+        let shared = Rc::new(item);
+        if self.inner.recycling {
+            *self.cached.borrow_mut() = Rc::downgrade(&shared);
+        }
+        SharedPoolItem(shared)
+    }
+
+    /// Get a [`SharedPoolItem`] sharing the item remembered for the current position, if there
+    /// is one.
+    fn cached_current(&self) -> Option<SharedPoolItem<BorrowedItem::Owned>> {
+        if !self.inner.recycling {
+            return None;
+        }
+        self.cached.borrow().upgrade().map(SharedPoolItem)
+    }
+
+    /// Forget the item remembered for the current position, before the position changes.
+    fn forget_current(&self) {
+        *self.cached.borrow_mut() = Weak::new();
+    }
+}
+
+impl<I, BorrowedItem> PooledIterator for CachingPooledIter<I, BorrowedItem>
+where
+    I:                             CursorLendingIterator,
+    BorrowedItem:                  ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    type Item = SharedPoolItem<BorrowedItem::Owned>;
+
+    /// Move the iterator one position forwards, and return the entry at that position.
+    /// Returns `None` if the iterator was at the last entry.
+    ///
+    /// # Panics
+    /// Panics if there are no buffers available.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.forget_current();
+        let item = self.inner.next()?;
+        Some(self.share(item))
+    }
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        let item = self.inner.try_next()?;
+        self.forget_current();
+        Ok(item.map(|item| self.share(item)))
+    }
+
+    #[inline]
+    fn buffer_pool_size(&self) -> usize {
+        self.inner.buffer_pool_size()
+    }
+
+    #[inline]
+    fn available_buffers(&self) -> usize {
+        self.inner.available_buffers()
+    }
+}
+
+impl<I, BorrowedItem> CursorPooledIterator for CachingPooledIter<I, BorrowedItem>
+where
+    I:                             CursorLendingIterator,
+    BorrowedItem:                  ToOwned,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.inner.valid()
+    }
+
+    /// Get the current value the iterator is at, if the iterator is [valid].
+    ///
+    /// The buffer most recently lent for the current position is shared, if it is still alive,
+    /// instead of filling a new one.
+    ///
+    /// # Panics
+    /// Panics if a new buffer is needed and there are no buffers available.
+    ///
+    /// [valid]: CursorPooledIterator::valid
+    fn current(&self) -> Option<Self::Item> {
+        if let Some(cached) = self.cached_current() {
+            return Some(cached);
+        }
+        let item = self.inner.current()?;
+        Some(self.share(item))
+    }
+
+    fn try_current(&self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        if let Some(cached) = self.cached_current() {
+            return Ok(Some(cached));
+        }
+        let item = self.inner.try_current()?;
+        Ok(item.map(|item| self.share(item)))
+    }
+
+    /// Move the iterator one position back, and return the entry at that position.
+    /// Returns `None` if the iterator was at the first entry.
+    ///
+    /// # Panics
+    /// Panics if there are no buffers available.
+    fn prev(&mut self) -> Option<Self::Item> {
+        self.forget_current();
+        let item = self.inner.prev()?;
+        Some(self.share(item))
+    }
+
+    fn try_prev(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
+        let item = self.inner.try_prev()?;
+        self.forget_current();
+        Ok(item.map(|item| self.share(item)))
+    }
+}
+
+impl<I, BorrowedItem, Key, Cmp> Seekable<Key, Cmp> for CachingPooledIter<I, BorrowedItem>
+where
+    I:                             CursorLendingIterator + Seekable<Key, Cmp>,
+    BorrowedItem:                  ToOwned,
+    Key:                           ?Sized,
+    Cmp:                           Comparator<Key>,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.forget_current();
+        self.inner.reset();
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.forget_current();
+        self.inner.seek(min_bound);
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.forget_current();
+        self.inner.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.forget_current();
+        self.inner.seek_to_first();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.forget_current();
+        self.inner.seek_to_last();
+    }
+}
+
+/// The reset strategy of the buffer pool of a [`PooledIter`], which runs an optional callback on
+/// each buffer returned to the pool.
+struct OnReturn<OwnedItem>(Option<SharedCallback<OwnedItem>>);
+
+/// A callback run on buffers returned to the pool of a [`PooledIter`].
+type SharedCallback<OwnedItem> = Rc<RefCell<dyn FnMut(&mut OwnedItem)>>;
+
+impl<OwnedItem> ResetResource<OwnedItem> for OnReturn<OwnedItem> {
+    #[inline]
+    fn reset(&self, resource: &mut OwnedItem) {
+        if let Some(on_return) = &self.0 {
+            (RefCell::borrow_mut(on_return))(resource);
+        }
+    }
+}
+
+impl<OwnedItem> Clone for OnReturn<OwnedItem> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<OwnedItem> Debug for OnReturn<OwnedItem> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("OnReturn")
+            .field(&self.0.as_ref().map(|_| "<closure>"))
+            .finish()
+    }
+}
+
+/// A buffer from the pool of a [`PooledIter`].
+type Buffer<OwnedItem> = PooledResource<BoundedPool<OwnedItem, OnReturn<OwnedItem>>, OwnedItem>;
+
 /// The type of an item returned by [`PooledIter`].
 ///
-/// The owned item buffer is returned to [`PooledIter`] when the `PoolItem` is dropped.
+/// The owned item buffer is returned to [`PooledIter`] when the `PoolItem` is dropped. Items
+/// lent while recycling is disabled (see [`PooledIter::set_recycling_enabled`]) are not returned
+/// to the pool.
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
-pub struct PoolItem<OwnedItem>(
-    PooledResource<BoundedPool<OwnedItem, ResetNothing>, OwnedItem>,
-);
+pub struct PoolItem<OwnedItem>(PoolItemBuffer<OwnedItem>);
+
+/// The buffer of a [`PoolItem`], which is detached from the pool if it was lent while recycling
+/// was disabled with [`PooledIter::set_recycling_enabled`].
+#[derive(Debug)]
+enum PoolItemBuffer<OwnedItem> {
+    Unique(Buffer<OwnedItem>),
+    Detached(OwnedItem),
+}
 
 impl<OwnedItem> Deref for PoolItem<OwnedItem> {
     type Target = OwnedItem;
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        &self.0
+        match &self.0 {
+            PoolItemBuffer::Unique(buffer) => buffer,
+            PoolItemBuffer::Detached(item) => item,
+        }
     }
 }
 
 impl<OwnedItem> DerefMut for PoolItem<OwnedItem> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        match &mut self.0 {
+            PoolItemBuffer::Unique(buffer) => buffer,
+            PoolItemBuffer::Detached(item) => item,
+        }
     }
 }
 
@@ -255,14 +685,54 @@ impl<OwnedItem> AsMut<OwnedItem> for PoolItem<OwnedItem> {
     }
 }
 
+/// The type of an item returned by [`CachingPooledIter`].
+///
+/// Several `SharedPoolItem`s may share one buffer, so a `SharedPoolItem` only gives immutable
+/// access to its item. The buffer is returned to the pool once every `SharedPoolItem` sharing it
+/// is dropped.
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct SharedPoolItem<OwnedItem>(Rc<PoolItem<OwnedItem>>);
+
+impl<OwnedItem> Deref for SharedPoolItem<OwnedItem> {
+    type Target = OwnedItem;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<OwnedItem> Borrow<OwnedItem> for SharedPoolItem<OwnedItem> {
+    #[inline]
+    fn borrow(&self) -> &OwnedItem {
+        self
+    }
+}
+
+impl<OwnedItem> AsRef<OwnedItem> for SharedPoolItem<OwnedItem> {
+    #[inline]
+    fn as_ref(&self) -> &OwnedItem {
+        self
+    }
+}
+
 
 #[cfg(test)]
+#[expect(clippy::assertions_on_result_states, reason = "only whether the call succeeds is checked")]
+#[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+#[expect(clippy::shadow_unrelated, reason = "each check in the test starts from fresh bindings")]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
 mod tests {
-    use crate::test_iter::TestIter;
+    use alloc::vec;
+
+    use crate::{cursor::BoxedPooledIter, key_caching_iter::KeyCachingIter, test_iter::TestIter};
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
     use super::*;
 
 
     #[test]
+    #[expect(clippy::nonminimal_bool, reason = "the assertion states the property being tested")]
     fn pooled_test_iter() {
         let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
         let mut iter = PooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 2);
@@ -353,4 +823,215 @@ mod tests {
         iter.seek_before(&4);
         assert_eq!(*iter.current().unwrap(), 3);
     }
+
+    #[test]
+    fn for_each_single_buffer() {
+        let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
+        let mut iter = PooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 1);
+
+        let mut collected = Vec::new();
+        iter.for_each(|item| collected.push(**item));
+        assert_eq!(collected, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        assert!(!iter.valid());
+        assert_eq!(iter.available_buffers(), 1);
+    }
+
+    #[test]
+    fn boxed_pooled_iters() {
+        let data_one: &[u8] = [0, 1, 2].as_slice();
+        let data_two: &[u8] = [5, 6].as_slice();
+
+        let plain = PooledIter::<_, u8>::new(TestIter::new(data_one).unwrap(), 1);
+        let caching = PooledIter::<_, u8>::new(
+            KeyCachingIter::<_, u8>::new(TestIter::new(data_two).unwrap()),
+            1,
+        );
+
+        let mut iters: Vec<BoxedPooledIter<'_, PoolItem<u8>>> = Vec::new();
+        iters.push(plain.into_boxed());
+        iters.push(caching.into_boxed());
+
+        let mut collected = Vec::new();
+        for iter in &mut iters {
+            while let Some(item) = iter.next() {
+                collected.push(*item);
+            }
+            assert!(!iter.valid());
+            assert_eq!(iter.prev().as_deref(), collected.last());
+        }
+        assert_eq!(collected, [0, 1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn peak_buffers_used() {
+        let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
+        let mut iter = PooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 4);
+        assert_eq!(iter.peak_buffers_used(), 0);
+
+        // Items dropped immediately only ever use one buffer.
+        for _ in 0..5 {
+            drop(iter.next());
+        }
+        assert_eq!(iter.peak_buffers_used(), 1);
+
+        let held = [iter.next(), iter.current(), iter.prev()];
+        assert_eq!(iter.peak_buffers_used(), 3);
+        drop(held);
+
+        // The peak is a maximum, so it is not lowered once the buffers are returned.
+        drop(iter.try_next());
+        assert_eq!(iter.peak_buffers_used(), 3);
+
+        let held = [iter.next(), iter.next(), iter.next(), iter.next()];
+        assert!(iter.try_current().is_err());
+        assert_eq!(iter.peak_buffers_used(), 4);
+        drop(held);
+    }
+
+    #[test]
+    fn preallocated_buffers() {
+        let data = [vec![1_u8; 3], vec![2; 8], vec![3; 16]];
+        let mut iter = PooledIter::<_, Vec<u8>>::new_with_capacity(
+            SortedSliceCursor::new(data.as_slice(), OrdComparator),
+            1,
+            16,
+        );
+
+        let first = iter.next().unwrap();
+        assert_eq!(*first, [1, 1, 1]);
+        assert_eq!(first.capacity(), 16);
+        let buffer_ptr = first.as_ptr();
+        drop(first);
+
+        // The single buffer is reused for each item, without reallocating.
+        for expected in &data[1..] {
+            let item = iter.next().unwrap();
+            assert_eq!(*item, *expected);
+            assert_eq!(item.capacity(), 16);
+            assert_eq!(item.as_ptr(), buffer_ptr);
+        }
+
+        let mut made = 0;
+        let factory_iter = PooledIter::<_, u8>::new_with_factory(
+            TestIter::new(&[]).unwrap(),
+            3,
+            || {
+                made += 1;
+                0
+            },
+        );
+        assert_eq!(factory_iter.buffer_pool_size(), 3);
+        assert_eq!(made, 3);
+    }
+
+    #[test]
+    fn on_return_counts_returns() {
+        let data: &[u8] = [0, 1, 2, 3, 4].as_slice();
+        let returned = Rc::new(Cell::new(0_usize));
+        let returned_in_callback = Rc::clone(&returned);
+        let mut iter = PooledIter::<_, u8>::new_with_on_return(
+            TestIter::new(data).unwrap(),
+            2,
+            move |buffer: &mut u8| {
+                *buffer = 0;
+                returned_in_callback.set(returned_in_callback.get() + 1);
+            },
+        );
+
+        let first = iter.next().unwrap();
+        let second = iter.next().unwrap();
+        assert_eq!(returned.get(), 0);
+
+        drop(first);
+        assert_eq!(returned.get(), 1);
+        drop(second);
+        assert_eq!(returned.get(), 2);
+
+        for _ in 0..3 {
+            drop(iter.next());
+        }
+        assert_eq!(returned.get(), 5);
+
+        // A buffer taken by a `try_*` method which does not lend an item is returned as well.
+        iter.seek_to_last();
+        assert!(iter.try_next().unwrap().is_none());
+        assert_eq!(returned.get(), 6);
+    }
+
+    #[test]
+    fn caching_current_shares_buffer() {
+        let data: &[u8] = [0, 1, 2, 3].as_slice();
+        let mut iter = PooledIter::<_, u8>::new_caching_current(TestIter::new(data).unwrap(), 1);
+
+        let first = iter.next().unwrap();
+        assert_eq!(iter.available_buffers(), 0);
+        // The only buffer is in use, but `current` shares it.
+        let current = iter.current().unwrap();
+        assert_eq!((*first, *current), (0, 0));
+        assert_eq!(*iter.try_current().unwrap().unwrap(), 0);
+        assert_eq!(iter.available_buffers(), 0);
+        drop((first, current));
+        assert_eq!(iter.available_buffers(), 1);
+
+        // Once every item at this position is dropped, `current` fills a new buffer.
+        let refilled = iter.current().unwrap();
+        assert_eq!(*iter.current().unwrap(), 0);
+        drop(refilled);
+
+        // As it does after a seek, which does not fill a buffer.
+        iter.seek(&2);
+        let current = iter.current().unwrap();
+        assert_eq!(*current, 2);
+        assert_eq!(*iter.current().unwrap(), 2);
+        drop(current);
+
+        // Items lent while recycling is disabled are not shared between calls.
+        iter.set_recycling_enabled(false);
+        let detached = iter.current().unwrap();
+        assert_eq!(*detached, 2);
+        assert!(!Rc::ptr_eq(&detached.0, &iter.current().unwrap().0));
+        drop(detached);
+
+        // By default, `current` fills a separate buffer.
+        let mut iter = PooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 1);
+        let first = iter.next().unwrap();
+        assert!(iter.try_current().is_err());
+        drop(first);
+    }
+
+    #[test]
+    fn disabled_recycling_bypasses_pool() {
+        let data: &[u8] = [0, 1, 2, 3, 4].as_slice();
+        let mut iter = PooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 1);
+        assert!(iter.recycling_enabled());
+
+        let pooled = iter.next().unwrap();
+        assert_eq!(iter.available_buffers(), 0);
+        assert!(iter.try_next().is_err());
+
+        // Without recycling, items do not take buffers from the pool, so more items than buffers
+        // can be held at once.
+        iter.set_recycling_enabled(false);
+        assert!(!iter.recycling_enabled());
+        let held = [iter.next(), iter.current(), iter.try_next().unwrap(), iter.prev()];
+        let held_items = held.each_ref().map(|item| item.as_deref().copied());
+        assert_eq!(held_items, [Some(1), Some(1), Some(2), Some(1)]);
+        assert_eq!(iter.available_buffers(), 0);
+        assert_eq!(iter.peak_buffers_used(), 1);
+
+        // Items lent before disabling recycling are still returned to the pool, while the others
+        // are not.
+        drop(pooled);
+        assert_eq!(iter.available_buffers(), 1);
+        drop(held);
+        assert_eq!(iter.available_buffers(), 1);
+
+        // Re-enabling recycling resumes pooling.
+        iter.set_recycling_enabled(true);
+        let pooled = iter.next().unwrap();
+        assert_eq!(*pooled, 2);
+        assert_eq!(iter.available_buffers(), 0);
+        assert!(iter.try_current().is_err());
+    }
 }
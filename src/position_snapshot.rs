@@ -0,0 +1,172 @@
+use core::borrow::Borrow as _;
+use alloc::borrow::ToOwned;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{comparator::Comparator, seekable::ItemToKey};
+use crate::seekable_iterators::SeekableLendingIterator;
+
+
+/// A snapshot of the position of a seekable iterator, which can be serialized with [`serde`]
+/// in order to persist the position across program runs.
+///
+/// The snapshot consists of an owned copy of the current key, or nothing if the iterator was
+/// not valid. See [`SavePosition`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub struct PositionSnapshot<OwnedKey> {
+    key: Option<OwnedKey>,
+}
+
+impl<OwnedKey> PositionSnapshot<OwnedKey> {
+    /// Create a snapshot of a position at `key`, or of a `!valid()` position if `key` is `None`.
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Option<OwnedKey>) -> Self {
+        Self { key }
+    }
+
+    /// Get the key of the position, if the snapshot was taken while the iterator was valid.
+    #[inline]
+    #[must_use]
+    pub const fn key(&self) -> Option<&OwnedKey> {
+        self.key.as_ref()
+    }
+
+    /// Convert the snapshot into the key of the position, if the snapshot was taken while the
+    /// iterator was valid.
+    #[inline]
+    #[must_use]
+    pub fn into_key(self) -> Option<OwnedKey> {
+        self.key
+    }
+}
+
+/// Save and restore the positions of [`SeekableLendingIterator`]s whose items can be converted
+/// into keys, as [`PositionSnapshot`]s.
+///
+/// Since only the current key is saved, restoring a position seeks to that key. If the current
+/// entry's key is not unique, restoring a position thus moves to the first entry with that key.
+/// If the collection has changed in the meantime, the restored position is the first entry
+/// whose key is at least the saved key.
+///
+/// For adapters like [`MergingIter`], restoring a position re-seeks all of the sources.
+///
+/// All implementations are automatically provided by a blanket impl.
+///
+/// [`MergingIter`]: crate::MergingIter
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub trait SavePosition<Key, Cmp>: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>
+where
+    Key: ?Sized + ToOwned,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    /// Take a snapshot of the current position, which holds an owned copy of the current key.
+    #[must_use]
+    fn save_position(&self) -> PositionSnapshot<Key::Owned> {
+        PositionSnapshot::new(self.current().map(|item| Self::item_to_key(item).to_owned()))
+    }
+
+    /// Restore a position saved by [`save_position`].
+    ///
+    /// If the snapshot holds a key, the iterator seeks to that key. Otherwise, the iterator is
+    /// reset, and becomes `!valid()`.
+    ///
+    /// [`save_position`]: SavePosition::save_position
+    fn load_position(&mut self, snapshot: PositionSnapshot<Key::Owned>) {
+        if let Some(key) = snapshot.key() {
+            self.seek(key.borrow());
+        } else {
+            self.reset();
+        }
+    }
+}
+
+impl<Key, Cmp, I> SavePosition<Key, Cmp> for I
+where
+    Key: ?Sized + ToOwned,
+    Cmp: ?Sized + Comparator<Key>,
+    I:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use alloc::vec;
+
+    use serde::de::{Deserializer, IntoDeserializer, Visitor};
+    use serde::de::value::{Error as ValueError, MapDeserializer};
+
+    use crate::{comparator::OrdComparator, merging_iter::MergingIter, test_iter::TestIter};
+    use crate::{cursor::CursorLendingIterator as _, seekable::Seekable as _};
+    use super::*;
+
+
+    /// A deserializer for `Some(key)`, which `serde::de::value` does not provide.
+    struct SomeKey(u8);
+
+    impl<'de> Deserializer<'de> for SomeKey {
+        type Error = ValueError;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+            visitor.visit_some(self.0.into_deserializer())
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum
+            identifier ignored_any
+        }
+    }
+
+    impl IntoDeserializer<'_, ValueError> for SomeKey {
+        type Deserializer = Self;
+
+        fn into_deserializer(self) -> Self {
+            self
+        }
+    }
+
+    fn merge() -> MergingIter<u8, OrdComparator, TestIter<'static>> {
+        MergingIter::new(
+            vec![
+                TestIter::new(&[1, 4, 7]).unwrap(),
+                TestIter::new(&[2, 4, 9]).unwrap(),
+            ],
+            OrdComparator,
+        )
+    }
+
+    #[test]
+    fn restore_serialized_position() {
+        let mut iter = merge();
+        iter.seek(&3);
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&7));
+
+        // A snapshot persisted as a map from field names to values.
+        let fields = MapDeserializer::<_, ValueError>::new([("key", SomeKey(7))].into_iter());
+        let snapshot = PositionSnapshot::<u8>::deserialize(fields).unwrap();
+        assert_eq!(snapshot, iter.save_position());
+
+        let mut fresh = merge();
+        fresh.load_position(snapshot);
+        assert_eq!(fresh.current(), Some(&7));
+        assert_eq!(fresh.next(), Some(&9));
+    }
+
+    #[test]
+    fn restore_invalid_position() {
+        let mut iter = merge();
+        let snapshot = iter.save_position();
+        assert_eq!(snapshot.key(), None);
+
+        let fields = MapDeserializer::<_, ValueError>::new([("key", ())].into_iter());
+        assert_eq!(PositionSnapshot::<u8>::deserialize(fields).unwrap(), snapshot);
+
+        iter.seek(&4);
+        iter.load_position(snapshot);
+        assert!(!iter.valid());
+    }
+}
@@ -0,0 +1,422 @@
+use core::{cmp::Ordering, marker::PhantomData};
+use alloc::vec::Vec;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator, merging_iter::MergingIter};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// A [`ProjectedMergingIter`] merges several sources whose keys are projected into a common
+/// logical key type.
+///
+/// It iterates over the sorted union of the sources' entries, in the order given by a single
+/// [`Comparator`] over the logical keys.
+///
+/// Each source is paired with a projection `fn(&SourceKey) -> LogicalKey`. Different sources may
+/// use different projections; for instance, each source might be physically sorted by a
+/// different encoding of the same logical key.
+///
+/// The items lent by a `ProjectedMergingIter` are pairs of the projected logical key and the
+/// source's item.
+///
+/// # Projection requirements
+/// Every source must be physically sorted in the same order that the provided comparator imposes
+/// on the projected logical keys. That is, if `a` comes before `b` in some source, then the
+/// projection of `a` must not compare greater than the projection of `b`. If this requirement is
+/// violated, the merge may yield entries out of order, and seeking may position the iterator
+/// incorrectly.
+///
+/// # Performance
+/// The current logical key of each source is cached after every motion, so each projection is
+/// called once per entry visited. Since the sources cannot be sought by logical key, `seek` and
+/// `seek_before` walk through each source one entry at a time (though they make use of the
+/// sources' current positions when possible).
+///
+/// Otherwise, the behavior of a `ProjectedMergingIter` matches that of a [`MergingIter`],
+/// including the caveats for duplicate keys and for switching direction.
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct ProjectedMergingIter<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+{
+    merging: MergingIter<
+        LogicalKey,
+        Cmp,
+        ProjectedSource<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>,
+    >,
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+    ProjectedMergingIter<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized + Comparator<SourceKey>,
+    Cmp:       Comparator<LogicalKey> + Clone,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey> + Seekable<SourceKey, SourceCmp>,
+{
+    /// Create a new [`ProjectedMergingIter`] from sources paired with their projections.
+    ///
+    /// See the type-level documentation for the requirements on the projections.
+    ///
+    /// # Panics
+    /// Panics if the length of `sources` is `usize::MAX`.
+    #[expect(clippy::type_complexity, reason = "it's just a list of pairs")]
+    #[must_use]
+    pub fn new(sources: Vec<(Iter, fn(&SourceKey) -> LogicalKey)>, cmp: Cmp) -> Self {
+        let sources = sources
+            .into_iter()
+            .map(|(iter, projection)| ProjectedSource::new(iter, projection, cmp.clone()))
+            .collect();
+
+        Self {
+            merging: MergingIter::new(sources, cmp),
+        }
+    }
+}
+
+impl<'lend, SourceKey, SourceCmp, LogicalKey, Cmp, Iter> LendItem<'lend>
+for ProjectedMergingIter<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+    Iter:      LendItem<'lend>,
+{
+    type Item = (&'lend LogicalKey, Iter::Item);
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter> CursorLendingIterator
+for ProjectedMergingIter<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized + Comparator<SourceKey>,
+    Cmp:       Comparator<LogicalKey>,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey> + Seekable<SourceKey, SourceCmp>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.merging.valid()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.merging.next()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.merging.current()
+    }
+
+    /// Move the iterator one position back, and return the entry at that position.
+    /// Returns `None` if the iterator was at the first entry.
+    ///
+    /// See [`MergingIter::prev`] for caveats about backwards iteration.
+    #[inline]
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.merging.prev()
+    }
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter> ItemToKey<LogicalKey>
+for ProjectedMergingIter<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+    Iter:      CursorLendingIterator,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ LogicalKey {
+        item.0
+    }
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter> Seekable<LogicalKey, Cmp>
+for ProjectedMergingIter<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized + Comparator<SourceKey>,
+    Cmp:       Comparator<LogicalKey>,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey> + Seekable<SourceKey, SourceCmp>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.merging.reset();
+    }
+
+    /// Move the iterator to the smallest logical key which is greater or equal than the provided
+    /// `min_bound`.
+    ///
+    /// Each source is walked one entry at a time, starting from its current position if that
+    /// position is strictly before `min_bound`, and from its first entry otherwise.
+    #[inline]
+    fn seek(&mut self, min_bound: &LogicalKey) {
+        self.merging.seek(min_bound);
+    }
+
+    /// Move the iterator to the greatest logical key which is strictly less than the provided
+    /// `strict_upper_bound`.
+    ///
+    /// Each source is walked one entry at a time, starting from its current position if that
+    /// position is at or after `strict_upper_bound`, and from its last entry otherwise.
+    #[inline]
+    fn seek_before(&mut self, strict_upper_bound: &LogicalKey) {
+        self.merging.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.merging.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.merging.seek_to_last();
+    }
+}
+
+/// A source of a [`ProjectedMergingIter`], which caches the logical key of its current entry.
+#[derive(Debug)]
+struct ProjectedSource<SourceKey: ?Sized, SourceCmp: ?Sized, LogicalKey, Cmp, Iter> {
+    iter:        Iter,
+    projection:  fn(&SourceKey) -> LogicalKey,
+    cmp:         Cmp,
+    /// Should be `Some` if and only if `iter` is `valid()`, in which case it holds the projection
+    /// of `iter`'s current key.
+    current_key: Option<LogicalKey>,
+    _source_cmp: PhantomData<SourceCmp>,
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+    ProjectedSource<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey>,
+{
+    #[must_use]
+    fn new(iter: Iter, projection: fn(&SourceKey) -> LogicalKey, cmp: Cmp) -> Self {
+        let mut this = Self {
+            iter,
+            projection,
+            cmp,
+            current_key: None,
+            _source_cmp: PhantomData,
+        };
+        this.refresh_key();
+        this
+    }
+
+    /// Recompute `self.current_key` after `self.iter` has moved.
+    fn refresh_key(&mut self) {
+        self.current_key = self.iter
+            .current()
+            .map(|item| (self.projection)(Iter::item_to_key(item)));
+    }
+}
+
+impl<'lend, SourceKey, SourceCmp, LogicalKey, Cmp, Iter> LendItem<'lend>
+for ProjectedSource<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+    Iter:      LendItem<'lend>,
+{
+    type Item = (&'lend LogicalKey, Iter::Item);
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter> CursorLendingIterator
+for ProjectedSource<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.current_key.is_some()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next();
+        self.refresh_key();
+        self.current()
+    }
+
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        let key = self.current_key.as_ref()?;
+        Some((key, self.iter.current()?))
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev();
+        self.refresh_key();
+        self.current()
+    }
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter> ItemToKey<LogicalKey>
+for ProjectedSource<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey>,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ LogicalKey {
+        item.0
+    }
+}
+
+impl<SourceKey, SourceCmp, LogicalKey, Cmp, Iter> Seekable<LogicalKey, Cmp>
+for ProjectedSource<SourceKey, SourceCmp, LogicalKey, Cmp, Iter>
+where
+    SourceKey: ?Sized,
+    SourceCmp: ?Sized + Comparator<SourceKey>,
+    Cmp:       Comparator<LogicalKey>,
+    Iter:      CursorLendingIterator + ItemToKey<SourceKey> + Seekable<SourceKey, SourceCmp>,
+{
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.current_key = None;
+    }
+
+    fn seek(&mut self, min_bound: &LogicalKey) {
+        let is_before_bound = |this: &Self| {
+            this.current_key
+                .as_ref()
+                .is_some_and(|key| this.cmp.cmp(key, min_bound) == Ordering::Less)
+        };
+
+        // If we're already strictly before the bound, we can walk forwards from here.
+        // Otherwise, the target might be behind us, so start over.
+        if !is_before_bound(self) {
+            self.seek_to_first();
+        }
+
+        while is_before_bound(self) {
+            self.next();
+        }
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &LogicalKey) {
+        let is_at_or_after_bound = |this: &Self| {
+            this.current_key
+                .as_ref()
+                .is_some_and(|key| this.cmp.cmp(key, strict_upper_bound) != Ordering::Less)
+        };
+
+        // If we're already at or after the bound, we can walk backwards from here.
+        // Otherwise, the target might be in front of us, so start over from the end.
+        if !is_at_or_after_bound(self) {
+            self.seek_to_last();
+        }
+
+        while is_at_or_after_bound(self) {
+            self.prev();
+        }
+    }
+
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.refresh_key();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+        self.refresh_key();
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use alloc::vec;
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    const TIMES_TEN: fn(&u8) -> u16 = |key| u16::from(*key) * 10;
+    const TIMES_FIVE: fn(&u8) -> u16 = |key| u16::from(*key) * 5;
+
+    #[test]
+    fn two_projected_sources() {
+        // Logical keys: [0, 20, 40, 60] and [5, 15, 25, 35, 45]
+        let data_one: &[u8] = [0, 2, 4, 6].as_slice();
+        let data_two: &[u8] = [1, 3, 5, 7, 9].as_slice();
+        let merged:   &[u16] = [0, 5, 15, 20, 25, 35, 40, 45, 60].as_slice();
+
+        let mut iter = ProjectedMergingIter::new(
+            vec![
+                (TestIter::new(data_one).unwrap(), TIMES_TEN),
+                (TestIter::new(data_two).unwrap(), TIMES_FIVE),
+            ],
+            OrdComparator,
+        );
+
+        for expected in merged {
+            assert_eq!(iter.next().unwrap().0, expected);
+        }
+        assert!(iter.next().is_none());
+
+        for expected in merged.iter().rev() {
+            assert_eq!(iter.prev().unwrap().0, expected);
+        }
+        assert!(iter.prev().is_none());
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn seek_projected_sources() {
+        let data_one: &[u8] = [0, 2, 4, 6].as_slice();
+        let data_two: &[u8] = [1, 3, 5, 7, 9].as_slice();
+
+        let mut iter = ProjectedMergingIter::new(
+            vec![
+                (TestIter::new(data_one).unwrap(), TIMES_TEN),
+                (TestIter::new(data_two).unwrap(), TIMES_FIVE),
+            ],
+            OrdComparator,
+        );
+
+        iter.seek(&21);
+        let (key, item) = iter.current().unwrap();
+        assert_eq!((*key, *item), (25, 5));
+
+        iter.seek(&6);
+        assert_eq!(*iter.current().unwrap().0, 15);
+
+        iter.seek(&40);
+        assert_eq!(*iter.current().unwrap().0, 40);
+        assert_eq!(*iter.next().unwrap().0, 45);
+
+        iter.seek_before(&40);
+        let (key, item) = iter.current().unwrap();
+        assert_eq!((*key, *item), (35, 7));
+
+        iter.seek_before(&60);
+        assert_eq!(*iter.current().unwrap().0, 45);
+
+        iter.seek_before(&1);
+        assert_eq!(*iter.current().unwrap().0, 0);
+
+        iter.seek_before(&0);
+        assert!(!iter.valid());
+
+        iter.seek(&61);
+        assert!(!iter.valid());
+
+        iter.seek_to_last();
+        assert_eq!(*iter.current().unwrap().0, 60);
+
+        iter.seek_to_first();
+        assert_eq!(*iter.current().unwrap().0, 0);
+    }
+}
@@ -0,0 +1,216 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, lending_iterator_support::LentItem};
+use crate::{seekable::ItemToKey, seekable_iterators::SeekableLendingIterator};
+
+
+/// Executes a sorted sequence of non-overlapping range scans over a parent iterator, in a single
+/// forward pass.
+///
+/// Each call to [`next_range`] hands out a [`RangeScan`] over the entries whose keys are in the
+/// next range, `lower..upper`. The parent iterator is sought only once, to the lower bound of the
+/// first range; afterwards, it is only ever moved forwards, one entry at a time. If a
+/// [`RangeScan`] is dropped before it is exhausted, the entries remaining in its range are
+/// skipped over by the next range's scan.
+///
+/// The ranges must be sorted and must not overlap; that is, each range's `upper` must be at most
+/// the next range's `lower`. Otherwise, entries may be skipped.
+///
+/// Created by [`SeekableLendingIteratorExt::scan_ranges`].
+///
+/// [`next_range`]: RangeScans::next_range
+/// [`SeekableLendingIteratorExt::scan_ranges`]: crate::SeekableLendingIteratorExt::scan_ranges
+#[derive(Debug)]
+pub struct RangeScans<'a, I: ?Sized, Key, Cmp: ?Sized> {
+    iter:    &'a mut I,
+    cmp:     &'a Cmp,
+    ranges:  &'a [(Key, Key)],
+    /// Whether `iter` has been sought to the lower bound of the first range.
+    started: bool,
+}
+
+impl<'a, I, Key, Cmp> RangeScans<'a, I, Key, Cmp>
+where
+    I:   ?Sized + SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    /// Create a scan over each of the `ranges`, which must be sorted and non-overlapping.
+    ///
+    /// The parent iterator is not moved until the first call to [`next_range`].
+    ///
+    /// [`next_range`]: RangeScans::next_range
+    #[inline]
+    #[must_use]
+    pub(crate) const fn new(iter: &'a mut I, cmp: &'a Cmp, ranges: &'a [(Key, Key)]) -> Self {
+        Self {
+            iter,
+            cmp,
+            ranges,
+            started: false,
+        }
+    }
+
+    /// Get the ranges which have not yet been handed out by [`next_range`].
+    ///
+    /// [`next_range`]: RangeScans::next_range
+    #[inline]
+    #[must_use]
+    pub const fn remaining_ranges(&self) -> &'a [(Key, Key)] {
+        self.ranges
+    }
+
+    /// Get a scan over the next range, or `None` if every range has been handed out.
+    pub fn next_range(&mut self) -> Option<RangeScan<'_, I, Key, Cmp>> {
+        let ((lower, upper), rest) = self.ranges.split_first()?;
+        self.ranges = rest;
+
+        if !self.started {
+            self.started = true;
+            self.iter.seek(lower);
+        }
+
+        Some(RangeScan {
+            iter:    self.iter,
+            cmp:     self.cmp,
+            lower,
+            upper,
+            started: false,
+            done:    false,
+        })
+    }
+}
+
+/// A forward-only scan over the entries of a parent iterator whose keys are in the range
+/// `lower..upper`, borrowing the parent iterator.
+///
+/// Handed out by [`RangeScans::next_range`].
+#[derive(Debug)]
+pub struct RangeScan<'a, I: ?Sized, Key, Cmp: ?Sized> {
+    iter:    &'a mut I,
+    cmp:     &'a Cmp,
+    lower:   &'a Key,
+    upper:   &'a Key,
+    /// Whether `next` has been called at least once.
+    started: bool,
+    /// Whether the scan has moved past its range (or the parent has reached its end).
+    done:    bool,
+}
+
+impl<'a, I, Key, Cmp> RangeScan<'a, I, Key, Cmp>
+where
+    I:   ?Sized + SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    /// Get the lower bound (inclusive) of the range.
+    #[inline]
+    #[must_use]
+    pub const fn lower(&self) -> &'a Key {
+        self.lower
+    }
+
+    /// Get the upper bound (exclusive) of the range.
+    #[inline]
+    #[must_use]
+    pub const fn upper(&self) -> &'a Key {
+        self.upper
+    }
+
+    /// Move forwards to the next entry in the range, and return it.
+    ///
+    /// Once the scan has moved past the end of its range, `None` is returned, and the parent
+    /// iterator is no longer moved; the parent is then left at the first entry whose key is at or
+    /// after `upper`, if any.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, I>> {
+        if self.done {
+            return None;
+        }
+
+        if self.started {
+            self.iter.next();
+        } else {
+            self.started = true;
+            // Skip whatever was left over from the previous range. Note that a `!valid()`
+            // parent has reached its end, and must not be moved, since it would wrap around.
+            while self.iter.current().is_some_and(|item| {
+                self.cmp.cmp(I::item_to_key(item), self.lower) == Ordering::Less
+            }) {
+                self.iter.next();
+            }
+        }
+
+        let in_range = self.iter.current().is_some_and(|item| {
+            self.cmp.cmp(I::item_to_key(item), self.upper) == Ordering::Less
+        });
+
+        if in_range {
+            self.iter.current()
+        } else {
+            self.done = true;
+            None
+        }
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, seekable_ext::SeekableLendingIteratorExt as _};
+    use crate::test_iter::TestIter;
+    #[cfg(feature = "alloc")]
+    use crate::{cursor::CursorLendingIterator as _, merging_iter::MergingIter};
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn adjacent_ranges_over_merge() {
+        let mut iter = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 3, 5, 7, 9]).unwrap(),
+                TestIter::new(&[2, 3, 6, 8]).unwrap(),
+            ],
+            OrdComparator,
+        );
+        let ranges = [(2, 4), (4, 7), (7, 10)];
+        let mut scans = iter.scan_ranges(&OrdComparator, &ranges);
+
+        let expected: [&[u8]; 3] = [&[2, 3, 3], &[5, 6], &[7, 8, 9]];
+        for expected_range in expected {
+            let mut scan = scans.next_range().unwrap();
+            for expected_key in expected_range {
+                assert_eq!(scan.next(), Some(expected_key));
+            }
+            assert_eq!(scan.next(), None);
+            assert_eq!(scan.next(), None);
+        }
+        assert!(scans.next_range().is_none());
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn partially_consumed_and_gapped_ranges() {
+        let data: &[u8] = [1, 2, 3, 4, 5, 6, 7, 8].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+        let ranges = [(2, 5), (6, 7), (9, 12)];
+        let mut scans = iter.scan_ranges(&OrdComparator, &ranges);
+
+        let mut scan = scans.next_range().unwrap();
+        assert_eq!(scan.next(), Some(&2));
+
+        let mut scan = scans.next_range().unwrap();
+        assert_eq!((scan.lower(), scan.upper()), (&6, &7));
+        assert_eq!(scan.next(), Some(&6));
+        assert_eq!(scan.next(), None);
+
+        let mut scan = scans.next_range().unwrap();
+        assert_eq!(scan.next(), None);
+        assert!(scans.remaining_ranges().is_empty());
+    }
+}
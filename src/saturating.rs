@@ -0,0 +1,152 @@
+use core::{borrow::Borrow as _, cmp::Ordering};
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use alloc::borrow::ToOwned;
+
+use crate::comparator::Comparator;
+
+
+/// A [`Comparator`] adapter which treats every key greater than or equal to a saturation point,
+/// `max_key`, as equal to `max_key` (and thus to each other).
+///
+/// Keys less than `max_key` are compared with the inner comparator, and are less than every
+/// saturated key. This bounds the effective key domain, such as for merges of numeric ranges in
+/// which every key beyond some limit should be treated as maximal. An owned copy of `max_key` is
+/// kept.
+///
+/// Created by [`ComparatorExt::saturating_at`], or by [`Saturating::new`].
+///
+/// # Total order
+/// If the inner comparator provides a total order, then so does `Saturating`; however, its
+/// equivalence relation is coarser, since distinct saturated keys compare as equal. Adapters
+/// therefore treat saturated keys as duplicates: for instance, a [`MergingIter`] yields saturated
+/// keys from different sources in an unspecified order relative to each other (rather than in the
+/// order of the inner comparator), and seeking to any saturated key moves to the first saturated
+/// entry. A collection sorted by the inner comparator is also sorted by `Saturating`, but not
+/// necessarily vice versa.
+///
+/// [`ComparatorExt::saturating_at`]: crate::ComparatorExt::saturating_at
+/// [`MergingIter`]: crate::MergingIter
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct Saturating<C, Key: ?Sized + ToOwned> {
+    cmp:     C,
+    max_key: Key::Owned,
+}
+
+impl<C, Key: ?Sized + ToOwned> Saturating<C, Key> {
+    /// Wrap the provided comparator, saturating at `max_key`.
+    #[inline]
+    #[must_use]
+    pub const fn new(cmp: C, max_key: Key::Owned) -> Self {
+        Self { cmp, max_key }
+    }
+
+    /// Get the saturation point, at and above which all keys are equal.
+    #[inline]
+    #[must_use]
+    pub fn max_key(&self) -> &Key {
+        self.max_key.borrow()
+    }
+
+    /// Get a reference to the inner comparator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &C {
+        &self.cmp
+    }
+
+    /// Convert the adapter back into the inner comparator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> C {
+        self.cmp
+    }
+}
+
+impl<Key, C> Comparator<Key> for Saturating<C, Key>
+where
+    Key: ?Sized + ToOwned,
+    C:   Comparator<Key>,
+{
+    fn cmp(&self, lhs: &Key, rhs: &Key) -> Ordering {
+        let lhs_saturated = self.cmp.cmp(lhs, self.max_key()).is_ge();
+        let rhs_saturated = self.cmp.cmp(rhs, self.max_key()).is_ge();
+
+        match (lhs_saturated, rhs_saturated) {
+            (true, true)   => Ordering::Equal,
+            (true, false)  => Ordering::Greater,
+            (false, true)  => Ordering::Less,
+            (false, false) => self.cmp.cmp(lhs, rhs),
+        }
+    }
+}
+
+impl<C: Clone, Key: ?Sized + ToOwned> Clone for Saturating<C, Key> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            cmp:     self.cmp.clone(),
+            max_key: self.max_key().to_owned(),
+        }
+    }
+}
+
+impl<C: Debug, Key: ?Sized + ToOwned<Owned: Debug>> Debug for Saturating<C, Key> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Saturating")
+            .field("cmp", &self.cmp)
+            .field("max_key", &self.max_key)
+            .finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{
+        comparator::OrdComparator, comparator_ext::ComparatorExt,
+        cursor::CursorLendingIterator as _, merging_iter::MergingIter, seekable::Seekable as _,
+        sorted_slice_cursor::SortedSliceCursor,
+    };
+    use super::*;
+
+
+    #[test]
+    fn keys_above_max_are_equal() {
+        let cmp = ComparatorExt::<u32>::saturating_at(OrdComparator, 10);
+        assert_eq!(*cmp.max_key(), 10);
+
+        assert_eq!(cmp.cmp(&3, &7), Ordering::Less);
+        assert_eq!(cmp.cmp(&9, &10), Ordering::Less);
+        assert_eq!(cmp.cmp(&10, &25), Ordering::Equal);
+        assert_eq!(cmp.cmp(&40, &25), Ordering::Equal);
+        assert_eq!(cmp.cmp(&40, &9), Ordering::Greater);
+    }
+
+    #[test]
+    fn saturated_keys_collapse_in_merge() {
+        let cmp = Saturating::<_, u32>::new(OrdComparator, 10);
+        let first: &[u32] = [1, 12, 15].as_slice();
+        let second: &[u32] = [5, 10, 20].as_slice();
+        let mut merge = MergingIter::new(
+            vec![
+                SortedSliceCursor::new(first, cmp.clone()),
+                SortedSliceCursor::new(second, cmp.clone()),
+            ],
+            cmp,
+        );
+
+        // The saturated keys are all equal, so ties are broken by source rather than by value.
+        for expected in [1, 5, 12, 15, 10, 20] {
+            assert_eq!(merge.next(), Some(&expected));
+        }
+        assert_eq!(merge.next(), None);
+
+        // Seeking to any saturated key moves to the first saturated entry.
+        merge.seek(&100);
+        assert_eq!(merge.current(), Some(&12));
+        merge.seek_before(&11);
+        assert_eq!(merge.current(), Some(&5));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::Seekable};
+
+
+/// A handle to a [`CursorLendingIterator`] which can only seek and read the current entry.
+///
+/// The advancing methods `next` and `prev` are not exposed, which enforces point-lookup access
+/// at the type level. The wrapped iterator implements [`Seekable`] if the inner iterator does,
+/// and provides [`valid`] and [`current`] as inherent methods.
+///
+/// ```compile_fail
+/// use seekable_iterator::{CursorLendingIterator, SeekOnly};
+///
+/// fn advance<I: CursorLendingIterator>(handle: &mut SeekOnly<I>) {
+///     handle.next();
+/// }
+/// ```
+///
+/// [`valid`]: SeekOnly::valid
+/// [`current`]: SeekOnly::current
+#[derive(Debug, Clone)]
+pub struct SeekOnly<I>(I);
+
+impl<I> SeekOnly<I> {
+    /// Wrap the provided iterator, hiding its advancing methods.
+    #[inline]
+    #[must_use]
+    pub const fn new(iter: I) -> Self {
+        Self(iter)
+    }
+
+    /// Convert the handle back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I: CursorLendingIterator> SeekOnly<I> {
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        self.0.valid()
+    }
+
+    /// Get the current value the iterator is at, if the iterator is [valid].
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    ///
+    /// [valid]: SeekOnly::valid
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, I>> {
+        self.0.current()
+    }
+}
+
+impl<I, Key, Cmp> Seekable<Key, Cmp> for SeekOnly<I>
+where
+    I:   Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+
+    #[inline]
+    fn seek(&mut self, min_bound: &Key) {
+        self.0.seek(min_bound);
+    }
+
+    #[inline]
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.0.seek_before(strict_upper_bound);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.0.seek_to_first();
+    }
+
+    #[inline]
+    fn seek_to_last(&mut self) {
+        self.0.seek_to_last();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::test_iter::TestIter;
+    use super::*;
+
+
+    #[test]
+    #[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+    fn point_lookups() {
+        let data: &[u8] = [1, 3, 5, 7].as_slice();
+        let mut handle = SeekOnly::new(TestIter::new(data).unwrap());
+        assert!(!handle.valid());
+
+        handle.seek(&4);
+        assert_eq!(handle.current(), Some(&5));
+
+        handle.seek_before(&4);
+        assert_eq!(handle.current(), Some(&3));
+
+        handle.seek_to_last();
+        assert_eq!(handle.current(), Some(&7));
+
+        handle.seek(&8);
+        assert!(!handle.valid());
+        assert_eq!(handle.current(), None);
+
+        handle.seek_to_first();
+        let mut iter = handle.into_inner();
+        assert_eq!(iter.next(), Some(&3));
+    }
+}
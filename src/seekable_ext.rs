@@ -0,0 +1,802 @@
+use core::cmp::Ordering;
+#[cfg(feature = "alloc")]
+use core::borrow::Borrow as _;
+#[cfg(feature = "alloc")]
+use alloc::borrow::ToOwned;
+
+#[cfg(feature = "alloc")]
+use crate::comparator::keys_equal;
+use crate::{comparator::Comparator, cursor::CursorLendingIterator as _};
+use crate::{lending_iterator_support::LentItem, seekable::ItemToKey};
+use crate::{range_scans::RangeScans, seekable_iterators::SeekableLendingIterator};
+use crate::window_iter::WindowIter;
+
+
+/// The number of steps after which [`SeekableLendingIteratorExt::seek_counting`] and
+/// [`SeekableLendingIteratorExt::seek_before_counting`] stop walking.
+const SEEK_COUNTING_CAP: usize = 64;
+
+/// The outcome of [`SeekableLendingIteratorExt::seek_classified`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeekOutcome {
+    /// The iterator landed on an entry whose key compares equal to the sought key.
+    Exact,
+    /// The iterator landed on an entry whose key is strictly greater than the sought key.
+    Successor,
+    /// There was no entry whose key is greater than or equal to the sought key, so the iterator
+    /// is `!valid()`.
+    PastEnd,
+}
+
+/// Extra provided methods for [`SeekableLendingIterator`]s whose items can be converted into
+/// keys.
+///
+/// Since the seekable traits do not expose the comparator used by an iterator, methods which
+/// need to compare keys take a reference to a comparator. That comparator must behave
+/// identically to the comparator used by the iterator.
+///
+/// All implementations are automatically provided by a blanket impl.
+pub trait SeekableLendingIteratorExt<Key, Cmp>:
+    SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>
+where
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`, and return the number of single-step motions (calls to `next` or `prev`)
+    /// that were needed to move between the old and new positions.
+    ///
+    /// If the iterator was not valid before the seek, it is treated as being one position before
+    /// the first entry.
+    ///
+    /// The distance is measured by walking one entry at a time from the old position, which is
+    /// also how the seek is performed. So that a long jump does not cost a long walk, if the
+    /// walk reaches 64 steps without finding the target, the walk is abandoned in favor of a
+    /// normal [`seek`], and 64 is returned. The returned value is thus `min(distance, 64)`, which
+    /// suffices to tell short hops from long jumps.
+    ///
+    /// For sources implementing [`IndexedCursor`], prefer
+    /// [`IndexedCursor::seek_counting_indexed`], which is exact and does not walk.
+    ///
+    /// [`seek`]: crate::Seekable::seek
+    /// [`IndexedCursor`]: crate::IndexedCursor
+    /// [`IndexedCursor::seek_counting_indexed`]: crate::IndexedCursor::seek_counting_indexed
+    fn seek_counting(&mut self, cmp: &Cmp, min_bound: &Key) -> usize {
+        let at_or_after_bound = |this: &Self| {
+            this.current().is_some_and(|item| {
+                cmp.cmp(Self::item_to_key(item), min_bound) != Ordering::Less
+            })
+        };
+
+        let mut distance = 0;
+
+        if at_or_after_bound(self) {
+            // The target is at or behind the current position. Walk backwards until we're
+            // strictly before the bound, and then step forwards once.
+            loop {
+                self.prev();
+                if !at_or_after_bound(self) {
+                    break;
+                }
+                distance += 1;
+                if distance >= SEEK_COUNTING_CAP {
+                    self.seek(min_bound);
+                    return SEEK_COUNTING_CAP;
+                }
+            }
+            self.next();
+        } else {
+            // The target is in front of the current position.
+            loop {
+                if distance >= SEEK_COUNTING_CAP {
+                    self.seek(min_bound);
+                    return SEEK_COUNTING_CAP;
+                }
+                self.next();
+                distance += 1;
+                if !self.valid() || at_or_after_bound(self) {
+                    break;
+                }
+            }
+        }
+
+        distance
+    }
+
+    /// Move the iterator to the smallest key which is greater or equal than the provided `key`,
+    /// and report whether the iterator landed on that exact key, on a greater key, or past the
+    /// end of the collection.
+    #[must_use]
+    fn seek_classified(&mut self, cmp: &Cmp, key: &Key) -> SeekOutcome {
+        self.seek(key);
+
+        let Some(item) = self.current() else {
+            return SeekOutcome::PastEnd;
+        };
+
+        if cmp.cmp(Self::item_to_key(item), key) == Ordering::Equal {
+            SeekOutcome::Exact
+        } else {
+            SeekOutcome::Successor
+        }
+    }
+
+    /// Determine whether `successor` is the key immediately following `key` in the collection:
+    /// that is, whether both keys are present, `successor` is greater than `key`, and no key
+    /// lies strictly between them.
+    ///
+    /// This is a correctness probe, such as for checking in a test suite that an iteration
+    /// visited every entry with no gaps. The iterator is moved: it is sought to `key`, then
+    /// advanced past every entry whose key equals `key`, and is left at the entry after them (or
+    /// `!valid()`). If `key` is not present, `false` is returned without advancing.
+    #[must_use]
+    fn is_immediate_successor(&mut self, cmp: &Cmp, key: &Key, successor: &Key) -> bool {
+        if self.seek_classified(cmp, key) != SeekOutcome::Exact {
+            return false;
+        }
+
+        while self.next().is_some_and(|item| {
+            cmp.cmp(Self::item_to_key(item), key) == Ordering::Equal
+        }) {}
+
+        self.current().is_some_and(|item| {
+            cmp.cmp(Self::item_to_key(item), successor) == Ordering::Equal
+        })
+    }
+
+    /// Move the iterator to the greatest key which is strictly less than the provided
+    /// `strict_upper_bound`, and return the number of single-step motions (calls to `next` or
+    /// `prev`) that were needed to move between the old and new positions.
+    ///
+    /// If the iterator was not valid before the seek, it is treated as being one position after
+    /// the last entry.
+    ///
+    /// As with [`seek_counting`], the seek is performed by walking one entry at a time from the
+    /// old position. If the walk reaches 64 steps without finding the target, the walk is
+    /// abandoned in favor of a normal [`seek_before`], and 64 is returned.
+    ///
+    /// For sources implementing [`IndexedCursor`], prefer
+    /// [`IndexedCursor::seek_before_counting_indexed`], which is exact and does not walk.
+    ///
+    /// [`seek_counting`]: SeekableLendingIteratorExt::seek_counting
+    /// [`seek_before`]: crate::Seekable::seek_before
+    /// [`IndexedCursor`]: crate::IndexedCursor
+    /// [`IndexedCursor::seek_before_counting_indexed`]: crate::IndexedCursor::seek_before_counting_indexed
+    fn seek_before_counting(&mut self, cmp: &Cmp, strict_upper_bound: &Key) -> usize {
+        let before_bound = |this: &Self| {
+            this.current().is_some_and(|item| {
+                cmp.cmp(Self::item_to_key(item), strict_upper_bound) == Ordering::Less
+            })
+        };
+
+        let mut distance = 0;
+
+        if before_bound(self) {
+            // The target is at or in front of the current position. Walk forwards until we're
+            // at or after the bound, and then step backwards once.
+            loop {
+                self.next();
+                if !before_bound(self) {
+                    break;
+                }
+                distance += 1;
+                if distance >= SEEK_COUNTING_CAP {
+                    self.seek_before(strict_upper_bound);
+                    return SEEK_COUNTING_CAP;
+                }
+            }
+            self.prev();
+        } else {
+            // The target is behind the current position.
+            loop {
+                if distance >= SEEK_COUNTING_CAP {
+                    self.seek_before(strict_upper_bound);
+                    return SEEK_COUNTING_CAP;
+                }
+                self.prev();
+                distance += 1;
+                if !self.valid() || before_bound(self) {
+                    break;
+                }
+            }
+        }
+
+        distance
+    }
+
+    /// Seek to `lower`, and return a cursor over the entries whose keys are in `lower..upper`.
+    ///
+    /// The returned [`WindowIter`] borrows this iterator, and moves it as the window moves.
+    /// When the window is dropped, this iterator is left at whatever position the window
+    /// moved it to; after iterating forwards through the whole window, that is the first entry
+    /// whose key is at or after `upper`, if any. This makes it convenient to process adjacent
+    /// ranges one at a time.
+    #[must_use]
+    fn window<'a>(
+        &'a mut self,
+        cmp:   &'a Cmp,
+        lower: &'a Key,
+        upper: &'a Key,
+    ) -> WindowIter<'a, Self, Key, Cmp> {
+        WindowIter::new(self, cmp, lower, upper)
+    }
+
+    /// Seek to `prefix_lower`, and return a cursor over the entries whose keys are in
+    /// `prefix_lower..prefix_upper`.
+    ///
+    /// This is intended for composite keys, such as byte strings made of several components,
+    /// where the comparator orders keys by their first component before any later components.
+    /// To select every entry whose leading components equal some prefix, pass that prefix as
+    /// `prefix_lower`, and the smallest key which sorts after every key with that prefix as
+    /// `prefix_upper`. For instance, with lexicographically-ordered byte strings, the prefix
+    /// `[2]` selects `[2]..[3]`.
+    ///
+    /// The returned cursor behaves exactly like the one returned by [`window`], including the
+    /// position this iterator is left at once the cursor is dropped.
+    ///
+    /// [`window`]: SeekableLendingIteratorExt::window
+    #[must_use]
+    fn seek_component_range<'a>(
+        &'a mut self,
+        cmp:          &'a Cmp,
+        prefix_lower: &'a Key,
+        prefix_upper: &'a Key,
+    ) -> WindowIter<'a, Self, Key, Cmp> {
+        self.window(cmp, prefix_lower, prefix_upper)
+    }
+
+    /// Execute a sorted sequence of non-overlapping range scans in a single forward pass.
+    ///
+    /// Each `(lower, upper)` pair selects the entries whose keys are in `lower..upper`. The
+    /// returned [`RangeScans`] hands out one [`RangeScan`] per range; this iterator is sought only
+    /// to the first range's `lower` bound, and is otherwise only moved forwards. This is cheaper
+    /// than a separate [`window`] per range, which seeks to each range's `lower` bound.
+    ///
+    /// The ranges must be sorted, and each range's `upper` must be at most the next range's
+    /// `lower`.
+    ///
+    /// [`RangeScan`]: crate::RangeScan
+    /// [`window`]: SeekableLendingIteratorExt::window
+    #[must_use]
+    fn scan_ranges<'a>(
+        &'a mut self,
+        cmp:    &'a Cmp,
+        ranges: &'a [(Key, Key)],
+    ) -> RangeScans<'a, Self, Key, Cmp>
+    where
+        Key: Sized,
+    {
+        RangeScans::new(self, cmp, ranges)
+    }
+
+    /// Count the entries whose keys are in `lower..upper`, by scanning them.
+    ///
+    /// The iterator is sought to `lower`, and then moved forwards through the range; afterwards,
+    /// it is left at the first entry whose key is at or after `upper`, if any.
+    ///
+    /// Sources which can count entries without scanning them may implement
+    /// [`CountBetweenHint`]; see also [`MergingIter::count_between`].
+    ///
+    /// [`CountBetweenHint`]: crate::CountBetweenHint
+    /// [`MergingIter::count_between`]: crate::MergingIter::count_between
+    #[must_use]
+    fn count_between(&mut self, cmp: &Cmp, lower: &Key, upper: &Key) -> usize {
+        let mut window = self.window(cmp, lower, upper);
+        let mut count = 0;
+        while window.next().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    /// Determine whether the collection being iterated over has no entries.
+    ///
+    /// The position of the iterator is preserved. If the iterator is [valid], the collection is
+    /// known to be nonempty without moving the iterator. Otherwise, the iterator is moved to the
+    /// first entry to check whether one exists, and is then [`reset`] back to its prior
+    /// position; note that an iterator which is not valid is always at the position that
+    /// [`reset`] moves to.
+    ///
+    /// For a [`MergingIter`], the collection is empty if and only if every source iterator's
+    /// collection is empty.
+    ///
+    /// [valid]: crate::CursorLendingIterator::valid
+    /// [`reset`]: crate::Seekable::reset
+    /// [`MergingIter`]: crate::MergingIter
+    #[must_use]
+    fn is_collection_empty(&mut self) -> bool {
+        if self.valid() {
+            return false;
+        }
+
+        self.seek_to_first();
+        let is_empty = !self.valid();
+        self.reset();
+        is_empty
+    }
+
+    /// Find the index of the first entry for which `pred` returns `true`, and move the iterator
+    /// to that entry.
+    ///
+    /// The collection must be partitioned by `pred`: every entry for which `pred` returns `false`
+    /// must come before every entry for which it returns `true`. This mirrors
+    /// [`slice::partition_point`], except that `None` is returned (and the iterator becomes
+    /// `!valid()`) if `pred` is `false` for every entry.
+    ///
+    /// The search walks one entry at a time from the first entry. For sources implementing
+    /// [`IndexedCursor`], prefer [`IndexedCursor::partition_point_indexed`], which performs a
+    /// binary search.
+    ///
+    /// [`IndexedCursor`]: crate::IndexedCursor
+    /// [`IndexedCursor::partition_point_indexed`]: crate::IndexedCursor::partition_point_indexed
+    fn partition_point<P>(&mut self, mut pred: P) -> Option<usize>
+    where
+        P: FnMut(&LentItem<'_, Self>) -> bool,
+    {
+        self.seek_to_first();
+
+        let mut index = 0;
+        loop {
+            if self.current().map(|item| pred(&item))? {
+                return Some(index);
+            }
+            self.next();
+            index += 1;
+        }
+    }
+
+    /// Move the iterator to the last entry for which `pred` returns `true`, and return whether
+    /// such an entry was found.
+    ///
+    /// The collection must be partitioned by `pred`: every entry for which `pred` returns `true`
+    /// must come before every entry for which it returns `false`. This suits lookups such as
+    /// "the latest version at or before some timestamp". If `pred` is `false` for every entry,
+    /// `false` is returned and the iterator becomes `!valid()`.
+    ///
+    /// The search walks backwards one entry at a time from the last entry. For sources
+    /// implementing [`IndexedCursor`], prefer [`IndexedCursor::seek_last_matching_indexed`],
+    /// which performs a binary search.
+    ///
+    /// [`IndexedCursor`]: crate::IndexedCursor
+    /// [`IndexedCursor::seek_last_matching_indexed`]: crate::IndexedCursor::seek_last_matching_indexed
+    fn seek_last_matching<P>(&mut self, mut pred: P) -> bool
+    where
+        P: FnMut(&LentItem<'_, Self>) -> bool,
+    {
+        self.seek_to_last();
+
+        loop {
+            match self.current() {
+                Some(item) if pred(&item) => return true,
+                Some(_) => {}
+                None => return false,
+            }
+            self.prev();
+        }
+    }
+
+    /// If the iterator is inside a run of entries with equal keys, move backwards to the first
+    /// entry of that run.
+    ///
+    /// If the iterator is already at the first entry of its run (including when the current key
+    /// is unique), it stays put; if it is `!valid()`, nothing happens. Keys are compared with
+    /// `cmp`, and the iterator walks backwards one entry at a time, keeping an owned copy of the
+    /// current key.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn rewind_to_run_start(&mut self, cmp: &Cmp)
+    where
+        Key: ToOwned,
+    {
+        let Some(item) = self.current() else {
+            return;
+        };
+        let run_key = Self::item_to_key(item).to_owned();
+
+        while self.prev().is_some_and(|prev_item| {
+            keys_equal(cmp, run_key.borrow(), Self::item_to_key(prev_item))
+        }) {}
+
+        // We're now one entry before the run, or `!valid()` if the run starts the collection;
+        // either way, `next` moves to the first entry of the run.
+        self.next();
+    }
+
+    /// Compute a checksum of the sequence of keys in the collection, by iterating over every
+    /// entry from the first entry onwards.
+    ///
+    /// The iterator is drained: afterwards, it is `!valid()`. Two iterators whose keys form
+    /// equal sequences of byte strings produce equal checksums, regardless of their sources;
+    /// for instance, this can be used to check that two supposedly-identical [`MergingIter`]s
+    /// agree.
+    ///
+    /// The checksum is a 64-bit FNV-1a hash of each key's length (as 8 little-endian bytes)
+    /// followed by the key's bytes, so that splitting the same bytes into keys differently
+    /// changes the checksum. The checksum is stable across platforms and versions of this crate,
+    /// but is not cryptographically secure.
+    ///
+    /// [`MergingIter`]: crate::MergingIter
+    #[must_use]
+    fn key_checksum(&mut self) -> u64
+    where
+        Key: AsRef<[u8]>,
+    {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let fold = |hash: u64, bytes: &[u8]| {
+            bytes.iter().fold(hash, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+        };
+
+        let mut hash = FNV_OFFSET_BASIS;
+        self.seek_to_first();
+        while let Some(new_hash) = self.current().map(|item| {
+            let key = Self::item_to_key(item).as_ref();
+            let len = u64::try_from(key.len()).unwrap_or(u64::MAX);
+            #[expect(
+                clippy::little_endian_bytes,
+                reason = "the checksum must not depend on the platform",
+            )]
+            let len = len.to_le_bytes();
+            fold(fold(hash, &len), key)
+        }) {
+            hash = new_hash;
+            self.next();
+        }
+
+        hash
+    }
+}
+
+impl<Key, Cmp, I> SeekableLendingIteratorExt<Key, Cmp> for I
+where
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+    I:   SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+{}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use core::array;
+
+    use crate::{comparator::OrdComparator, indexed::IndexedCursor as _, test_iter::TestIter};
+    use crate::{cursor::CursorLendingIterator as _, seekable::Seekable as _};
+    use super::*;
+
+
+    fn hundred() -> [u8; 100] {
+        array::from_fn(|idx| u8::try_from(idx).unwrap())
+    }
+
+    #[test]
+    fn small_and_large_seeks() {
+        let data = hundred();
+        let mut iter = TestIter::new(&data).unwrap();
+
+        // From the phantom position, reaching the first entry takes one step.
+        assert_eq!(iter.seek_counting(&OrdComparator, &0), 1);
+        assert_eq!(iter.seek_counting(&OrdComparator, &2), 2);
+        assert_eq!(*iter.current().unwrap(), 2);
+        assert_eq!(iter.seek_counting(&OrdComparator, &40), 38);
+        assert_eq!(*iter.current().unwrap(), 40);
+
+        // Backwards
+        assert_eq!(iter.seek_counting(&OrdComparator, &37), 3);
+        assert_eq!(*iter.current().unwrap(), 37);
+        assert_eq!(iter.seek_counting(&OrdComparator, &37), 0);
+        assert_eq!(iter.seek_counting(&OrdComparator, &0), 37);
+        assert_eq!(*iter.current().unwrap(), 0);
+
+        // Past the end
+        iter.seek(&97);
+        assert_eq!(iter.seek_counting(&OrdComparator, &200), 3);
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn capped_seeks() {
+        let data = hundred();
+        let mut iter = TestIter::new(&data).unwrap();
+
+        iter.seek(&10);
+        assert_eq!(iter.seek_counting(&OrdComparator, &74), SEEK_COUNTING_CAP);
+        assert_eq!(*iter.current().unwrap(), 74);
+        assert_eq!(iter.seek_counting(&OrdComparator, &99), 25);
+        assert_eq!(iter.seek_counting(&OrdComparator, &3), SEEK_COUNTING_CAP);
+        assert_eq!(*iter.current().unwrap(), 3);
+        assert_eq!(iter.seek_counting(&OrdComparator, &66), 63);
+        assert_eq!(*iter.current().unwrap(), 66);
+    }
+
+    #[test]
+    fn indexed_matches_walk() {
+        let data = hundred();
+        let mut walked = TestIter::new(&data).unwrap();
+        let mut indexed = TestIter::new(&data).unwrap();
+
+        for target in [5, 3, 3, 50, 99, 0, 150, 20] {
+            let walk_distance = walked.seek_counting(&OrdComparator, &target);
+            let index_distance = indexed.seek_counting_indexed(&target);
+            assert_eq!(walk_distance, index_distance.min(SEEK_COUNTING_CAP));
+            assert_eq!(walked.current(), indexed.current());
+        }
+    }
+
+    #[test]
+    fn near_and_far_seek_befores() {
+        let data = hundred();
+        let mut iter = TestIter::new(&data).unwrap();
+
+        // From the phantom position, reaching the last entry takes one step.
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &200), 1);
+        assert_eq!(*iter.current().unwrap(), 99);
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &97), 3);
+        assert_eq!(*iter.current().unwrap(), 96);
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &60), 37);
+        assert_eq!(*iter.current().unwrap(), 59);
+
+        // Forwards
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &63), 3);
+        assert_eq!(*iter.current().unwrap(), 62);
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &63), 0);
+
+        // Before the start
+        iter.seek(&2);
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &0), 3);
+        assert!(!iter.valid());
+
+        // Capped
+        iter.seek(&90);
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &10), SEEK_COUNTING_CAP);
+        assert_eq!(*iter.current().unwrap(), 9);
+        assert_eq!(iter.seek_before_counting(&OrdComparator, &15), 5);
+        assert_eq!(*iter.current().unwrap(), 14);
+    }
+
+    #[test]
+    fn indexed_seek_before_matches_walk() {
+        let data = hundred();
+        let mut walked = TestIter::new(&data).unwrap();
+        let mut indexed = TestIter::new(&data).unwrap();
+
+        for target in [5, 3, 3, 50, 99, 0, 150, 20, 1] {
+            let walk_distance = walked.seek_before_counting(&OrdComparator, &target);
+            let index_distance = indexed.seek_before_counting_indexed(&target);
+            assert_eq!(walk_distance, index_distance.min(SEEK_COUNTING_CAP));
+            assert_eq!(walked.current(), indexed.current());
+        }
+    }
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn composite_key_groups() {
+        use crate::sorted_slice_cursor::SortedSliceCursor;
+
+        // Composite keys of a one-byte group and a variable-length suffix
+        let data: &[&[u8]] = &[
+            &[1, 9],
+            &[2],
+            &[2, 0],
+            &[2, 0, 5],
+            &[2, 7],
+            &[3],
+            &[3, 1],
+        ];
+        let mut iter = SortedSliceCursor::new(data, OrdComparator);
+
+        let mut group = iter.seek_component_range(&OrdComparator, [2].as_slice(), [3].as_slice());
+        for expected in [[2].as_slice(), &[2, 0], &[2, 0, 5], &[2, 7]] {
+            assert_eq!(group.next(), Some(&expected));
+        }
+        assert_eq!(group.next(), None);
+        assert_eq!(iter.current(), Some(&[3].as_slice()));
+
+        let mut group = iter.seek_component_range(&OrdComparator, [4].as_slice(), [5].as_slice());
+        assert_eq!(group.next(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn classified_merge_seeks() {
+        use alloc::vec;
+        use crate::merging_iter::MergingIter;
+
+        let data_one: &[u8] = [1, 4, 9].as_slice();
+        let data_two: &[u8] = [2, 4, 6].as_slice();
+        let mut iter = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+
+        assert_eq!(iter.seek_classified(&OrdComparator, &4), SeekOutcome::Exact);
+        assert_eq!(iter.current(), Some(&4));
+        assert_eq!(iter.seek_classified(&OrdComparator, &9), SeekOutcome::Exact);
+        assert_eq!(iter.seek_classified(&OrdComparator, &0), SeekOutcome::Successor);
+        assert_eq!(iter.current(), Some(&1));
+        assert_eq!(iter.seek_classified(&OrdComparator, &7), SeekOutcome::Successor);
+        assert_eq!(iter.current(), Some(&9));
+        assert_eq!(iter.seek_classified(&OrdComparator, &10), SeekOutcome::PastEnd);
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn immediate_successors() {
+        let data: &[u8] = [1, 3, 3, 4, 7].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        assert!(iter.is_immediate_successor(&OrdComparator, &1, &3));
+        // Duplicates of the first key are skipped.
+        assert!(iter.is_immediate_successor(&OrdComparator, &3, &4));
+        assert!(iter.is_immediate_successor(&OrdComparator, &4, &7));
+        assert_eq!(iter.current(), Some(&7));
+
+        // `3` and `4` lie between `1` and `7`.
+        assert!(!iter.is_immediate_successor(&OrdComparator, &1, &4));
+        assert!(!iter.is_immediate_successor(&OrdComparator, &1, &7));
+        // Absent keys are never adjacent.
+        assert!(!iter.is_immediate_successor(&OrdComparator, &2, &3));
+        assert!(!iter.is_immediate_successor(&OrdComparator, &4, &5));
+        assert!(!iter.is_immediate_successor(&OrdComparator, &7, &1));
+        assert!(!iter.valid());
+        assert!(!iter.is_immediate_successor(&OrdComparator, &3, &3));
+    }
+
+    #[test]
+    fn empty_and_nonempty() {
+        let data = hundred();
+        let mut iter = TestIter::new(&data).unwrap();
+        assert!(!iter.is_collection_empty());
+        // The iterator was not valid, and is returned to that position.
+        assert!(!iter.valid());
+
+        iter.seek(&42);
+        assert!(!iter.is_collection_empty());
+        assert_eq!(*iter.current().unwrap(), 42);
+
+        let mut empty = TestIter::new(&[]).unwrap();
+        assert!(empty.is_collection_empty());
+        assert!(!empty.valid());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn empty_and_nonempty_merges() {
+        use alloc::vec;
+        use crate::merging_iter::MergingIter;
+
+        let mut no_sources = MergingIter::<u8, _, TestIter<'_>>::new(vec![], OrdComparator);
+        assert!(no_sources.is_collection_empty());
+
+        let mut empty_sources = MergingIter::new(
+            vec![TestIter::new(&[]).unwrap(), TestIter::new(&[]).unwrap()],
+            OrdComparator,
+        );
+        assert!(empty_sources.is_collection_empty());
+
+        let data: &[u8] = [3, 4].as_slice();
+        let mut one_nonempty = MergingIter::new(
+            vec![TestIter::new(&[]).unwrap(), TestIter::new(data).unwrap()],
+            OrdComparator,
+        );
+        assert!(!one_nonempty.is_collection_empty());
+        assert!(!one_nonempty.valid());
+        assert_eq!(one_nonempty.next(), Some(&3));
+        assert!(!one_nonempty.is_collection_empty());
+        assert_eq!(one_nonempty.current(), Some(&3));
+    }
+
+    #[test]
+    fn partition_point_matches_indexed() {
+        let data = hundred();
+        let mut iter = TestIter::new(&data).unwrap();
+
+        assert_eq!(iter.partition_point(|&&item| item >= 5), Some(5));
+        assert_eq!(iter.current(), Some(&5));
+        assert_eq!(iter.partition_point_indexed(|&&item| item >= 5), Some(5));
+        assert_eq!(iter.current(), Some(&5));
+
+        for boundary in [0, 1, 42, 99, 100, 200] {
+            let expected = (boundary < 100).then_some(usize::from(boundary));
+            assert_eq!(iter.partition_point(|&&item| item >= boundary), expected);
+            assert_eq!(iter.partition_point_indexed(|&&item| item >= boundary), expected);
+            assert_eq!(iter.current_index(), expected);
+        }
+    }
+
+    #[test]
+    fn seek_last_matching_matches_indexed() {
+        let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        assert!(iter.seek_last_matching(|&&item| item <= 5));
+        assert_eq!(iter.current(), Some(&5));
+        assert!(iter.seek_last_matching_indexed(|&&item| item <= 5));
+        assert_eq!(iter.current(), Some(&5));
+
+        for boundary in [0, 1, 5, 9, 20] {
+            assert!(iter.seek_last_matching(|&&item| item <= boundary));
+            assert_eq!(iter.current(), Some(&boundary.min(9)));
+            assert!(iter.seek_last_matching_indexed(|&&item| item <= boundary));
+            assert_eq!(iter.current(), Some(&boundary.min(9)));
+        }
+
+        assert!(!iter.seek_last_matching(|_| false));
+        assert!(!iter.valid());
+        assert!(!iter.seek_last_matching_indexed(|_| false));
+        assert!(!iter.valid());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn key_checksums() {
+        use alloc::vec;
+        use crate::{merging_iter::MergingIter, sorted_slice_cursor::SortedSliceCursor};
+
+        let left: &[&[u8]] = &[b"apple", b"cherry"];
+        let right: &[&[u8]] = &[b"banana", b"date"];
+        let all: &[&[u8]] = &[b"apple", b"banana", b"cherry", b"date"];
+        let resplit: &[&[u8]] = &[b"apple", b"banana", b"cherry", b"da", b"te"];
+
+        let mut merged = MergingIter::new(
+            vec![
+                SortedSliceCursor::new(left, OrdComparator),
+                SortedSliceCursor::new(right, OrdComparator),
+            ],
+            OrdComparator,
+        );
+        let mut single = MergingIter::new(
+            vec![SortedSliceCursor::new(all, OrdComparator)],
+            OrdComparator,
+        );
+        let mut other = SortedSliceCursor::new(resplit, OrdComparator);
+
+        let checksum = SeekableLendingIteratorExt::<[u8], _>::key_checksum(&mut merged);
+        assert!(!merged.valid());
+        assert_eq!(checksum, SeekableLendingIteratorExt::<[u8], _>::key_checksum(&mut single));
+        assert_ne!(checksum, SeekableLendingIteratorExt::<[u8], _>::key_checksum(&mut other));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rewind_mid_run() {
+        let data: &[u8] = [1, 2, 2, 2, 3].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        iter.rewind_to_run_start(&OrdComparator);
+        assert!(!iter.valid());
+
+        iter.seek_to_index(3);
+        iter.rewind_to_run_start(&OrdComparator);
+        assert_eq!(iter.current_index(), Some(1));
+
+        // Already at the run start
+        iter.rewind_to_run_start(&OrdComparator);
+        assert_eq!(iter.current_index(), Some(1));
+
+        // Runs at the edges of the collection
+        iter.seek_to_index(0);
+        iter.rewind_to_run_start(&OrdComparator);
+        assert_eq!(iter.current_index(), Some(0));
+        iter.seek_to_index(4);
+        iter.rewind_to_run_start(&OrdComparator);
+        assert_eq!(iter.current_index(), Some(4));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rewind_run_at_start() {
+        let data: &[u8] = [5, 5, 5].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        iter.seek_to_last();
+        iter.rewind_to_run_start(&OrdComparator);
+        assert_eq!(iter.current_index(), Some(0));
+    }
+}
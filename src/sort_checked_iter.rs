@@ -0,0 +1,233 @@
+use core::{borrow::Borrow as _, cmp::Ordering};
+use alloc::borrow::ToOwned;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// A debugging adapter for a [`CursorLendingIterator`] which asserts that keys are yielded in
+/// sorted order during forwards iteration.
+///
+/// Seeking relies on the entries of a [`Seekable`] iterator being sorted by its comparator, but
+/// a stack of adapters (or a comparator which does not match the keys' true order) can silently
+/// break that invariant. Wrapping the top of an adapter stack in a `SortCheckedIter` turns such
+/// bugs into a panic at the first offending entry.
+///
+/// After each call to `next` which moves from one entry to another, the new key is checked to be
+/// greater than or equal to the previous key, according to the provided comparator. Other
+/// motions (`prev` and every seek) are not checked, but the position they move to is used as the
+/// previous key for the next call to `next`. Wrapping around, from the last entry to the first,
+/// is not checked either.
+///
+/// An owned copy of the current key is kept, and its buffer is reused where possible.
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct SortCheckedIter<I, Cmp, Key: ?Sized + ToOwned> {
+    iter:     I,
+    cmp:      Cmp,
+    /// Should be `Some` if and only if `iter` is `valid()`, in which case it holds an owned copy
+    /// of `iter`'s current key.
+    prev_key: Option<Key::Owned>,
+}
+
+impl<I, Cmp, Key> SortCheckedIter<I, Cmp, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized + ToOwned,
+{
+    /// Wrap the provided iterator, whose keys should be sorted according to `cmp`.
+    #[must_use]
+    pub fn new(iter: I, cmp: Cmp) -> Self {
+        let mut this = Self {
+            iter,
+            cmp,
+            prev_key: None,
+        };
+        this.record_key();
+        this
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Record an owned copy of the current key after `self.iter` has moved.
+    fn record_key(&mut self) {
+        if let Some(item) = self.iter.current() {
+            let key = I::item_to_key(item);
+
+            if let Some(prev_key) = &mut self.prev_key {
+                key.clone_into(prev_key);
+            } else {
+                self.prev_key = Some(key.to_owned());
+            }
+        } else {
+            self.prev_key = None;
+        }
+    }
+}
+
+impl<'lend, I, Cmp, Key> LendItem<'lend> for SortCheckedIter<I, Cmp, Key>
+where
+    I:   LendItem<'lend>,
+    Key: ?Sized + ToOwned,
+{
+    type Item = I::Item;
+}
+
+impl<I, Cmp, Key> CursorLendingIterator for SortCheckedIter<I, Cmp, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized + ToOwned,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    /// Move the iterator one position forwards, and return the entry at that position.
+    ///
+    /// # Panics
+    /// Panics if the new entry's key is less than the key of the entry the iterator was at.
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next();
+
+        if let (Some(prev_key), Some(item)) = (&self.prev_key, self.iter.current()) {
+            assert_ne!(
+                self.cmp.cmp(prev_key.borrow(), I::item_to_key(item)),
+                Ordering::Greater,
+                "SortCheckedIter: a key was less than the previous key",
+            );
+        }
+
+        self.record_key();
+        self.iter.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current()
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev();
+        self.record_key();
+        self.iter.current()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, Cmp, Key> ItemToKey<Key> for SortCheckedIter<I, Cmp, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized + ToOwned,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+impl<I, Cmp, Key> Seekable<Key, Cmp> for SortCheckedIter<I, Cmp, Key>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized + ToOwned,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.record_key();
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.record_key();
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.iter.seek_before(strict_upper_bound);
+        self.record_key();
+    }
+
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.record_key();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+        self.record_key();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use crate::{
+        comparator::OrdComparator, comparator_ext::ComparatorExt as _, merging_iter::MergingIter,
+        seekable_ext::SeekableLendingIteratorExt as _, sorted_slice_cursor::SortedSliceCursor,
+    };
+    use super::*;
+
+
+    fn last_digit(key: &u8) -> &u8 {
+        const DIGITS: [u8; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        #[expect(clippy::indexing_slicing, reason = "`key % 10` is a valid index")]
+        &DIGITS[usize::from(key % 10)]
+    }
+
+    #[test]
+    fn sorted_stack_passes() {
+        let first: &[u32] = [1, 4, 6, 9].as_slice();
+        let second: &[u32] = [2, 4, 5, 8].as_slice();
+        let mut merge = MergingIter::new(
+            vec![
+                SortedSliceCursor::new(first, OrdComparator),
+                SortedSliceCursor::new(second, OrdComparator),
+            ],
+            OrdComparator,
+        );
+        let window = merge.window(&OrdComparator, &2, &8);
+        let mut checked = SortCheckedIter::new(window, OrdComparator);
+
+        for expected in [2, 4, 4, 5, 6] {
+            assert_eq!(checked.next(), Some(&expected));
+        }
+        assert_eq!(checked.next(), None);
+        // Wrapping around is not an ordering violation.
+        assert_eq!(checked.next(), Some(&2));
+    }
+
+    #[test]
+    #[should_panic = "a key was less than the previous key"]
+    fn order_breaking_key_map_panics() {
+        let data: &[u8] = [3, 12, 21].as_slice();
+        let cmp = OrdComparator.map_key(last_digit);
+        let mut checked = SortCheckedIter::new(SortedSliceCursor::new(data, cmp), cmp);
+
+        while checked.next().is_some() {}
+    }
+}
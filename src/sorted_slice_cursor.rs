@@ -0,0 +1,345 @@
+use core::{borrow::Borrow, cmp::Ordering, marker::PhantomData, ops::Deref};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{clone_position::ClonePosition, comparator::Comparator};
+use crate::cursor::CursorLendingIterator;
+use crate::{indexed::{CountBetweenHint, IndexedCursor}, key_cursor::KeyCursor};
+use crate::key_value_cursor::KeyValueCursor;
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// A seekable cursor over a slice which is sorted according to a [`Comparator`].
+///
+/// The slice is stored as `Data`, which can be any type that dereferences to a slice, such as a
+/// borrowed slice (see [`SortedSliceCursor`]) or an owned `Vec` (see [`SortedVecCursor`]).
+///
+/// The cursor lends references to the slice's entries. It can seek by any key type `Key` such
+/// that the entries implement <code>[Borrow]\<Key\></code> and the comparator implements
+/// <code>[Comparator]\<Key\></code>; seeks are performed by binary search.
+///
+/// The slice must be sorted according to the comparator, for every key type it is used with;
+/// otherwise, seeks may position the cursor incorrectly. Duplicate entries are permitted, unless
+/// the cursor was checked with [`with_unique_keys`].
+///
+/// [`with_unique_keys`]: SortedCursor::with_unique_keys
+#[derive(Debug)]
+pub struct SortedCursor<T, Data, Cmp> {
+    data:   Data,
+    cmp:    Cmp,
+    /// Whether `data` has been checked to be strictly increasing.
+    unique: bool,
+    cursor: Option<usize>,
+    /// Makes the lifetime bounds of the lent `&T` items follow from those of the cursor.
+    _entry: PhantomData<T>,
+}
+
+impl<T, Data: Clone, Cmp: Clone> Clone for SortedCursor<T, Data, Cmp> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            data:   self.data.clone(),
+            cmp:    self.cmp.clone(),
+            unique: self.unique,
+            cursor: self.cursor,
+            _entry: PhantomData,
+        }
+    }
+}
+
+/// A [`SortedCursor`] over a borrowed slice.
+pub type SortedSliceCursor<'a, T, Cmp> = SortedCursor<T, &'a [T], Cmp>;
+
+/// A [`SortedCursor`] which owns its entries.
+///
+/// This is useful when a cursor must outlive the code which sorted its entries, such as when the
+/// entries are collected from an iterator.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub type SortedVecCursor<T, Cmp> = SortedCursor<T, Vec<T>, Cmp>;
+
+impl<T, Data, Cmp> SortedCursor<T, Data, Cmp> {
+    /// Create a cursor over `data`, which must be sorted according to `cmp`.
+    ///
+    /// The cursor starts out `!valid()`.
+    #[inline]
+    #[must_use]
+    pub const fn new(data: Data, cmp: Cmp) -> Self {
+        Self {
+            data,
+            cmp,
+            unique: false,
+            cursor: None,
+            _entry: PhantomData,
+        }
+    }
+
+    /// Get the slice the cursor iterates over.
+    #[inline]
+    #[must_use]
+    pub const fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Convert the cursor back into the slice it iterates over.
+    #[inline]
+    #[must_use]
+    pub fn into_data(self) -> Data {
+        self.data
+    }
+
+    /// Get the comparator the slice is sorted by.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+}
+
+impl<T, Data: Deref<Target = [T]>, Cmp> SortedCursor<T, Data, Cmp> {
+    /// Check that the entries of the slice are strictly increasing according to the comparator,
+    /// so that no two entries have equal keys.
+    ///
+    /// If they are, the cursor is returned and reports [`keys_are_unique`] as `true`; otherwise,
+    /// `None` is returned.
+    ///
+    /// Uniqueness is only checked for the key type `Key`. The cursor is [`Seekable`] by every key
+    /// type that its entries borrow as, but `keys_are_unique` does not distinguish between them,
+    /// so the cursor should only be checked with (and relied upon for) a key type under which no
+    /// other key type it is used with considers more entries equal.
+    ///
+    /// [`keys_are_unique`]: CursorLendingIterator::keys_are_unique
+    #[must_use]
+    pub fn with_unique_keys<Key>(mut self) -> Option<Self>
+    where
+        T:   Borrow<Key>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        let strictly_increasing = self.data.is_sorted_by(|lhs, rhs| {
+            self.cmp.cmp(lhs.borrow(), rhs.borrow()) == Ordering::Less
+        });
+
+        strictly_increasing.then(|| {
+            self.unique = true;
+            self
+        })
+    }
+
+    /// Get the index of the first entry which is not `Less` than `key`, or `data.len()` if
+    /// there is no such entry.
+    fn partition_point<Key>(&self, key: &Key) -> usize
+    where
+        T:   Borrow<Key>,
+        Key: ?Sized,
+        Cmp: Comparator<Key>,
+    {
+        self.data.partition_point(|entry| self.cmp.cmp(entry.borrow(), key) == Ordering::Less)
+    }
+}
+
+impl<'lend, T, Data, Cmp> LendItem<'lend> for SortedCursor<T, Data, Cmp>
+where
+    Data: Deref<Target = [T]>,
+{
+    type Item = &'lend T;
+}
+
+impl<T, Data: Deref<Target = [T]>, Cmp> CursorLendingIterator for SortedCursor<T, Data, Cmp> {
+    #[inline]
+    fn valid(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        let next_idx = self.cursor.map_or(0, |idx| idx + 1);
+        self.cursor = (next_idx < self.data.len()).then_some(next_idx);
+        self.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.data.get(self.cursor?)
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.cursor = self.cursor.unwrap_or(self.data.len()).checked_sub(1);
+        self.current()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.unique
+    }
+}
+
+/// Positions are saved as indices; the new cursor borrows the same slice.
+impl<T, Cmp: Clone> ClonePosition for SortedSliceCursor<'_, T, Cmp> {
+    type PositionHandle = Option<usize>;
+
+    #[inline]
+    fn clone_position(&self) -> Self::PositionHandle {
+        self.cursor
+    }
+
+    fn at_position(&self, handle: &Self::PositionHandle) -> Self {
+        Self {
+            data:   self.data,
+            cmp:    self.cmp.clone(),
+            unique: self.unique,
+            cursor: handle.filter(|&idx| idx < self.data.len()),
+            _entry: PhantomData,
+        }
+    }
+}
+
+impl<T, Data: Deref<Target = [T]>, Cmp> IndexedCursor for SortedCursor<T, Data, Cmp> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    #[inline]
+    fn current_index(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    #[inline]
+    fn seek_to_index(&mut self, index: usize) {
+        self.cursor = (index < self.data.len()).then_some(index);
+    }
+}
+
+impl<T, Data, Cmp, Key> CountBetweenHint<Key, Cmp> for SortedCursor<T, Data, Cmp>
+where
+    T:    Borrow<Key>,
+    Data: Deref<Target = [T]>,
+    Key: ?Sized,
+    Cmp: Comparator<Key>,
+{
+    /// Count the entries in `lower..upper` with two binary searches.
+    fn count_between_hint(&self, _cmp: &Cmp, lower: &Key, upper: &Key) -> Option<usize> {
+        let start = self.partition_point(lower);
+        let end = self.partition_point(upper);
+        Some(end.saturating_sub(start))
+    }
+}
+
+impl<T, Data, Cmp, Key> ItemToKey<Key> for SortedCursor<T, Data, Cmp>
+where
+    T:    Borrow<Key>,
+    Data: Deref<Target = [T]>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        item.borrow()
+    }
+}
+
+impl<T, Data, Cmp, Key> KeyCursor<Key> for SortedCursor<T, Data, Cmp>
+where
+    T:    Borrow<Key>,
+    Data: Deref<Target = [T]>,
+    Key:  ?Sized,
+{}
+
+impl<K, V, Data, Cmp> KeyValueCursor<K, V> for SortedCursor<(K, V), Data, Cmp>
+where
+    Data: Deref<Target = [(K, V)]>,
+{
+    #[inline]
+    fn item_to_key_value(item: LentItem<'_, Self>) -> (&'_ K, &'_ V) {
+        (&item.0, &item.1)
+    }
+}
+
+impl<T, Data, Cmp, Key> Seekable<Key, Cmp> for SortedCursor<T, Data, Cmp>
+where
+    T:    Borrow<Key>,
+    Data: Deref<Target = [T]>,
+    Key: ?Sized,
+    Cmp: Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.cursor = None;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        let index = self.partition_point(min_bound);
+        self.seek_to_index(index);
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.cursor = self.partition_point(strict_upper_bound).checked_sub(1);
+    }
+
+    #[inline]
+    fn seek_to_first(&mut self) {
+        self.seek_to_index(0);
+    }
+
+    fn seek_to_last(&mut self) {
+        self.cursor = self.data.len().checked_sub(1);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::comparator::OrdComparator;
+    use super::*;
+
+
+    #[test]
+    fn seek_with_duplicates() {
+        let data: &[u16] = [1, 3, 3, 3, 5, 8].as_slice();
+        let mut iter = SortedSliceCursor::new(data, OrdComparator);
+
+        iter.seek(&3);
+        assert_eq!(iter.current_index(), Some(1));
+        iter.seek(&4);
+        assert_eq!(iter.current(), Some(&5));
+        iter.seek_before(&5);
+        assert_eq!(iter.current_index(), Some(3));
+        iter.seek_before(&1);
+        assert!(!iter.valid());
+        iter.seek(&9);
+        assert!(!iter.valid());
+
+        iter.seek_to_last();
+        assert_eq!(iter.current(), Some(&8));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.prev(), None);
+        assert_eq!(iter.prev(), Some(&8));
+    }
+
+    #[test]
+    fn seek_by_borrowed_key() {
+        let data: &[&[u8]] = &[b"a", b"ab", b"b"];
+        let mut iter = SortedSliceCursor::new(data, OrdComparator);
+
+        Seekable::<[u8], _>::seek(&mut iter, b"aa".as_slice());
+        assert_eq!(iter.current(), Some(&b"ab".as_slice()));
+    }
+
+    #[test]
+    fn unique_keys() {
+        let unique: &[u16] = [1, 3, 5].as_slice();
+        let duplicated: &[u16] = [1, 3, 3].as_slice();
+
+        assert!(!SortedSliceCursor::new(unique, OrdComparator).keys_are_unique());
+        let checked = SortedSliceCursor::new(unique, OrdComparator).with_unique_keys::<u16>();
+        assert!(checked.is_some_and(|iter| iter.keys_are_unique()));
+        assert!(
+            SortedSliceCursor::new(duplicated, OrdComparator)
+                .with_unique_keys::<u16>()
+                .is_none(),
+        );
+    }
+}
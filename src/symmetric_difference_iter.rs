@@ -0,0 +1,244 @@
+use core::{cmp::Ordering, marker::PhantomData};
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::ItemToKey,
+};
+
+
+#[derive(Debug, Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// An iterator over the items of two sorted sources whose keys are present in exactly one of
+/// the two sources.
+///
+/// Since items may be lent from either source, both sources must lend the same item type. Both
+/// sources must be sorted by the provided comparator; otherwise, the output is unspecified.
+///
+/// The fronts of the two sources are compared, and the smaller one is yielded and advanced. When
+/// the fronts have equal keys, every item with that key is skipped in both sources.
+///
+/// This iterator only supports forwards iteration. Like a [`CursorLendingIterator`], it is
+/// circular: after the last item is yielded, `next` returns `None`, and a further call to `next`
+/// starts over from the first item.
+#[derive(Debug, Clone)]
+pub struct SymmetricDifferenceIter<A, B, Cmp, Key: ?Sized> {
+    left:       A,
+    right:      B,
+    cmp:        Cmp,
+    _key:       PhantomData<Key>,
+    /// The source whose current item is the current item of `self`, or `None` if `self` is
+    /// not valid.
+    current:    Option<Side>,
+    left_done:  bool,
+    right_done: bool,
+}
+
+impl<A, B, Cmp, Key> SymmetricDifferenceIter<A, B, Cmp, Key>
+where
+    A:   CursorLendingIterator + ItemToKey<Key>,
+    B:   CursorLendingIterator + ItemToKey<Key>
+        + for<'lend> LendItem<'lend, Item = LentItem<'lend, A>>,
+    Cmp: Comparator<Key>,
+    Key: ?Sized,
+{
+    /// Create an iterator over the items of `left` and `right` whose keys are present in only
+    /// one of the two sources.
+    ///
+    /// The new iterator is `!valid()`, regardless of the positions of `left` and `right`.
+    #[must_use]
+    pub const fn new(left: A, right: B, cmp: Cmp) -> Self {
+        Self {
+            left,
+            right,
+            cmp,
+            _key:       PhantomData,
+            current:    None,
+            left_done:  false,
+            right_done: false,
+        }
+    }
+
+    /// Determine whether the iterator is currently at any item.
+    #[inline]
+    #[must_use]
+    pub const fn valid(&self) -> bool {
+        self.current.is_some()
+    }
+
+    /// Get the current item the iterator is at, if it is valid.
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, A>> {
+        match self.current? {
+            Side::Left  => self.left.current(),
+            Side::Right => self.right.current(),
+        }
+    }
+
+    /// Move the iterator forwards to the next item whose key is present in only one source, and
+    /// return that item.
+    ///
+    /// If the iterator is not valid, it moves to the first such item; and if there is no such
+    /// item, the iterator becomes `!valid()` and `None` is returned.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, A>> {
+        match self.current {
+            Some(Side::Left)  => self.advance_left(),
+            Some(Side::Right) => self.advance_right(),
+            None              => self.start(),
+        }
+
+        self.settle();
+        self.current()
+    }
+
+    /// Get the comparator used to compare the keys of the two sources.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Convert the iterator back into the two sources.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (A, B) {
+        (self.left, self.right)
+    }
+
+    /// Move the fronts of both sources to their first items.
+    fn start(&mut self) {
+        if self.left.valid() {
+            // Move to the phantom element, so that `next` moves to the first item.
+            while self.left.next().is_some() {}
+        }
+        if self.right.valid() {
+            while self.right.next().is_some() {}
+        }
+        self.advance_left();
+        self.advance_right();
+    }
+
+    fn advance_left(&mut self) {
+        self.left.next();
+        self.left_done = !self.left.valid();
+    }
+
+    fn advance_right(&mut self) {
+        self.right.next();
+        self.right_done = !self.right.valid();
+    }
+
+    /// Set `self.current` to the smaller front, skipping keys present in both sources.
+    fn settle(&mut self) {
+        loop {
+            let ordering = match (self.left.current(), self.right.current()) {
+                (Some(left_item), Some(right_item)) if !self.left_done && !self.right_done => {
+                    self.cmp.cmp(A::item_to_key(left_item), B::item_to_key(right_item))
+                }
+                (_, _) if !self.left_done  => Ordering::Less,
+                (_, _) if !self.right_done => Ordering::Greater,
+                (_, _) => {
+                    self.current = None;
+                    return;
+                }
+            };
+
+            match ordering {
+                Ordering::Less    => self.current = Some(Side::Left),
+                Ordering::Greater => self.current = Some(Side::Right),
+                Ordering::Equal   => {
+                    self.skip_shared_key();
+                    continue;
+                }
+            }
+            return;
+        }
+    }
+
+    /// Both fronts are at the same key; move both sources past every item with that key.
+    fn skip_shared_key(&mut self) {
+        // Move `right` past every item equal to `left`'s current key.
+        loop {
+            self.advance_right();
+            if self.right_done || !self.fronts_equal() {
+                break;
+            }
+        }
+
+        // Move `right` back to its last item with the shared key, to compare `left` against.
+        self.right.prev();
+
+        loop {
+            self.advance_left();
+            if self.left_done || !self.fronts_equal() {
+                break;
+            }
+        }
+
+        self.advance_right();
+    }
+
+    /// Determine whether the current keys of `left` and `right` are equal. Returns `false` if
+    /// either source is not valid.
+    fn fronts_equal(&self) -> bool {
+        let (Some(left_item), Some(right_item)) = (self.left.current(), self.right.current()) else {
+            return false;
+        };
+        keys_equal(&self.cmp, A::item_to_key(left_item), B::item_to_key(right_item))
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use super::*;
+
+
+    fn assert_symmetric_difference(left: &[u8], right: &[u8], expected: &[u8]) {
+        let mut iter = SymmetricDifferenceIter::new(
+            TestIter::new(left).unwrap(),
+            TestIter::new(right).unwrap(),
+            OrdComparator,
+        );
+
+        for item in expected {
+            assert_eq!(iter.next(), Some(item));
+        }
+        assert_eq!(iter.next(), None);
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn symmetric_differences() {
+        assert_symmetric_difference(&[1, 2, 3], &[2, 3, 4], &[1, 4]);
+        assert_symmetric_difference(&[1, 2, 2, 3], &[2], &[1, 3]);
+        assert_symmetric_difference(&[2], &[0, 2, 2, 2], &[0]);
+        assert_symmetric_difference(&[], &[5, 6], &[5, 6]);
+        assert_symmetric_difference(&[5, 6], &[], &[5, 6]);
+        assert_symmetric_difference(&[5, 6], &[5, 6], &[]);
+    }
+
+    #[test]
+    fn restarts_after_end() {
+        let left: &[u8] = &[1, 3];
+        let right: &[u8] = &[2, 3];
+        let mut iter = SymmetricDifferenceIter::new(
+            TestIter::new(left).unwrap(),
+            TestIter::new(right).unwrap(),
+            OrdComparator,
+        );
+
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.current(), Some(&1));
+    }
+}
@@ -1,6 +1,7 @@
 #![expect(clippy::redundant_pub_crate, reason = "emphasize that this is internal")]
 
 use crate::{comparator::OrdComparator, cursor::CursorLendingIterator};
+use crate::indexed::{CountBetweenHint, IndexedCursor};
 use crate::{
     lending_iterator_support::{LendItem, LentItem},
     seekable::{ItemToKey, Seekable},
@@ -10,15 +11,17 @@ use crate::{
 /// An inefficient but functional seekable lending iterator over a byte slice.
 pub(crate) struct TestIter<'a> {
     data:   &'a [u8],
+    unique: bool,
     cursor: Option<usize>,
 }
 
 impl<'a> TestIter<'a> {
-    /// Checks that `data` is sorted.
+    /// Checks that `data` is sorted, and records whether its entries are unique.
     pub(crate) fn new(data: &'a [u8]) -> Option<Self> {
         if data.is_sorted() {
             Some(Self {
                 data,
+                unique: data.is_sorted_by(|lhs, rhs| lhs < rhs),
                 cursor: None,
             })
         } else {
@@ -68,6 +71,10 @@ impl CursorLendingIterator for TestIter<'_> {
 
         self.current()
     }
+
+    fn keys_are_unique(&self) -> bool {
+        self.unique
+    }
 }
 
 impl ItemToKey<u8> for TestIter<'_> {
@@ -115,3 +122,29 @@ impl Seekable<u8, OrdComparator> for TestIter<'_> {
         self.prev();
     }
 }
+
+impl IndexedCursor for TestIter<'_> {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn current_index(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    fn seek_to_index(&mut self, index: usize) {
+        self.cursor = if index < self.data.len() {
+            Some(index)
+        } else {
+            None
+        };
+    }
+}
+
+impl CountBetweenHint<u8, OrdComparator> for TestIter<'_> {
+    fn count_between_hint(&self, _cmp: &OrdComparator, lower: &u8, upper: &u8) -> Option<usize> {
+        let start = self.data.partition_point(|entry| entry < lower);
+        let end = self.data.partition_point(|entry| entry < upper);
+        Some(end.saturating_sub(start))
+    }
+}
@@ -0,0 +1,490 @@
+#[cfg(not(feature = "std"))]
+use core::borrow::Borrow as _;
+#[cfg(feature = "std")]
+use core::{borrow::Borrow, cmp::Ordering, fmt::Debug};
+use alloc::{borrow::ToOwned, vec::Vec};
+#[cfg(feature = "std")]
+use alloc::{collections::BTreeMap, format};
+
+use crate::{comparator::{Comparator, keys_equal}, cursor::CursorLendingIterator};
+use crate::seekable::{ItemToKey, Seekable};
+#[cfg(feature = "std")]
+use crate::{merging_iter::MergingIter, sorted_slice_cursor::SortedSliceCursor};
+
+
+/// Assert that seeking to the same key twice in a row moves a [`Seekable`] iterator to the same
+/// position both times.
+///
+/// This is a correctness probe for custom `Seekable` implementations, intended for use in their
+/// tests (including fuzz-style tests with arbitrary `keys`). It catches implementations whose
+/// [`seek`] depends on state left behind by a previous motion, such as a stale cached position.
+///
+/// For each key in `keys`, in order, the iterator is [`seek`]ed to that key twice, and after each
+/// seek the current key is recorded. The two positions must either both be `!valid()`, or both
+/// be at keys which are equal according to `cmp`, which should behave identically to the
+/// iterator's comparator. Since positions are compared by key, a seek which lands on a different
+/// entry with an equal key is not detected. The iterator is left at the position of the second
+/// seek to the last key.
+///
+/// # Panics
+/// Panics if two seeks to the same key move the iterator to different positions.
+///
+/// [`seek`]: Seekable::seek
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn assert_seek_idempotent<I, Cmp, Key>(iter: &mut I, cmp: &Cmp, keys: &[Key])
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Cmp: ?Sized + Comparator<Key>,
+    Key: ToOwned,
+{
+    for (key_idx, key) in keys.iter().enumerate() {
+        iter.seek(key);
+        let first_position = iter.current().map(|item| I::item_to_key(item).to_owned());
+
+        iter.seek(key);
+        let idempotent = match (&first_position, iter.current()) {
+            (None, None) => true,
+            (Some(first_key), Some(item)) => {
+                keys_equal(cmp, first_key.borrow(), I::item_to_key(item))
+            }
+            _ => false,
+        };
+
+        assert!(
+            idempotent,
+            "assert_seek_idempotent: seeking twice to the key at index {key_idx} moved to \
+             different positions",
+        );
+    }
+}
+
+/// Assert that seeking to the key of every entry of a [`Seekable`] iterator lands on an entry
+/// with exactly that key.
+///
+/// This is a correctness probe for custom `Seekable` implementations, intended for use in their
+/// tests; it catches off-by-one errors in [`seek`], such as landing one entry past the target.
+///
+/// Owned copies of every key in the collection are first collected by moving forwards from the
+/// first entry. The iterator is then [`seek`]ed to each of those keys in turn, and the current
+/// key must compare as [`Equal`] to it according to `cmp`, which should behave identically to
+/// the iterator's comparator. The iterator is left at the position of the last seek.
+///
+/// # Panics
+/// Panics if a seek to an existing key is not at an entry with an equal key.
+///
+/// [`seek`]: Seekable::seek
+/// [`Equal`]: core::cmp::Ordering::Equal
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn assert_all_keys_seekable<I, Cmp, Key>(iter: &mut I, cmp: &Cmp)
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Cmp: ?Sized + Comparator<Key>,
+    Key: ?Sized + ToOwned,
+{
+    let mut keys = Vec::new();
+    iter.seek_to_first();
+    loop {
+        let Some(item) = iter.current() else {
+            break;
+        };
+        keys.push(I::item_to_key(item).to_owned());
+        iter.next();
+    }
+
+    for (entry_idx, key) in keys.iter().enumerate() {
+        iter.seek(key.borrow());
+        let landed_exactly = iter
+            .current()
+            .is_some_and(|item| keys_equal(cmp, key.borrow(), I::item_to_key(item)));
+
+        assert!(
+            landed_exactly,
+            "assert_all_keys_seekable: seeking to the key of entry {entry_idx} did not land on \
+             that key",
+        );
+    }
+}
+
+
+/// Assert that a [`MergingIter`] over the provided sources agrees with a [`BTreeMap`] built from
+/// their union, when iterating forwards, iterating backwards, and seeking.
+///
+/// This is a one-call property test for the merge of a particular set of sources, such as
+/// sources produced by a custom storage layer. Each source is a `Vec` of key-value entries,
+/// which should be sorted by key according to `cmp`; `cmp` must agree with the keys' [`Ord`]
+/// implementation, which orders the expected `BTreeMap`.
+///
+/// Entries with equal keys are all expected, ordered by the index of their source and then by
+/// their position in that source, so a [strict duplicates] merge is used. The checks are:
+/// - iterating forwards from the start yields every entry, in order;
+/// - iterating backwards from the end yields every entry, in reverse order;
+/// - [`seek_to_first`] and [`seek_to_last`] land on the first and last entries;
+/// - a battery of pseudorandom seeks, each to the key of some entry, lands on the expected
+///   entry, both with [`seek`] and [`seek_before`], and moving one entry in either direction
+///   from there reaches the expected neighbor.
+///
+/// Every check is then repeated on merges of the same sources with all the entries of the
+/// smallest, a middle, or the largest key removed, with that key as the seek target, so that
+/// seeks to absent keys, to keys below the minimum, and to keys above the maximum are covered.
+///
+/// The seeks are deterministic, so a failure can be reproduced by running the same test again.
+///
+/// # Panics
+/// Panics if the merge disagrees with the expected entries. The panic message includes the
+/// disagreeing entries and a description of the check.
+///
+/// [strict duplicates]: MergingIter::new_strict_duplicates
+/// [`seek`]: Seekable::seek
+/// [`seek_before`]: Seekable::seek_before
+/// [`seek_to_first`]: Seekable::seek_to_first
+/// [`seek_to_last`]: Seekable::seek_to_last
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[expect(
+    clippy::needless_pass_by_value,
+    reason = "taking ownership lets callers build the sources inline",
+)]
+pub fn assert_merge_matches_btreemap<K, V, Cmp>(sources: Vec<Vec<(K, V)>>, cmp: Cmp)
+where
+    K:   Ord + Debug,
+    V:   PartialEq + Debug,
+    Cmp: Comparator<K> + Clone,
+{
+    let entries: Vec<Vec<Entry<'_, K, V>>> = sources
+        .iter()
+        .map(|source| source.iter().map(|(key, value)| Entry(key, value)).collect())
+        .collect();
+    let expected = expected_entries(&entries);
+
+    // A fixed xorshift generator, so that failures are reproducible.
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let num_seeks = expected.len().saturating_mul(4).saturating_add(8);
+    let mut targets = Vec::with_capacity(num_seeks);
+    if let Ok(len @ 1..) = u64::try_from(expected.len()) {
+        for _ in 0..num_seeks {
+            state ^= state << 13_u32;
+            state ^= state >> 7_u32;
+            state ^= state << 17_u32;
+            let target_idx = usize::try_from(state % len).unwrap_or(0);
+            if let Some(&(target, _)) = expected.get(target_idx) {
+                targets.push(target);
+            }
+        }
+    }
+    check_merge(&entries, &expected, &cmp, &targets, None);
+
+    let mut distinct_keys: Vec<&K> = expected.iter().map(|&(key, _)| key).collect();
+    distinct_keys.dedup();
+    let absent_keys = [
+        distinct_keys.first(),
+        distinct_keys.get(distinct_keys.len() >> 1_u8),
+        distinct_keys.last(),
+    ];
+    for &absent in absent_keys.into_iter().flatten() {
+        let without: Vec<Vec<Entry<'_, K, V>>> = entries
+            .iter()
+            .map(|source| {
+                source
+                    .iter()
+                    .filter(|entry| entry.0 != absent)
+                    .map(|&Entry(key, value)| Entry(key, value))
+                    .collect()
+            })
+            .collect();
+        let expected_without = expected_entries(&without);
+        check_merge(&without, &expected_without, &cmp, &[absent], Some(absent));
+    }
+}
+
+/// Get every entry of the sources, in the order that a strict duplicates merge should yield
+/// them, to compare with in [`assert_merge_matches_btreemap`].
+#[cfg(feature = "std")]
+fn expected_entries<'a, K: Ord, V>(entries: &[Vec<Entry<'a, K, V>>]) -> Vec<(&'a K, &'a V)> {
+    let mut by_key: BTreeMap<&K, Vec<&V>> = BTreeMap::new();
+    for &Entry(key, value) in entries.iter().flatten() {
+        by_key.entry(key).or_default().push(value);
+    }
+    by_key
+        .into_iter()
+        .flat_map(|(key, values)| values.into_iter().map(move |value| (key, value)))
+        .collect()
+}
+
+/// Perform the checks of [`assert_merge_matches_btreemap`] on a merge of `entries`, seeking to
+/// each of the `targets`.
+///
+/// If the merge is of sources with every entry of some key removed, that key should be given
+/// as `removed`, to be reported in panic messages.
+#[cfg(feature = "std")]
+fn check_merge<K, V, Cmp>(
+    entries:  &[Vec<Entry<'_, K, V>>],
+    expected: &[(&K, &V)],
+    cmp:      &Cmp,
+    targets:  &[&K],
+    removed:  Option<&K>,
+) where
+    K:   Ord + Debug,
+    V:   PartialEq + Debug,
+    Cmp: Comparator<K> + Clone,
+{
+    let cursors = entries
+        .iter()
+        .map(|source| SortedSliceCursor::new(source.as_slice(), cmp.clone()))
+        .collect();
+    let mut merge = MergingIter::new_strict_duplicates(cursors, cmp.clone());
+    let context = removed.map(|key| format!(" (with every entry of {key:?} removed)"));
+    let context = context.as_deref().unwrap_or("");
+
+    for (entry_idx, &entry) in expected.iter().enumerate() {
+        merge.next();
+        assert_eq!(
+            current_entry(&merge),
+            Some(entry),
+            "assert_merge_matches_btreemap: iterating forwards, entry {entry_idx} differs\
+             {context}",
+        );
+    }
+    merge.next();
+    assert_eq!(
+        current_entry(&merge),
+        None,
+        "assert_merge_matches_btreemap: too many entries forwards{context}",
+    );
+
+    for (entry_idx, &entry) in expected.iter().enumerate().rev() {
+        merge.prev();
+        assert_eq!(
+            current_entry(&merge),
+            Some(entry),
+            "assert_merge_matches_btreemap: iterating backwards, entry {entry_idx} differs\
+             {context}",
+        );
+    }
+    merge.prev();
+    assert_eq!(
+        current_entry(&merge),
+        None,
+        "assert_merge_matches_btreemap: too many entries backwards{context}",
+    );
+
+    merge.seek_to_first();
+    assert_eq!(
+        current_entry(&merge),
+        expected.first().copied(),
+        "assert_merge_matches_btreemap: seeking to the first entry{context}",
+    );
+    merge.seek_to_last();
+    assert_eq!(
+        current_entry(&merge),
+        expected.last().copied(),
+        "assert_merge_matches_btreemap: seeking to the last entry{context}",
+    );
+
+    for &target in targets {
+        // The index of the first entry whose key is at least `target`.
+        let first_at_least =
+            expected.partition_point(|&(key, _)| key.cmp(target) == Ordering::Less);
+        let expected_at = |idx: Option<usize>| expected.get(idx?).copied();
+
+        merge.seek(target);
+        assert_eq!(
+            current_entry(&merge),
+            expected_at(Some(first_at_least)),
+            "assert_merge_matches_btreemap: seeking to {target:?}{context}",
+        );
+        merge.prev();
+        assert_eq!(
+            current_entry(&merge),
+            expected_at(first_at_least.checked_sub(1)),
+            "assert_merge_matches_btreemap: moving backwards after seeking to {target:?}\
+             {context}",
+        );
+
+        merge.seek_before(target);
+        assert_eq!(
+            current_entry(&merge),
+            expected_at(first_at_least.checked_sub(1)),
+            "assert_merge_matches_btreemap: seeking before {target:?}{context}",
+        );
+        merge.next();
+        assert_eq!(
+            current_entry(&merge),
+            expected_at(Some(first_at_least)),
+            "assert_merge_matches_btreemap: moving forwards after seeking before {target:?}\
+             {context}",
+        );
+    }
+}
+
+/// Split the current entry of a merge into references to its key and value, to compare with
+/// the expected entries in [`assert_merge_matches_btreemap`].
+#[cfg(feature = "std")]
+fn current_entry<'a, K, V, Cmp>(
+    merge: &MergingIter<K, Cmp, SortedSliceCursor<'_, Entry<'a, K, V>, Cmp>>,
+) -> Option<(&'a K, &'a V)>
+where
+    Cmp: Comparator<K>,
+{
+    merge.current().map(|&Entry(key, value)| (key, value))
+}
+
+/// A key-value entry of a source of [`assert_merge_matches_btreemap`], which borrows as its key
+/// so that the sources can be [`SortedSliceCursor`]s seeked by key.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+struct Entry<'a, K, V>(&'a K, &'a V);
+
+#[cfg(feature = "std")]
+impl<K, V> Borrow<K> for Entry<'_, K, V> {
+    #[inline]
+    fn borrow(&self) -> &K {
+        self.0
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{comparator::OrdComparator, test_iter::TestIter};
+    use crate::lending_iterator_support::{LendItem, LentItem};
+    use super::*;
+
+
+    /// A deliberately broken iterator, whose every other seek moves one entry too far.
+    struct FlakySeek<'a> {
+        iter:    TestIter<'a>,
+        toggled: bool,
+    }
+
+    impl<'lend> LendItem<'lend> for FlakySeek<'_> {
+        type Item = &'lend u8;
+    }
+
+    impl CursorLendingIterator for FlakySeek<'_> {
+        fn valid(&self) -> bool {
+            self.iter.valid()
+        }
+
+        fn next(&mut self) -> Option<LentItem<'_, Self>> {
+            self.iter.next()
+        }
+
+        fn current(&self) -> Option<LentItem<'_, Self>> {
+            self.iter.current()
+        }
+
+        fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+            self.iter.prev()
+        }
+    }
+
+    impl ItemToKey<u8> for FlakySeek<'_> {
+        fn item_to_key(item: LentItem<'_, Self>) -> &'_ u8 {
+            item
+        }
+    }
+
+    impl Seekable<u8, OrdComparator> for FlakySeek<'_> {
+        fn reset(&mut self) {
+            self.iter.reset();
+        }
+
+        fn seek(&mut self, min_bound: &u8) {
+            self.iter.seek(min_bound);
+            self.toggled = !self.toggled;
+            if self.toggled {
+                self.iter.next();
+            }
+        }
+
+        fn seek_before(&mut self, strict_upper_bound: &u8) {
+            self.iter.seek_before(strict_upper_bound);
+        }
+
+        fn seek_to_first(&mut self) {
+            self.iter.seek_to_first();
+        }
+
+        fn seek_to_last(&mut self) {
+            self.iter.seek_to_last();
+        }
+    }
+
+    #[test]
+    fn sorted_source_is_idempotent() {
+        let data: &[u8] = [1, 3, 3, 5, 9].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        assert_seek_idempotent(&mut iter, &OrdComparator, &[0, 1, 2, 3, 5, 8, 9, 10, 3, 0]);
+        assert_eq!(iter.current(), Some(&1));
+    }
+
+    #[test]
+    fn every_key_of_sorted_source_is_seekable() {
+        let data: &[u8] = [1, 3, 3, 5, 9].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        assert_all_keys_seekable(&mut iter, &OrdComparator);
+        assert_eq!(iter.current(), Some(&9));
+    }
+
+    #[test]
+    #[should_panic = "seeking to the key of entry 0 did not land on that key"]
+    fn overshooting_seek_is_caught() {
+        let data: &[u8] = [1, 3, 5].as_slice();
+        let mut iter = FlakySeek {
+            iter:    TestIter::new(data).unwrap(),
+            toggled: false,
+        };
+
+        assert_all_keys_seekable(&mut iter, &OrdComparator);
+    }
+
+    #[test]
+    #[should_panic = "seeking twice to the key at index 0 moved to different positions"]
+    fn flaky_seek_is_caught() {
+        let data: &[u8] = [1, 3, 5].as_slice();
+        let mut iter = FlakySeek {
+            iter:    TestIter::new(data).unwrap(),
+            toggled: false,
+        };
+
+        assert_seek_idempotent(&mut iter, &OrdComparator, &[2, 4]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn merges_match_btreemap() {
+        use alloc::vec;
+
+        assert_merge_matches_btreemap::<u8, char, _>(vec![], OrdComparator);
+        assert_merge_matches_btreemap(vec![vec![(1, 'a'), (4, 'b'), (9, 'c')]], OrdComparator);
+        // Disjoint sources.
+        assert_merge_matches_btreemap(
+            vec![vec![(5, 'e'), (6, 'f')], vec![(1, 'a'), (2, 'b')], vec![]],
+            OrdComparator,
+        );
+        // Overlapping sources, with duplicate keys within and across sources.
+        assert_merge_matches_btreemap(
+            vec![
+                vec![(1, "one"), (3, "three"), (3, "tres"), (8, "eight")],
+                vec![],
+                vec![(0, "zero"), (3, "drei"), (8, "acht"), (9, "neun")],
+                vec![(3, "trois"), (4, "quatre"), (8, "huit")],
+            ],
+            OrdComparator,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic = "assert_merge_matches_btreemap: iterating forwards, entry 1 differs"]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn unsorted_source_is_caught() {
+        use alloc::vec;
+
+        assert_merge_matches_btreemap(vec![vec![(1, ()), (5, ()), (3, ())]], OrdComparator);
+    }
+}
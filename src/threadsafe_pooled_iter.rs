@@ -1,8 +1,11 @@
 use core::{
     borrow::{Borrow, BorrowMut},
+    mem,
     ops::{Deref, DerefMut},
+    sync::atomic::{AtomicUsize, Ordering},
 };
-use alloc::borrow::ToOwned;
+use alloc::{borrow::ToOwned, vec::Vec};
+use std::{sync::mpsc::{self, Receiver}, thread::{self, JoinHandle}};
 
 use anchored_pool::{PooledResource, ResetNothing, ResourcePoolEmpty, SharedBoundedPool};
 
@@ -23,8 +26,10 @@ use crate::{
 #[derive(Debug)]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 pub struct ThreadsafePooledIter<I, BorrowedItem: ToOwned> {
-    iter: I,
-    pool: SharedBoundedPool<BorrowedItem::Owned, ResetNothing>,
+    iter:      I,
+    pool:      SharedBoundedPool<BorrowedItem::Owned, ResetNothing>,
+    /// The largest number of buffers observed to be in use at once.
+    peak_used: AtomicUsize,
 }
 
 impl<I, BorrowedItem> ThreadsafePooledIter<I, BorrowedItem>
@@ -37,7 +42,96 @@ where
     pub fn new(iter: I, num_buffers: usize) -> Self {
         let pool = SharedBoundedPool::new_default_without_reset(num_buffers);
 
-        Self { iter, pool }
+        Self {
+            iter,
+            pool,
+            peak_used: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<I, BorrowedItem: ToOwned> ThreadsafePooledIter<I, BorrowedItem> {
+    /// Get the largest number of buffers observed to be in use at the same time.
+    ///
+    /// Usage is recorded whenever this iterator lends out an item, and counts every buffer held
+    /// at that time, including buffers taken by [`with_reserved_buffers`] (though items lent by
+    /// the resulting [`ReservedBuffers`] are not recorded). This can help to determine whether
+    /// the `num_buffers` provided to [`ThreadsafePooledIter::new`] is larger or smaller than
+    /// needed.
+    ///
+    /// For an iterator created by [`SharedPoolGroup::pooled_iter`], the buffers held at that
+    /// time are counted across the whole group, including buffers held by items of the group's
+    /// other iterators; only the times at which usage is recorded are specific to this iterator.
+    ///
+    /// [`with_reserved_buffers`]: ThreadsafePooledIter::with_reserved_buffers
+    #[inline]
+    #[must_use]
+    pub fn peak_buffers_used(&self) -> usize {
+        self.peak_used.load(Ordering::Relaxed)
+    }
+
+    /// Update the peak number of buffers in use with the current number of buffers in use.
+    ///
+    /// Takes the fields of `self` separately, so that `self.iter` may remain borrowed.
+    fn record_usage(
+        pool:      &SharedBoundedPool<BorrowedItem::Owned, ResetNothing>,
+        peak_used: &AtomicUsize,
+    ) {
+        let used = pool.pool_size() - pool.available_resources();
+        peak_used.fetch_max(used, Ordering::Relaxed);
+    }
+}
+
+impl<I, BorrowedItem> ThreadsafePooledIter<I, BorrowedItem>
+where
+    BorrowedItem:        ToOwned,
+    BorrowedItem::Owned: Default,
+{
+    /// Reserve `num_buffers` of this iterator's buffers for a separate consumer, returning a
+    /// [`ReservedBuffers`] handle which lends out items using only the reserved buffers.
+    ///
+    /// The reserved buffers are removed from this iterator's buffer pool until the handle is
+    /// dropped, so neither this iterator nor the handle can exhaust the other's buffers.
+    /// The contents of the reserved buffers are moved into the handle, so no new buffers
+    /// are allocated, and are moved back when the handle is dropped, so the buffers keep any
+    /// capacity they had. (The contents of buffers in items lent by the handle which outlive the
+    /// handle are not moved back.)
+    ///
+    /// Reserved buffers still count towards this iterator's [`buffer_pool_size`], as buffers in
+    /// use. In particular, if every buffer is reserved, this iterator's methods which wait for a
+    /// buffer (like [`next`]) do not panic as they would with a pool of zero buffers; instead,
+    /// they wait until the handle is dropped, which deadlocks if the handle is dropped by the
+    /// same thread.
+    ///
+    /// Cursors cannot be shared, so the handle does not have a cursor of its own; instead, its
+    /// methods take the cursor to advance as an argument.
+    ///
+    /// # Errors
+    /// Returns an error, without reserving any buffers, if fewer than `num_buffers` buffers are
+    /// currently available.
+    ///
+    /// [`buffer_pool_size`]: PooledIterator::buffer_pool_size
+    /// [`next`]: PooledIterator::next
+    pub fn with_reserved_buffers(
+        &self,
+        num_buffers: usize,
+    ) -> Result<ReservedBuffers<BorrowedItem>, OutOfBuffers> {
+        let mut placeholders = Vec::with_capacity(num_buffers);
+        for _ in 0..num_buffers {
+            // If this fails, the buffers taken so far are dropped and returned to the pool.
+            let buffer = self.pool.try_get().map_err(|ResourcePoolEmpty| OutOfBuffers)?;
+            placeholders.push(ThreadsafePoolItem(buffer));
+        }
+
+        let mut taken_buffers = placeholders
+            .iter_mut()
+            .map(|placeholder| mem::take(&mut **placeholder));
+        let pool = SharedBoundedPool::new_without_reset(
+            num_buffers,
+            || taken_buffers.next().unwrap_or_default(),
+        );
+
+        Ok(ReservedBuffers { pool, placeholders })
     }
 }
 
@@ -81,7 +175,9 @@ where
     /// This method may also cause a deadlock if no buffers are currently available, and the
     /// current thread needs to make progress in order to release a buffer.
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().map(|item| Self::fill_buffer(&self.pool, item))
+        let item = self.iter.next().map(|item| Self::fill_buffer(&self.pool, item));
+        Self::record_usage(&self.pool, &self.peak_used);
+        item
     }
 
     fn try_next(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
@@ -90,6 +186,7 @@ where
 
         if let Some(item) = self.iter.next() {
             item.borrow().clone_into(&mut buffer);
+            Self::record_usage(&self.pool, &self.peak_used);
             Ok(Some(ThreadsafePoolItem(buffer)))
         } else {
             Ok(None)
@@ -106,6 +203,52 @@ where
     }
 }
 
+impl<I, BorrowedItem> ThreadsafePooledIter<I, BorrowedItem>
+where
+    I:                             CursorLendingIterator + Send + 'static,
+    BorrowedItem:                  ToOwned + 'static,
+    BorrowedItem::Owned:           Send,
+    for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+{
+    /// Move the iterator to a new thread which drives it forwards, sending each item over an
+    /// [`mpsc`] channel.
+    ///
+    /// The producer thread starts from the iterator's current position: if the iterator is
+    /// valid, the first item sent is the entry after the current entry, and otherwise it is the
+    /// first entry of the collection. The thread stops once the end of the collection is reached
+    /// or the [`Receiver`] is dropped, and the join handle returns the iterator, positioned
+    /// `!valid()` or just after the last item sent.
+    ///
+    /// # Buffer budget
+    /// Each item sent over the channel holds one of the iterator's buffers until the consumer
+    /// drops it, and the buffer pool is shared (through an `Arc`) between the producer and
+    /// every item. The producer waits for a buffer whenever all of them are in use, so it gets at
+    /// most `num_buffers` items ahead of the consumer; in particular, a consumer which holds
+    /// onto every item it receives will stall the producer once all buffers are in use.
+    ///
+    /// # Panics
+    /// The producer thread panics, and the join handle returns an error, if
+    /// `self.buffer_pool_size() == 0`.
+    #[must_use]
+    pub fn spawn_producer(
+        mut self,
+    ) -> (JoinHandle<Self>, Receiver<ThreadsafePoolItem<BorrowedItem::Owned>>) {
+        let (sender, receiver) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            while let Some(item) = self.next() {
+                if sender.send(item).is_err() {
+                    // The receiver was dropped; the item was returned to the pool.
+                    break;
+                }
+            }
+            self
+        });
+
+        (handle, receiver)
+    }
+}
+
 impl<I, BorrowedItem> CursorPooledIterator for ThreadsafePooledIter<I, BorrowedItem>
 where
     I:                             CursorLendingIterator,
@@ -129,7 +272,9 @@ where
     /// [valid]: CursorPooledIterator::valid
     #[inline]
     fn current(&self) -> Option<Self::Item> {
-        self.iter.current().map(|item| Self::fill_buffer(&self.pool, item))
+        let item = self.iter.current().map(|item| Self::fill_buffer(&self.pool, item));
+        Self::record_usage(&self.pool, &self.peak_used);
+        item
     }
 
     fn try_current(&self) -> Result<Option<Self::Item>, OutOfBuffers> {
@@ -138,6 +283,7 @@ where
 
         if let Some(item) = self.iter.current() {
             item.borrow().clone_into(&mut buffer);
+            Self::record_usage(&self.pool, &self.peak_used);
             Ok(Some(ThreadsafePoolItem(buffer)))
         } else {
             Ok(None)
@@ -154,7 +300,9 @@ where
     /// This method may also cause a deadlock if no buffers are currently available, and the
     /// current thread needs to make progress in order to release a buffer.
     fn prev(&mut self) -> Option<Self::Item> {
-        self.iter.prev().map(|item| Self::fill_buffer(&self.pool, item))
+        let item = self.iter.prev().map(|item| Self::fill_buffer(&self.pool, item));
+        Self::record_usage(&self.pool, &self.peak_used);
+        item
     }
 
     fn try_prev(&mut self) -> Result<Option<Self::Item>, OutOfBuffers> {
@@ -163,6 +311,7 @@ where
 
         if let Some(item) = self.iter.prev() {
             item.borrow().clone_into(&mut buffer);
+            Self::record_usage(&self.pool, &self.peak_used);
             Ok(Some(ThreadsafePoolItem(buffer)))
         } else {
             Ok(None)
@@ -201,6 +350,161 @@ where
     }
 }
 
+/// A single buffer pool shared by several [`ThreadsafePooledIter`]s, which bounds the total
+/// number of buffers used by all of them.
+///
+/// Giving each of `n` sources its own pool multiplies the memory used for buffers by `n`;
+/// instead, each [`ThreadsafePooledIter`] created by [`SharedPoolGroup::pooled_iter`] draws from
+/// this group's buffers. The pool is shared through an `Arc`, so the iterators (and their items)
+/// may outlive the group.
+///
+/// Since the buffers are shared, each iterator's [`buffer_pool_size`], [`available_buffers`], and
+/// [`peak_buffers_used`] refer to the whole group, and one iterator may need to wait on items
+/// lent by another.
+///
+/// [`buffer_pool_size`]: PooledIterator::buffer_pool_size
+/// [`available_buffers`]: PooledIterator::available_buffers
+/// [`peak_buffers_used`]: ThreadsafePooledIter::peak_buffers_used
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct SharedPoolGroup<BorrowedItem: ToOwned> {
+    pool: SharedBoundedPool<BorrowedItem::Owned, ResetNothing>,
+}
+
+impl<BorrowedItem> SharedPoolGroup<BorrowedItem>
+where
+    BorrowedItem:        ToOwned,
+    BorrowedItem::Owned: Default,
+{
+    /// Create a group whose iterators can lend out up to `num_buffers` items at a time in
+    /// total.
+    #[must_use]
+    pub fn new(num_buffers: usize) -> Self {
+        Self {
+            pool: SharedBoundedPool::new_default_without_reset(num_buffers),
+        }
+    }
+}
+
+impl<BorrowedItem: ToOwned> SharedPoolGroup<BorrowedItem> {
+    /// Convert the provided iterator into a [`ThreadsafePooledIter`] which uses this group's
+    /// buffers.
+    #[must_use]
+    pub fn pooled_iter<I>(&self, iter: I) -> ThreadsafePooledIter<I, BorrowedItem> {
+        ThreadsafePooledIter {
+            iter,
+            pool:      self.pool.clone(),
+            peak_used: AtomicUsize::new(0),
+        }
+    }
+
+    /// Get the total number of buffers in the group, including buffers that are currently in
+    /// use.
+    #[inline]
+    #[must_use]
+    pub fn buffer_pool_size(&self) -> usize {
+        self.pool.pool_size()
+    }
+
+    /// Get the number of buffers in the group that are currently available.
+    ///
+    /// In multithreaded scenarios, the returned value could change at any time.
+    #[must_use]
+    pub fn available_buffers(&self) -> usize {
+        self.pool.available_resources()
+    }
+}
+
+/// A handle to buffers reserved from a [`ThreadsafePooledIter`]'s buffer pool, created by
+/// [`ThreadsafePooledIter::with_reserved_buffers`].
+///
+/// The handle lends out items from a cursor provided by the caller, using only its reserved
+/// buffers. The reserved buffers, along with their contents, are returned to the
+/// [`ThreadsafePooledIter`]'s buffer pool once the handle is dropped, though the contents of
+/// buffers in items lent by the handle which are still alive at that time are not returned.
+///
+/// If the handle has zero reserved buffers, its methods which wait for a buffer panic.
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub struct ReservedBuffers<BorrowedItem: ToOwned> {
+    pool:         SharedBoundedPool<BorrowedItem::Owned, ResetNothing>,
+    /// Held to keep the reserved buffers out of the source iterator's pool. Their contents were
+    /// moved into `pool`, and are moved back when the handle is dropped.
+    placeholders: Vec<ThreadsafePoolItem<BorrowedItem::Owned>>,
+}
+
+impl<BorrowedItem: ToOwned> ReservedBuffers<BorrowedItem> {
+    /// Move the provided cursor one position forwards, and return the entry at that position in
+    /// one of the reserved buffers. Returns `None` if the cursor was at the last entry.
+    ///
+    /// May need to wait for a reserved buffer to become available.
+    ///
+    /// # Potential Panics or Deadlocks
+    /// If `self.buffer_pool_size() == 0`, then this method panics.
+    /// This method may also cause a deadlock if no reserved buffers are currently available, and
+    /// the current thread needs to make progress in order to release a buffer.
+    pub fn next<I>(&self, iter: &mut I) -> Option<ThreadsafePoolItem<BorrowedItem::Owned>>
+    where
+        I:                             CursorLendingIterator,
+        for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+    {
+        iter.next()
+            .map(|item| ThreadsafePooledIter::<I, BorrowedItem>::fill_buffer(&self.pool, item))
+    }
+
+    /// If a reserved buffer is available, move the provided cursor one position forwards, and
+    /// return the entry at that position. Returns `Ok(None)` if the cursor was at the last entry.
+    ///
+    /// # Errors
+    /// Returns an error if no reserved buffers were available.
+    pub fn try_next<I>(
+        &self,
+        iter: &mut I,
+    ) -> Result<Option<ThreadsafePoolItem<BorrowedItem::Owned>>, OutOfBuffers>
+    where
+        I:                             CursorLendingIterator,
+        for<'lend> LentItem<'lend, I>: Borrow<BorrowedItem>,
+    {
+        let mut buffer = self.pool.try_get()
+            .map_err(|ResourcePoolEmpty| OutOfBuffers)?;
+
+        if let Some(item) = iter.next() {
+            item.borrow().clone_into(&mut buffer);
+            Ok(Some(ThreadsafePoolItem(buffer)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the total number of reserved buffers, including buffers that are currently in use.
+    #[inline]
+    #[must_use]
+    pub fn buffer_pool_size(&self) -> usize {
+        self.pool.pool_size()
+    }
+
+    /// Get the number of reserved buffers that are currently available.
+    ///
+    /// In multithreaded scenarios, the returned value could change at any time.
+    #[must_use]
+    pub fn available_buffers(&self) -> usize {
+        self.pool.available_resources()
+    }
+}
+
+impl<BorrowedItem: ToOwned> Drop for ReservedBuffers<BorrowedItem> {
+    fn drop(&mut self) {
+        // Move the contents of the available reserved buffers back into the placeholders, so
+        // that the buffers returned to the source iterator's pool keep their capacity.
+        for placeholder in &mut self.placeholders {
+            let Ok(mut buffer) = self.pool.try_get() else {
+                break;
+            };
+            mem::swap(&mut **placeholder, &mut *buffer);
+        }
+    }
+}
+
 /// The type of an item returned by [`ThreadsafePooledIter`].
 ///
 /// The owned item buffer is returned to the [`ThreadsafePooledIter`] when the
@@ -257,12 +561,19 @@ impl<OwnedItem> AsMut<OwnedItem> for ThreadsafePoolItem<OwnedItem> {
 
 
 #[cfg(test)]
+#[expect(clippy::assertions_on_result_states, reason = "only whether the call succeeds is checked")]
+#[expect(clippy::shadow_unrelated, reason = "each check in the test starts from fresh bindings")]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
 mod tests {
-    use crate::test_iter::TestIter;
+    use alloc::vec;
+
+    use crate::{comparator::OrdComparator, merging_iter::MergingIter, test_iter::TestIter};
+    use crate::sorted_slice_cursor::SortedSliceCursor;
     use super::*;
 
 
     #[test]
+    #[expect(clippy::nonminimal_bool, reason = "the assertion states the property being tested")]
     fn threadsafe_pooled_test_iter() {
         let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
         let mut iter = ThreadsafePooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 2);
@@ -303,6 +614,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn shared_pool_group_bounds_total_usage() {
+        let data_one: &[u8] = [0, 2, 4, 6].as_slice();
+        let data_two: &[u8] = [1, 3, 5, 7].as_slice();
+        let group = SharedPoolGroup::<u8>::new(3);
+        let mut iter_one = group.pooled_iter(TestIter::new(data_one).unwrap());
+        let mut iter_two = group.pooled_iter(TestIter::new(data_two).unwrap());
+        let in_use = || group.buffer_pool_size() - group.available_buffers();
+
+        let first = iter_one.next().unwrap();
+        let second = iter_two.next().unwrap();
+        let third = iter_one.next().unwrap();
+        assert_eq!((*first, *second, *third), (0, 1, 2));
+        assert_eq!(in_use(), 3);
+        assert_eq!(iter_two.available_buffers(), 0);
+
+        // Neither source can take a fourth buffer.
+        assert!(iter_one.try_next().is_err());
+        assert!(iter_two.try_next().is_err());
+        assert_eq!(in_use(), 3);
+
+        drop(first);
+        let fourth = iter_two.next().unwrap();
+        assert_eq!(*fourth, 3);
+        assert_eq!(in_use(), 3);
+        // `iter_one` only ever held two buffers at once, but the peak counts the whole group.
+        assert_eq!(iter_one.peak_buffers_used(), 3);
+        assert_eq!(iter_two.peak_buffers_used(), 3);
+
+        drop((second, third, fourth));
+        assert_eq!(in_use(), 0);
+        let mut remaining = vec![];
+        while let Some(item) = iter_one.next() {
+            remaining.push(*item);
+            let other = iter_two.next().unwrap();
+            remaining.push(*other);
+            assert!(in_use() <= 3);
+        }
+        assert_eq!(remaining, [4, 5, 6, 7]);
+    }
+
     #[test]
     fn seek_test() {
         let data: &[u8] = [0, 1, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 6, 7, 8, 9, 99].as_slice();
@@ -353,4 +705,127 @@ mod tests {
         iter.seek_before(&4);
         assert_eq!(*iter.current().unwrap(), 3);
     }
+
+    #[test]
+    fn reserved_buffers() {
+        let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
+        let mut iter = ThreadsafePooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 3);
+        let mut other_cursor = TestIter::new(data).unwrap();
+
+        let reserved = iter.with_reserved_buffers(2).unwrap();
+        assert_eq!(reserved.buffer_pool_size(), 2);
+        assert_eq!(iter.available_buffers(), 1);
+
+        // Only one buffer is left for `iter`, and it cannot take the reserved buffers.
+        let first = iter.next().unwrap();
+        assert_eq!(*first, 0);
+        assert!(iter.try_next().is_err());
+
+        // The reserved buffers are unaffected by `iter` exhausting its own buffers.
+        let reserved_first = reserved.try_next(&mut other_cursor).unwrap().unwrap();
+        let reserved_second = reserved.next(&mut other_cursor).unwrap();
+        assert_eq!((*reserved_first, *reserved_second), (0, 1));
+        assert!(reserved.try_next(&mut other_cursor).is_err());
+
+        // Returning a buffer to one consumer does not make it available to the other.
+        drop(reserved_first);
+        assert_eq!(reserved.available_buffers(), 1);
+        assert!(iter.try_next().is_err());
+        drop(first);
+        assert_eq!(iter.available_buffers(), 1);
+        assert_eq!(*reserved.next(&mut other_cursor).unwrap(), 2);
+
+        // Too many buffers cannot be reserved, and a failed reservation takes nothing.
+        assert!(iter.with_reserved_buffers(2).is_err());
+        assert_eq!(iter.available_buffers(), 1);
+
+        drop(reserved_second);
+        drop(reserved);
+        assert_eq!(iter.available_buffers(), 3);
+        assert_eq!(*iter.next().unwrap(), 1);
+    }
+
+    #[test]
+    fn reserved_buffers_keep_capacity() {
+        let data = [vec![0_u8; 64], vec![]];
+        let mut iter = ThreadsafePooledIter::<_, Vec<u8>>::new(
+            SortedSliceCursor::new(data.as_slice(), OrdComparator),
+            1,
+        );
+
+        // Grow the only buffer.
+        drop(iter.next());
+
+        let reserved = iter.with_reserved_buffers(1).unwrap();
+        let mut other_cursor = SortedSliceCursor::new(data.as_slice(), OrdComparator);
+        other_cursor.next();
+        let item = reserved.next(&mut other_cursor).unwrap();
+        assert!(item.is_empty());
+        assert!(item.capacity() >= 64);
+        drop((item, reserved));
+
+        let item = iter.next().unwrap();
+        assert!(item.is_empty());
+        assert!(item.capacity() >= 64);
+    }
+
+    #[test]
+    #[expect(clippy::default_numeric_fallback, reason = "the types of test literals do not matter")]
+    fn peak_buffers_used() {
+        let data: &[u8] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice();
+        let mut iter = ThreadsafePooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 4);
+        assert_eq!(iter.peak_buffers_used(), 0);
+
+        // Items dropped immediately only ever use one buffer.
+        for _ in 0..5 {
+            drop(iter.next());
+        }
+        assert_eq!(iter.peak_buffers_used(), 1);
+
+        let held = [iter.next(), iter.current(), iter.prev()];
+        assert_eq!(iter.peak_buffers_used(), 3);
+        drop(held);
+
+        // The peak is a maximum, so it is not lowered once the buffers are returned.
+        drop(iter.try_next());
+        assert_eq!(iter.peak_buffers_used(), 3);
+
+        let held = [iter.next(), iter.next(), iter.next(), iter.next()];
+        assert!(iter.try_current().is_err());
+        assert_eq!(iter.peak_buffers_used(), 4);
+        drop(held);
+    }
+
+    #[test]
+    fn producer_sends_full_union() {
+        let data_one: &'static [u8] = &[0, 2, 4, 6, 8];
+        let data_two: &'static [u8] = &[1, 3, 4, 9];
+        let merging = MergingIter::new(
+            vec![TestIter::new(data_one).unwrap(), TestIter::new(data_two).unwrap()],
+            OrdComparator,
+        );
+        let pooled = ThreadsafePooledIter::<_, u8>::new(merging, 2);
+
+        let (handle, receiver) = pooled.spawn_producer();
+        let union: Vec<u8> = receiver.iter().map(|item| *item).collect();
+        assert_eq!(union, [0, 1, 2, 3, 4, 4, 6, 8, 9]);
+
+        let pooled = handle.join().unwrap();
+        assert!(!pooled.valid());
+        assert!(pooled.peak_buffers_used() <= 2);
+        assert_eq!(pooled.available_buffers(), 2);
+    }
+
+    #[test]
+    fn producer_stops_when_receiver_dropped() {
+        let data: &'static [u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let pooled = ThreadsafePooledIter::<_, u8>::new(TestIter::new(data).unwrap(), 1);
+
+        let (handle, receiver) = pooled.spawn_producer();
+        assert_eq!(receiver.recv().map(|item| *item), Ok(0));
+        drop(receiver);
+
+        let pooled = handle.join().unwrap();
+        assert_eq!(pooled.available_buffers(), 1);
+    }
 }
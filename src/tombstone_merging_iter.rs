@@ -0,0 +1,237 @@
+use core::fmt::{Debug, Formatter, Result as FmtResult};
+use alloc::borrow::ToOwned;
+
+use crate::{comparator::Comparator, distinct_keys_iter::DistinctKeysIter};
+use crate::{lending_iterator_support::LentItem, merging_iter::MergingIter};
+use crate::{seekable::ItemToKey, seekable_iterators::SeekableLendingIterator};
+
+
+/// An adapter for [`MergingIter`] which omits keys that were deleted by tombstones.
+///
+/// When iterating forwards, only the highest-priority item of each group of items with equal
+/// keys is yielded, and keys whose highest-priority item is a tombstone are skipped.
+///
+/// This provides the semantics of an LSM-tree, where a tombstone marks its key as deleted in
+/// every lower-priority source. Priority is given by source index: among items with equal keys,
+/// the item from the lowest-indexed source (in the current order of the sources; see
+/// [`MergingIter::current_source`]) takes precedence, as with [`DistinctKeysIter`]. If that item
+/// is a tombstone (as determined by the provided `is_tombstone` function), neither it nor any of
+/// the items it shadows are yielded.
+///
+/// A tombstone in a lower-priority source has no effect if a higher-priority source contains an
+/// item with the same key.
+///
+/// This adapter only supports forwards iteration.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct TombstoneMergingIter<Key: ?Sized + ToOwned, Cmp, Iter, F> {
+    distinct:     DistinctKeysIter<Key, Cmp, Iter>,
+    is_tombstone: F,
+}
+
+impl<Key, Cmp, Iter, F> TombstoneMergingIter<Key, Cmp, Iter, F>
+where
+    Key:  ?Sized + ToOwned,
+    Cmp:  Comparator<Key>,
+    Iter: SeekableLendingIterator<Key, Cmp> + ItemToKey<Key>,
+    F:    FnMut(&LentItem<'_, Iter>) -> bool,
+{
+    /// Wrap the provided [`MergingIter`], using `is_tombstone` to determine which items are
+    /// tombstones.
+    ///
+    /// The adapter starts at the same position as `merging`, even if `merging` is at a
+    /// tombstone or at a shadowed item; use [`seek_to_first`] or [`next`] to move to the first
+    /// live entry.
+    ///
+    /// [`seek_to_first`]: TombstoneMergingIter::seek_to_first
+    /// [`next`]: TombstoneMergingIter::next
+    #[must_use]
+    pub fn new(
+        merging:      MergingIter<Key, Cmp, Iter>,
+        is_tombstone: F,
+    ) -> Self {
+        Self {
+            distinct: merging.distinct_keys(),
+            is_tombstone,
+        }
+    }
+
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    ///
+    /// [`CursorLendingIterator::valid()`]: crate::CursorLendingIterator::valid
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        self.distinct.valid()
+    }
+
+    /// Get the current value the iterator is at.
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    ///
+    /// [`CursorLendingIterator::current()`]: crate::CursorLendingIterator::current
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, Iter>> {
+        self.distinct.current()
+    }
+
+    /// Move the iterator forwards to the first live item whose key is strictly greater than the
+    /// current key, and return that item. Deleted keys are skipped over.
+    ///
+    /// As with [`CursorLendingIterator::next()`], if the iterator is not valid, it moves to the
+    /// first live entry of the collection; and if there is no such entry, the iterator becomes
+    /// `!valid()` and `None` is returned.
+    ///
+    /// [`CursorLendingIterator::next()`]: crate::CursorLendingIterator::next
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, Iter>> {
+        self.distinct.next();
+        self.skip_tombstones();
+        self.distinct.current()
+    }
+
+    /// Move the iterator to the smallest live key which is greater or equal than the provided
+    /// `min_bound`.
+    ///
+    /// See [`Seekable::seek`].
+    ///
+    /// [`Seekable::seek`]: crate::Seekable::seek
+    pub fn seek(&mut self, min_bound: &Key) {
+        self.distinct.seek(min_bound);
+        self.skip_tombstones();
+    }
+
+    /// Move the iterator to the smallest live key in the collection.
+    ///
+    /// See [`Seekable::seek_to_first`].
+    ///
+    /// [`Seekable::seek_to_first`]: crate::Seekable::seek_to_first
+    pub fn seek_to_first(&mut self) {
+        self.distinct.seek_to_first();
+        self.skip_tombstones();
+    }
+
+    /// Reset the iterator to its initial position, before the first entry and after the last
+    /// entry (if there are any entries in the collection).
+    ///
+    /// See [`Seekable::reset`].
+    ///
+    /// [`Seekable::reset`]: crate::Seekable::reset
+    pub fn reset(&mut self) {
+        self.distinct.reset();
+    }
+
+    /// Get a reference to the inner [`MergingIter`].
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &MergingIter<Key, Cmp, Iter> {
+        self.distinct.inner()
+    }
+
+    /// Convert the adapter back into the inner [`MergingIter`].
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> MergingIter<Key, Cmp, Iter> {
+        self.distinct.into_inner()
+    }
+
+    /// While the current item is a tombstone, move forwards to the next distinct key.
+    fn skip_tombstones(&mut self) {
+        while self.distinct.current().is_some_and(|item| (self.is_tombstone)(&item)) {
+            self.distinct.next();
+        }
+    }
+}
+
+impl<Key, Cmp, Iter, F> Debug for TombstoneMergingIter<Key, Cmp, Iter, F>
+where
+    Key:        ?Sized + ToOwned + Debug,
+    Key::Owned: Debug,
+    Cmp:        Debug,
+    Iter:       Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TombstoneMergingIter")
+            .field("distinct", &self.distinct)
+            .field("is_tombstone", &"..")
+            .finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use core::borrow::Borrow;
+
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use super::*;
+
+
+    /// An entry keyed by `key`, which is either a value or a tombstone.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Entry {
+        key:   u8,
+        value: Option<char>,
+    }
+
+    impl Borrow<u8> for Entry {
+        fn borrow(&self) -> &u8 {
+            &self.key
+        }
+    }
+
+    const fn live(key: u8, value: char) -> Entry {
+        Entry { key, value: Some(value) }
+    }
+
+    const fn tombstone(key: u8) -> Entry {
+        Entry { key, value: None }
+    }
+
+    type Source<'a> = SortedSliceCursor<'a, Entry, OrdComparator>;
+
+    fn merge(
+        sources: [&[Entry]; 2],
+    ) -> TombstoneMergingIter<u8, OrdComparator, Source<'_>, impl FnMut(&&Entry) -> bool> {
+        let sources = sources
+            .into_iter()
+            .map(|data| SortedSliceCursor::new(data, OrdComparator))
+            .collect();
+        TombstoneMergingIter::new(
+            MergingIter::new(sources, OrdComparator),
+            |entry: &&Entry| entry.value.is_none(),
+        )
+    }
+
+    #[test]
+    fn tombstones_hide_lower_priority_values() {
+        let newest = [tombstone(2), live(3, 'c'), tombstone(5)];
+        let oldest = [live(1, 'a'), live(2, 'b'), live(3, 'x'), live(4, 'd'), live(5, 'e')];
+        let mut iter = merge([&newest, &oldest]);
+
+        for expected in [live(1, 'a'), live(3, 'c'), live(4, 'd')] {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.next(), None);
+        assert!(!iter.valid());
+
+        iter.seek(&2);
+        assert_eq!(iter.current(), Some(&live(3, 'c')));
+        iter.seek(&5);
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn lower_priority_tombstones_are_shadowed() {
+        let newest = [live(1, 'a'), live(2, 'b')];
+        let oldest = [tombstone(1), tombstone(3)];
+        let mut iter = merge([&newest, &oldest]);
+
+        iter.seek_to_first();
+        assert_eq!(iter.current(), Some(&live(1, 'a')));
+        assert_eq!(iter.next(), Some(&live(2, 'b')));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,495 @@
+use core::marker::PhantomData;
+use alloc::vec::Vec;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator, merge_core};
+use crate::lending_iterator_support::{LendItem, LentItem};
+use crate::{seekable::ItemToKey, try_seekable::TrySeekable};
+
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Forwards,
+    Backwards,
+    /// A switch of direction failed partway through, so the non-`current_iter` iterators must
+    /// be repositioned before moving in either direction.
+    Unsettled,
+}
+
+/// A `TryMergingIter` takes several sources whose seeks may fail, and iterates over the sorted
+/// union of their entries.
+///
+/// This is a version of [`MergingIter`] for sources which implement [`TrySeekable`] instead of
+/// [`Seekable`]. The sources may have overlap in their keys, and can be provided in any order;
+/// the comparators used by each source must all behave identically to each other and to the
+/// provided `cmp` value.
+///
+/// Switching between forwards and backwards iteration requires seeking every source, so
+/// [`try_next`] and [`try_prev`] may fail as well. As with a [`MergingIter`] in its default mode,
+/// items with duplicate keys may be skipped when switching direction. The merging logic itself is
+/// shared with [`MergingIter`].
+///
+/// The seeking methods mirror those of [`TrySeekable`], but are inherent methods, since every
+/// [`Seekable`] type already implements [`TrySeekable`].
+///
+/// # Errors
+/// If a source returns an error, the error is returned immediately.
+///
+/// If [`try_next`] or [`try_prev`] fails while switching direction, only the other sources
+/// were being repositioned, so the `TryMergingIter` stays at its current entry; the call can
+/// simply be retried, and the next successful move (in either direction) repositions the other
+/// sources first.
+///
+/// If a seek fails (including the seek to the first or last entry made by [`try_next`] or
+/// [`try_prev`] when the `TryMergingIter` is not valid), the `TryMergingIter` becomes `!valid()`,
+/// and its previous position is lost: it must be repositioned with a seek, and a following
+/// [`try_next`] starts over from the first entry.
+///
+/// [`MergingIter`]: crate::MergingIter
+/// [`Seekable`]: crate::Seekable
+/// [`try_next`]: TryMergingIter::try_next
+/// [`try_prev`]: TryMergingIter::try_prev
+#[derive(Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct TryMergingIter<Key: ?Sized, Cmp, Iter> {
+    iterators:    Vec<Iter>,
+    cmp:          Cmp,
+    _key:         PhantomData<Key>,
+    /// The index of the current iterator, which is `valid()` if this is `Some`.
+    ///
+    /// If `None`, the positions of the iterators in `self.iterators` are unspecified.
+    /// Otherwise, the `current_iter` iterator is only moved by `try_next`, `try_prev`, and seeks,
+    /// and never by a failed direction switch.
+    current_iter: Option<usize>,
+    /// If `current_iter` is `Some` and `direction` is `Forwards`, then the non-`current_iter`
+    /// iterators are in front of `current_iter`. If `Backwards`, the non-`current_iter`
+    /// iterators are behind `current_iter`.
+    direction:    Direction,
+}
+
+impl<Key, Cmp, Iter> TryMergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: CursorLendingIterator + ItemToKey<Key> + TrySeekable<Key, Cmp>,
+{
+    /// Create a new [`TryMergingIter`], which starts out `!valid()`.
+    ///
+    /// See [`MergingIter::new`] for the requirements on the comparators.
+    ///
+    /// [`MergingIter::new`]: crate::MergingIter::new
+    #[inline]
+    #[must_use]
+    pub const fn new(iterators: Vec<Iter>, cmp: Cmp) -> Self {
+        Self {
+            iterators,
+            cmp,
+            _key:         PhantomData,
+            current_iter: None,
+            direction:    Direction::Forwards,
+        }
+    }
+
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    #[inline]
+    #[must_use]
+    pub const fn valid(&self) -> bool {
+        self.current_iter.is_some()
+    }
+
+    /// Get the current value the iterator is at, if the iterator is [valid].
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    ///
+    /// [valid]: TryMergingIter::valid
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, Iter>> {
+        self.iterators.get(self.current_iter?)?.current()
+    }
+
+    /// Get the index of the source which the current entry comes from, if the iterator is
+    /// [valid].
+    ///
+    /// [valid]: TryMergingIter::valid
+    #[inline]
+    #[must_use]
+    pub const fn current_source(&self) -> Option<usize> {
+        self.current_iter
+    }
+
+    /// Get the comparator used to merge the sources.
+    #[inline]
+    #[must_use]
+    pub const fn comparator(&self) -> &Cmp {
+        &self.cmp
+    }
+
+    /// Convert the iterator back into its sources, in the order they were provided.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> Vec<Iter> {
+        self.iterators
+    }
+
+    /// Move the iterator one position forwards, and return the entry at that position.
+    /// Returns `Ok(None)` if the iterator was at the last entry.
+    ///
+    /// If the iterator is not valid, it moves to the first entry of the collection.
+    ///
+    /// # Errors
+    /// Returns an error if a source failed to seek, which may happen if the iterator was not
+    /// valid or was not last moved forwards. If the iterator was valid, it stays at its current
+    /// entry; otherwise, it stays `!valid()`. See the type-level documentation.
+    pub fn try_next(&mut self) -> Result<Option<LentItem<'_, Iter>>, Iter::Error> {
+        if let Some(current_idx) = self.current_iter {
+            if !matches!(self.direction, Direction::Forwards) {
+                self.direction = Direction::Unsettled;
+                merge_core::switch_to_forwards(
+                    &mut self.iterators,
+                    &self.cmp,
+                    current_idx,
+                    false,
+                    Iter::try_seek,
+                )?;
+                self.direction = Direction::Forwards;
+            }
+
+            if let Some(current_iter) = self.iterators.get_mut(current_idx) {
+                current_iter.next();
+            }
+            self.current_iter = merge_core::find_smallest(&self.iterators, &self.cmp, |_| true);
+        } else {
+            self.try_seek_to_first()?;
+        }
+
+        Ok(self.current())
+    }
+
+    /// Move the iterator one position backwards, and return the entry at that position.
+    /// Returns `Ok(None)` if the iterator was at the first entry.
+    ///
+    /// If the iterator is not valid, it moves to the last entry of the collection.
+    ///
+    /// # Errors
+    /// Returns an error if a source failed to seek, which may happen if the iterator was not
+    /// valid or was not last moved backwards. If the iterator was valid, it stays at its current
+    /// entry; otherwise, it stays `!valid()`. See the type-level documentation.
+    pub fn try_prev(&mut self) -> Result<Option<LentItem<'_, Iter>>, Iter::Error> {
+        if let Some(current_idx) = self.current_iter {
+            if !matches!(self.direction, Direction::Backwards) {
+                self.direction = Direction::Unsettled;
+                merge_core::switch_to_backwards(
+                    &mut self.iterators,
+                    &self.cmp,
+                    current_idx,
+                    false,
+                    Iter::try_seek_before,
+                )?;
+                self.direction = Direction::Backwards;
+            }
+
+            if let Some(current_iter) = self.iterators.get_mut(current_idx) {
+                current_iter.prev();
+            }
+            self.current_iter = merge_core::find_largest(&self.iterators, &self.cmp);
+        } else {
+            self.try_seek_to_last()?;
+        }
+
+        Ok(self.current())
+    }
+}
+
+impl<'lend, Key, Cmp, Iter> LendItem<'lend> for TryMergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Iter: LendItem<'lend>,
+{
+    type Item = Iter::Item;
+}
+
+impl<Key, Cmp, Iter> ItemToKey<Key> for TryMergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Iter: ItemToKey<Key>,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        Iter::item_to_key(item)
+    }
+}
+
+impl<Key, Cmp, Iter> TryMergingIter<Key, Cmp, Iter>
+where
+    Key:  ?Sized,
+    Cmp:  Comparator<Key>,
+    Iter: CursorLendingIterator + ItemToKey<Key> + TrySeekable<Key, Cmp>,
+{
+    /// Reset the iterator to its initial position, before the first entry and after the last
+    /// entry (if there are any entries in the collection).
+    ///
+    /// # Errors
+    /// Returns the first error returned by a source's [`TrySeekable::try_reset`]. The iterator
+    /// is `!valid()` afterwards, regardless of errors.
+    pub fn try_reset(&mut self) -> Result<(), Iter::Error> {
+        self.current_iter = None;
+        self.direction = Direction::Forwards;
+        for iter in &mut self.iterators {
+            iter.try_reset()?;
+        }
+        Ok(())
+    }
+
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`.
+    ///
+    /// See [`TrySeekable::try_seek`].
+    ///
+    /// # Errors
+    /// Returns the first error returned by a source's [`TrySeekable::try_seek`]. The iterator is
+    /// then `!valid()`.
+    pub fn try_seek(&mut self, min_bound: &Key) -> Result<(), Iter::Error> {
+        self.current_iter = None;
+        for iter in &mut self.iterators {
+            iter.try_seek(min_bound)?;
+        }
+
+        self.current_iter = merge_core::find_smallest(&self.iterators, &self.cmp, |_| true);
+        self.direction = Direction::Forwards;
+        Ok(())
+    }
+
+    /// Move the iterator to the greatest key which is strictly less than the provided
+    /// `strict_upper_bound`.
+    ///
+    /// See [`TrySeekable::try_seek_before`].
+    ///
+    /// # Errors
+    /// Returns the first error returned by a source's [`TrySeekable::try_seek_before`]. The
+    /// iterator is then `!valid()`.
+    pub fn try_seek_before(&mut self, strict_upper_bound: &Key) -> Result<(), Iter::Error> {
+        self.current_iter = None;
+        for iter in &mut self.iterators {
+            iter.try_seek_before(strict_upper_bound)?;
+        }
+
+        self.current_iter = merge_core::find_largest(&self.iterators, &self.cmp);
+        self.direction = Direction::Backwards;
+        Ok(())
+    }
+
+    /// Move the iterator to the smallest key in the collection.
+    ///
+    /// See [`TrySeekable::try_seek_to_first`].
+    ///
+    /// # Errors
+    /// Returns the first error returned by a source's [`TrySeekable::try_seek_to_first`]. The
+    /// iterator is then `!valid()`.
+    pub fn try_seek_to_first(&mut self) -> Result<(), Iter::Error> {
+        self.current_iter = None;
+        for iter in &mut self.iterators {
+            iter.try_seek_to_first()?;
+        }
+
+        self.current_iter = merge_core::find_smallest(&self.iterators, &self.cmp, |_| true);
+        self.direction = Direction::Forwards;
+        Ok(())
+    }
+
+    /// Move the iterator to the greatest key in the collection.
+    ///
+    /// See [`TrySeekable::try_seek_to_last`].
+    ///
+    /// # Errors
+    /// Returns the first error returned by a source's [`TrySeekable::try_seek_to_last`]. The
+    /// iterator is then `!valid()`.
+    pub fn try_seek_to_last(&mut self) -> Result<(), Iter::Error> {
+        self.current_iter = None;
+        for iter in &mut self.iterators {
+            iter.try_seek_to_last()?;
+        }
+
+        self.current_iter = merge_core::find_largest(&self.iterators, &self.cmp);
+        self.direction = Direction::Backwards;
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use alloc::vec;
+    use core::convert::Infallible;
+
+    use crate::{comparator::OrdComparator, seekable::Seekable as _, test_iter::TestIter};
+    use super::*;
+
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct SeekError(u8);
+
+    /// A source which fails to seek to the bound `fail_at`, once.
+    struct FlakySource {
+        iter:    TestIter<'static>,
+        fail_at: Option<u8>,
+    }
+
+    impl<'lend> LendItem<'lend> for FlakySource {
+        type Item = &'lend u8;
+    }
+
+    impl CursorLendingIterator for FlakySource {
+        fn valid(&self) -> bool {
+            self.iter.valid()
+        }
+
+        fn next(&mut self) -> Option<LentItem<'_, Self>> {
+            self.iter.next()
+        }
+
+        fn current(&self) -> Option<LentItem<'_, Self>> {
+            self.iter.current()
+        }
+
+        fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+            self.iter.prev()
+        }
+    }
+
+    impl ItemToKey<u8> for FlakySource {
+        fn item_to_key(item: LentItem<'_, Self>) -> &'_ u8 {
+            item
+        }
+    }
+
+    impl TrySeekable<u8, OrdComparator> for FlakySource {
+        type Error = SeekError;
+
+        fn try_reset(&mut self) -> Result<(), Self::Error> {
+            self.iter.reset();
+            Ok(())
+        }
+
+        fn try_seek(&mut self, min_bound: &u8) -> Result<(), Self::Error> {
+            if self.fail_at == Some(*min_bound) {
+                self.fail_at = None;
+                return Err(SeekError(*min_bound));
+            }
+            self.iter.seek(min_bound);
+            Ok(())
+        }
+
+        fn try_seek_before(&mut self, strict_upper_bound: &u8) -> Result<(), Self::Error> {
+            if self.fail_at == Some(*strict_upper_bound) {
+                self.fail_at = None;
+                return Err(SeekError(*strict_upper_bound));
+            }
+            self.iter.seek_before(strict_upper_bound);
+            Ok(())
+        }
+
+        fn try_seek_to_first(&mut self) -> Result<(), Self::Error> {
+            self.iter.seek_to_first();
+            Ok(())
+        }
+
+        fn try_seek_to_last(&mut self) -> Result<(), Self::Error> {
+            self.iter.seek_to_last();
+            Ok(())
+        }
+    }
+
+    fn flaky(data: &'static [u8], fail_at: Option<u8>) -> FlakySource {
+        FlakySource {
+            iter: TestIter::new(data).unwrap(),
+            fail_at,
+        }
+    }
+
+    #[test]
+    #[expect(
+        clippy::assertions_on_result_states,
+        reason = "only whether the call succeeds is checked",
+    )]
+    fn infallible_sources() {
+        let mut iter = TryMergingIter::new(
+            vec![
+                TestIter::new(&[1, 4, 7]).unwrap(),
+                TestIter::new(&[2, 5]).unwrap(),
+                TestIter::new(&[3, 6]).unwrap(),
+            ],
+            OrdComparator,
+        );
+
+        for expected in 1..=7 {
+            assert_eq!(iter.try_next(), Ok(Some(&expected)));
+        }
+        assert_eq!(iter.try_next(), Ok(None));
+
+        let result: Result<(), Infallible> = iter.try_seek(&5);
+        assert!(result.is_ok());
+        assert_eq!(iter.current(), Some(&5));
+        assert_eq!(iter.current_source(), Some(1));
+        assert_eq!(iter.try_prev(), Ok(Some(&4)));
+        assert_eq!(iter.try_next(), Ok(Some(&5)));
+    }
+
+    #[test]
+    fn seek_error_propagates() {
+        let mut iter = TryMergingIter::new(
+            vec![flaky(&[1, 3, 5], None), flaky(&[2, 4, 6], Some(4))],
+            OrdComparator,
+        );
+
+        assert_eq!(iter.try_seek(&3), Ok(()));
+        assert_eq!(iter.current(), Some(&3));
+
+        assert_eq!(iter.try_seek(&4), Err(SeekError(4)));
+        assert!(!iter.valid());
+
+        // The iterator can be repositioned after an error.
+        assert_eq!(iter.try_seek_to_first(), Ok(()));
+        assert_eq!(iter.current(), Some(&1));
+    }
+
+    #[test]
+    fn direction_switch_error_propagates() {
+        let mut iter = TryMergingIter::new(
+            vec![flaky(&[1, 3, 5], None), flaky(&[2, 4, 6], Some(3))],
+            OrdComparator,
+        );
+
+        assert_eq!(iter.try_seek_to_first(), Ok(()));
+        for expected in [2, 3, 4] {
+            assert_eq!(iter.try_next(), Ok(Some(&expected)));
+        }
+        // Switching to backwards iteration seeks the other source before `4`, which succeeds.
+        assert_eq!(iter.try_prev(), Ok(Some(&3)));
+        // Switching back to forwards iteration seeks the second source to `3`, which fails.
+        assert_eq!(iter.try_next(), Err(SeekError(3)));
+        // The iterator stays at its current entry, and retrying picks up from there.
+        assert_eq!(iter.current(), Some(&3));
+        assert_eq!(iter.try_next(), Ok(Some(&4)));
+        assert_eq!(iter.try_next(), Ok(Some(&5)));
+    }
+
+    #[test]
+    fn failed_switch_is_redone_in_either_direction() {
+        let mut iter = TryMergingIter::new(
+            vec![flaky(&[1, 3, 5], None), flaky(&[2, 4, 6], Some(3))],
+            OrdComparator,
+        );
+
+        assert_eq!(iter.try_seek(&4), Ok(()));
+        assert_eq!(iter.try_prev(), Ok(Some(&3)));
+        // Switching forwards fails...
+        assert_eq!(iter.try_next(), Err(SeekError(3)));
+        assert_eq!(iter.current(), Some(&3));
+        // ...so moving backwards instead must reposition the other source again.
+        assert_eq!(iter.try_prev(), Ok(Some(&2)));
+        assert_eq!(iter.try_prev(), Ok(Some(&1)));
+        assert_eq!(iter.try_prev(), Ok(None));
+    }
+}
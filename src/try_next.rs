@@ -0,0 +1,37 @@
+use core::{error::Error, fmt::{Display, Formatter, Result as FmtResult}};
+
+use crate::{cursor::CursorLendingIterator, lending_iterator_support::LentItem};
+
+
+/// The error returned when a source cannot currently move forwards without blocking.
+///
+/// See [`TryNext`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotReady;
+
+impl Display for NotReady {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("the source is not ready to move forwards")
+    }
+}
+
+impl Error for NotReady {}
+
+/// A non-blocking version of [`CursorLendingIterator::next`], for sources whose `next` may
+/// block (for instance, while waiting on I/O or another thread).
+///
+/// This is used by [`MergingIter::try_next`] to make progress on the sources which are ready,
+/// instead of blocking on a slow source.
+///
+/// [`MergingIter::try_next`]: crate::MergingIter::try_next
+pub trait TryNext: CursorLendingIterator {
+    /// Attempt to move the iterator one position forwards, and return the entry at that position,
+    /// without blocking.
+    ///
+    /// On success, this behaves exactly like [`CursorLendingIterator::next`].
+    ///
+    /// # Errors
+    /// Returns [`NotReady`] if the iterator could not move forwards without blocking. The
+    /// position of the iterator is then unchanged, and the call may be retried later.
+    fn try_next(&mut self) -> Result<Option<LentItem<'_, Self>>, NotReady>;
+}
@@ -0,0 +1,99 @@
+use core::convert::Infallible;
+
+use crate::{comparator::Comparator, seekable::Seekable};
+
+
+/// A fallible version of [`Seekable`], for sources where positioning the iterator can fail (for
+/// instance, due to I/O errors).
+///
+/// Each method corresponds to a method of [`Seekable`], and has the same behavior on success. If
+/// an error is returned, the position of the iterator is unspecified, though it should be
+/// possible to reposition the iterator with a later call.
+///
+/// Every [`Seekable`] type implements `TrySeekable` with an [`Infallible`] error type.
+pub trait TrySeekable<Key: ?Sized, Cmp: ?Sized + Comparator<Key>> {
+    /// The error which may occur while positioning the iterator.
+    type Error;
+
+    /// Attempt to reset the iterator to its initial position, before the first entry and after
+    /// the last entry (if there are any entries in the collection).
+    ///
+    /// See [`Seekable::reset`].
+    ///
+    /// # Errors
+    /// Returns an error if the iterator could not be repositioned.
+    fn try_reset(&mut self) -> Result<(), Self::Error>;
+
+    /// Attempt to move the iterator to the smallest key which is greater or equal than the
+    /// provided `min_bound`.
+    ///
+    /// See [`Seekable::seek`].
+    ///
+    /// # Errors
+    /// Returns an error if the iterator could not be repositioned.
+    fn try_seek(&mut self, min_bound: &Key) -> Result<(), Self::Error>;
+
+    /// Attempt to move the iterator to the greatest key which is strictly less than the provided
+    /// `strict_upper_bound`.
+    ///
+    /// See [`Seekable::seek_before`].
+    ///
+    /// # Errors
+    /// Returns an error if the iterator could not be repositioned.
+    fn try_seek_before(&mut self, strict_upper_bound: &Key) -> Result<(), Self::Error>;
+
+    /// Attempt to move the iterator to the smallest key in the collection.
+    ///
+    /// See [`Seekable::seek_to_first`].
+    ///
+    /// # Errors
+    /// Returns an error if the iterator could not be repositioned.
+    fn try_seek_to_first(&mut self) -> Result<(), Self::Error>;
+
+    /// Attempt to move the iterator to the greatest key in the collection.
+    ///
+    /// See [`Seekable::seek_to_last`].
+    ///
+    /// # Errors
+    /// Returns an error if the iterator could not be repositioned.
+    fn try_seek_to_last(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<Key, Cmp, S> TrySeekable<Key, Cmp> for S
+where
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+    S:   ?Sized + Seekable<Key, Cmp>,
+{
+    type Error = Infallible;
+
+    #[inline]
+    fn try_reset(&mut self) -> Result<(), Self::Error> {
+        self.reset();
+        Ok(())
+    }
+
+    #[inline]
+    fn try_seek(&mut self, min_bound: &Key) -> Result<(), Self::Error> {
+        self.seek(min_bound);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_seek_before(&mut self, strict_upper_bound: &Key) -> Result<(), Self::Error> {
+        self.seek_before(strict_upper_bound);
+        Ok(())
+    }
+
+    #[inline]
+    fn try_seek_to_first(&mut self) -> Result<(), Self::Error> {
+        self.seek_to_first();
+        Ok(())
+    }
+
+    #[inline]
+    fn try_seek_to_last(&mut self) -> Result<(), Self::Error> {
+        self.seek_to_last();
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use core::borrow::Borrow as _;
+use alloc::{borrow::ToOwned, collections::VecDeque};
+
+use crate::{comparator::{keys_equal, Comparator}, cursor::CursorLendingIterator};
+use crate::{lending_iterator_support::LentItem, seekable::{ItemToKey, Seekable}};
+
+
+/// An adapter for a [`CursorLendingIterator`] which, when iterating forwards, skips items whose
+/// keys are equal to the key of one of the last `window` items yielded.
+///
+/// Unlike [`DedupIter`], which only collapses adjacent items with equal keys, this collapses
+/// repeated keys that are near each other in a stream which need not be sorted, while keeping
+/// a key that reappears once it has fallen out of the window. Each yielded key enters the window,
+/// evicting the oldest key if the window is full; skipped items do not enter the window.
+///
+/// Owned copies of the keys in the window are kept in a ring buffer. Keys are considered equal if
+/// the provided comparator says so. A window of `0` yields every item.
+///
+/// This adapter only supports forwards iteration.
+///
+/// [`DedupIter`]: crate::DedupIter
+#[derive(Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct TtlDedupIter<I, Key: ?Sized + ToOwned, Cmp> {
+    iter:        I,
+    cmp:         Cmp,
+    window:      usize,
+    /// The keys of the most recently yielded items, oldest first. Holds at most `window` keys.
+    recent_keys: VecDeque<Key::Owned>,
+}
+
+impl<I, Key, Cmp> TtlDedupIter<I, Key, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key>,
+    Key: ?Sized + ToOwned,
+    Cmp: Comparator<Key>,
+{
+    /// Wrap the provided iterator, whose keys are compared with `cmp`, remembering the keys of
+    /// the last `window` items yielded.
+    ///
+    /// If the iterator is currently valid, its current key is the first key in the window.
+    #[must_use]
+    pub fn new(iter: I, cmp: Cmp, window: usize) -> Self {
+        let mut this = Self {
+            iter,
+            cmp,
+            window,
+            recent_keys: VecDeque::with_capacity(window),
+        };
+        this.record_current_key();
+        this
+    }
+
+    /// Get the number of recently-yielded keys which are remembered.
+    #[inline]
+    #[must_use]
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Determine whether the iterator is currently at any value in the collection.
+    ///
+    /// See [`CursorLendingIterator::valid()`].
+    #[inline]
+    #[must_use]
+    pub fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    /// Get the current value the iterator is at.
+    ///
+    /// See [`CursorLendingIterator::current()`].
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<LentItem<'_, I>> {
+        self.iter.current()
+    }
+
+    /// Move the iterator forwards to the next item whose key is not in the window, and return
+    /// that item.
+    ///
+    /// As with [`CursorLendingIterator::next()`], if the iterator is not valid, it moves to the
+    /// first entry of the collection; and if there is no such item, the iterator becomes
+    /// `!valid()` and `None` is returned. The window is kept when the end is reached.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<LentItem<'_, I>> {
+        loop {
+            self.iter.next();
+            let item = self.iter.current()?;
+
+            let key = I::item_to_key(item);
+            let is_recent = self.recent_keys.iter().any(|recent_key| {
+                keys_equal(&self.cmp, recent_key.borrow(), key)
+            });
+
+            if !is_recent {
+                break;
+            }
+        }
+
+        self.record_current_key();
+        self.iter.current()
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Push an owned copy of the current key into the window, if the iterator is valid,
+    /// evicting the oldest key if the window is full.
+    fn record_current_key(&mut self) {
+        if self.window == 0 {
+            return;
+        }
+        let Some(item) = self.iter.current() else {
+            return;
+        };
+        let key = I::item_to_key(item);
+
+        let evicted = if self.recent_keys.len() < self.window {
+            None
+        } else {
+            self.recent_keys.pop_front()
+        };
+
+        if let Some(mut oldest) = evicted {
+            key.clone_into(&mut oldest);
+            self.recent_keys.push_back(oldest);
+        } else {
+            self.recent_keys.push_back(key.to_owned());
+        }
+    }
+}
+
+impl<I, Key, Cmp> TtlDedupIter<I, Key, Cmp>
+where
+    I:   CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized + ToOwned,
+    Cmp: Comparator<Key>,
+{
+    /// Move the iterator to the smallest key which is greater or equal than the provided
+    /// `min_bound`. The window is cleared, and then holds only the new current key.
+    ///
+    /// See [`Seekable::seek`].
+    pub fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.recent_keys.clear();
+        self.record_current_key();
+    }
+
+    /// Reset the iterator to its initial position, before the first entry and after the last
+    /// entry (if there are any entries in the collection). The window is cleared.
+    ///
+    /// See [`Seekable::reset`].
+    pub fn reset(&mut self) {
+        self.iter.reset();
+        self.recent_keys.clear();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    use super::*;
+
+
+    /// An unsorted stream of keys, which is only iterated forwards.
+    fn stream(data: &[u8]) -> SortedSliceCursor<'_, u8, OrdComparator> {
+        SortedSliceCursor::new(data, OrdComparator)
+    }
+
+    #[test]
+    fn repeats_within_window_are_dropped() {
+        let data = [1, 2, 1, 3, 4, 5, 1, 1, 2];
+        let mut iter = TtlDedupIter::new(stream(&data), OrdComparator, 3);
+
+        // The second `1` is within the window of `[1, 2]`, but the third `1` comes after
+        // `3, 4, 5` have pushed it out of the window.
+        for expected in [1, 2, 3, 4, 5, 1, 2] {
+            assert_eq!(iter.next(), Some(&expected));
+        }
+        assert_eq!(iter.next(), None);
+        assert!(!iter.valid());
+    }
+
+    #[test]
+    fn window_size_controls_dedup() {
+        let data = [7, 8, 7, 8, 7];
+
+        let mut pairwise = TtlDedupIter::new(stream(&data), OrdComparator, 2);
+        assert_eq!(pairwise.next(), Some(&7));
+        assert_eq!(pairwise.next(), Some(&8));
+        assert_eq!(pairwise.next(), None);
+
+        let mut adjacent = TtlDedupIter::new(stream(&data), OrdComparator, 1);
+        for expected in data {
+            assert_eq!(adjacent.next(), Some(&expected));
+        }
+
+        let mut disabled = TtlDedupIter::new(stream(&[4, 4]), OrdComparator, 0);
+        assert_eq!(disabled.next(), Some(&4));
+        assert_eq!(disabled.next(), Some(&4));
+        assert_eq!(disabled.next(), None);
+    }
+}
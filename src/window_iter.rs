@@ -0,0 +1,211 @@
+use core::cmp::Ordering;
+
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// A cursor over the entries of a parent iterator whose keys are in the range `lower..upper`,
+/// borrowing the parent iterator.
+///
+/// Like any cursor, the window is circular, and is initially `!valid()`; the first call to
+/// `next()` moves to the first entry in the window. Each motion of the window moves the parent
+/// iterator.
+///
+/// When the window is dropped, the parent iterator is left wherever the window moved it. In
+/// particular, after iterating forwards through the whole window (until `next()` returns
+/// `None`), the parent is left at the first entry whose key is at or after `upper`, if any, so
+/// that an adjacent window starting at `upper` can be processed next.
+///
+/// Created by [`SeekableLendingIteratorExt::window`].
+///
+/// [`SeekableLendingIteratorExt::window`]: crate::SeekableLendingIteratorExt::window
+#[derive(Debug)]
+pub struct WindowIter<'a, I: ?Sized, Key: ?Sized, Cmp: ?Sized> {
+    iter:     &'a mut I,
+    cmp:      &'a Cmp,
+    lower:    &'a Key,
+    upper:    &'a Key,
+    valid:    bool,
+    /// Whether `iter` is still at the position it was sought to by `WindowIter::new`, so that
+    /// it need not be sought again on the first call to `next`.
+    at_lower: bool,
+}
+
+impl<'a, I, Key, Cmp> WindowIter<'a, I, Key, Cmp>
+where
+    I:   ?Sized + CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    /// Seek `iter` to `lower`, and create a window over the keys in `lower..upper`.
+    #[must_use]
+    pub(crate) fn new(iter: &'a mut I, cmp: &'a Cmp, lower: &'a Key, upper: &'a Key) -> Self {
+        iter.seek(lower);
+
+        Self {
+            iter,
+            cmp,
+            lower,
+            upper,
+            valid:    false,
+            at_lower: true,
+        }
+    }
+
+    /// Get the lower bound (inclusive) of the window.
+    #[inline]
+    #[must_use]
+    pub const fn lower(&self) -> &'a Key {
+        self.lower
+    }
+
+    /// Get the upper bound (exclusive) of the window.
+    #[inline]
+    #[must_use]
+    pub const fn upper(&self) -> &'a Key {
+        self.upper
+    }
+
+    /// Determine whether the parent iterator's current key is within `lower..upper`.
+    fn parent_in_window(&self) -> bool {
+        self.iter.current().is_some_and(|item| {
+            let key = I::item_to_key(item);
+            self.cmp.cmp(key, self.lower) != Ordering::Less
+                && self.cmp.cmp(key, self.upper) == Ordering::Less
+        })
+    }
+}
+
+impl<'lend, I, Key, Cmp> LendItem<'lend> for WindowIter<'_, I, Key, Cmp>
+where
+    I:   ?Sized + LendItem<'lend>,
+    Key: ?Sized,
+    Cmp: ?Sized,
+{
+    type Item = I::Item;
+}
+
+impl<I, Key, Cmp> CursorLendingIterator for WindowIter<'_, I, Key, Cmp>
+where
+    I:   ?Sized + CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.valid
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        if self.valid {
+            self.iter.next();
+        } else if self.at_lower {
+            // `iter` was already sought to `lower` when the window was created.
+        } else {
+            self.iter.seek(self.lower);
+        }
+        self.at_lower = false;
+
+        self.valid = self.parent_in_window();
+        self.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        if self.valid {
+            self.iter.current()
+        } else {
+            None
+        }
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        if self.valid {
+            self.iter.prev();
+        } else {
+            self.iter.seek_before(self.upper);
+        }
+        self.at_lower = false;
+
+        self.valid = self.parent_in_window();
+        self.current()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, Key, Cmp> ItemToKey<Key> for WindowIter<'_, I, Key, Cmp>
+where
+    I:   ?Sized + CursorLendingIterator + ItemToKey<Key> + Seekable<Key, Cmp>,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item)
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    use crate::{
+        comparator::OrdComparator, seekable_ext::SeekableLendingIteratorExt as _,
+        test_iter::TestIter,
+    };
+    use super::*;
+
+
+    #[test]
+    #[expect(
+        clippy::shadow_unrelated,
+        reason = "each check in the test starts from fresh bindings",
+    )]
+    fn adjacent_windows() {
+        let data: &[u8] = [1, 2, 4, 4, 5, 7, 8, 9].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+
+        let mut window = iter.window(&OrdComparator, &2, &5);
+        assert!(!window.valid());
+        for expected in [2, 4, 4] {
+            assert_eq!(window.next(), Some(&expected));
+        }
+        assert_eq!(window.next(), None);
+        assert!(!window.valid());
+        // The parent is left at the end of the window, once the window is no longer used.
+        assert_eq!(iter.current(), Some(&5));
+
+        let mut window = iter.window(&OrdComparator, &5, &9);
+        for expected in [5, 7, 8] {
+            assert_eq!(window.next(), Some(&expected));
+        }
+        assert_eq!(window.next(), None);
+        assert_eq!(iter.current(), Some(&9));
+    }
+
+    #[test]
+    fn window_is_circular() {
+        let data: &[u8] = [1, 2, 4, 5, 7].as_slice();
+        let mut iter = TestIter::new(data).unwrap();
+        let mut window = iter.window(&OrdComparator, &3, &6);
+
+        assert_eq!(window.prev(), Some(&5));
+        assert_eq!(window.prev(), Some(&4));
+        assert_eq!(window.prev(), None);
+        assert_eq!(window.next(), Some(&4));
+        assert_eq!(window.next(), Some(&5));
+        assert_eq!(window.next(), None);
+        assert_eq!(window.next(), Some(&4));
+
+        let mut empty_window = iter.window(&OrdComparator, &5, &5);
+        assert_eq!(empty_window.next(), None);
+        assert_eq!(empty_window.prev(), None);
+    }
+}
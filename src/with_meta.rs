@@ -0,0 +1,220 @@
+use crate::{comparator::Comparator, cursor::CursorLendingIterator};
+use crate::{
+    lending_iterator_support::{LendItem, LentItem},
+    seekable::{ItemToKey, Seekable},
+};
+
+
+/// An adapter which pairs each item of a [`CursorLendingIterator`] with metadata computed from
+/// the iterator, such as provenance information which would otherwise be lost in an adapter
+/// stack.
+///
+/// After every motion which leaves the inner iterator [valid] (including `prev` and every seek),
+/// the provided closure is called with a reference to the inner iterator, and its result is
+/// stored until the next motion. Items are then lent as pairs of the inner item and a reference
+/// to that metadata; for instance, the closure could return [`MergingIter::current_source`] to
+/// record which source of a merge each item came from.
+///
+/// Since the metadata is recomputed on every motion, it should be cheap to compute.
+///
+/// [`MergingIter::current_source`]: crate::MergingIter::current_source
+/// [valid]: CursorLendingIterator::valid
+#[derive(Debug, Clone)]
+pub struct WithMeta<I, M, F> {
+    iter:    I,
+    compute: F,
+    /// Should be `Some` if and only if `iter` is `valid()`, in which case it holds the metadata
+    /// computed for `iter`'s current position.
+    meta:    Option<M>,
+}
+
+impl<I, M, F> WithMeta<I, M, F>
+where
+    I: CursorLendingIterator,
+    F: FnMut(&I) -> M,
+{
+    /// Wrap the provided iterator, computing the metadata of each position with `compute`.
+    #[must_use]
+    pub fn new(iter: I, compute: F) -> Self {
+        let mut this = Self {
+            iter,
+            compute,
+            meta: None,
+        };
+        this.refresh();
+        this
+    }
+
+    /// Get the metadata of the current position, if the iterator is [valid].
+    ///
+    /// [valid]: CursorLendingIterator::valid
+    #[inline]
+    #[must_use]
+    pub const fn meta(&self) -> Option<&M> {
+        self.meta.as_ref()
+    }
+
+    /// Get a reference to the inner iterator.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> &I {
+        &self.iter
+    }
+
+    /// Convert the adapter back into the inner iterator.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Recompute the metadata after `self.iter` has moved.
+    fn refresh(&mut self) {
+        self.meta = if self.iter.valid() {
+            Some((self.compute)(&self.iter))
+        } else {
+            None
+        };
+    }
+}
+
+impl<'lend, I: LendItem<'lend>, M, F> LendItem<'lend> for WithMeta<I, M, F> {
+    type Item = (I::Item, &'lend M);
+}
+
+impl<I, M, F> CursorLendingIterator for WithMeta<I, M, F>
+where
+    I: CursorLendingIterator,
+    F: FnMut(&I) -> M,
+{
+    #[inline]
+    fn valid(&self) -> bool {
+        self.iter.valid()
+    }
+
+    fn next(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.next();
+        self.refresh();
+        self.current()
+    }
+
+    #[inline]
+    fn current(&self) -> Option<LentItem<'_, Self>> {
+        self.iter.current().zip(self.meta.as_ref())
+    }
+
+    fn prev(&mut self) -> Option<LentItem<'_, Self>> {
+        self.iter.prev();
+        self.refresh();
+        self.current()
+    }
+
+    #[inline]
+    fn keys_are_unique(&self) -> bool {
+        self.iter.keys_are_unique()
+    }
+}
+
+impl<I, M, F, Key> ItemToKey<Key> for WithMeta<I, M, F>
+where
+    I:   ItemToKey<Key>,
+    Key: ?Sized,
+{
+    #[inline]
+    fn item_to_key(item: LentItem<'_, Self>) -> &'_ Key {
+        I::item_to_key(item.0)
+    }
+}
+
+impl<I, M, F, Key, Cmp> Seekable<Key, Cmp> for WithMeta<I, M, F>
+where
+    I:   CursorLendingIterator + Seekable<Key, Cmp>,
+    F:   FnMut(&I) -> M,
+    Key: ?Sized,
+    Cmp: ?Sized + Comparator<Key>,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.iter.reset();
+        self.meta = None;
+    }
+
+    fn seek(&mut self, min_bound: &Key) {
+        self.iter.seek(min_bound);
+        self.refresh();
+    }
+
+    fn seek_before(&mut self, strict_upper_bound: &Key) {
+        self.iter.seek_before(strict_upper_bound);
+        self.refresh();
+    }
+
+    fn seek_to_first(&mut self) {
+        self.iter.seek_to_first();
+        self.refresh();
+    }
+
+    fn seek_to_last(&mut self) {
+        self.iter.seek_to_last();
+        self.refresh();
+    }
+}
+
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used, reason = "an unexpected `None` or `Err` should fail the test")]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    use crate::{comparator::OrdComparator, indexed::IndexedCursor as _, test_iter::TestIter};
+    #[cfg(feature = "alloc")]
+    use crate::merging_iter::MergingIter;
+    use super::*;
+
+
+    #[test]
+    fn positions_as_metadata() {
+        let data: &[u8] = [10, 20, 30].as_slice();
+        let mut tagged = WithMeta::new(TestIter::new(data).unwrap(), |iter: &TestIter<'_>| {
+            iter.current_index().unwrap()
+        });
+
+        assert_eq!(tagged.next(), Some((&10, &0)));
+        assert_eq!(tagged.next(), Some((&20, &1)));
+        Seekable::<u8, OrdComparator>::seek_to_last(&mut tagged);
+        assert_eq!(tagged.meta(), Some(&2));
+        assert_eq!(tagged.prev(), Some((&20, &1)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn merge_source_indices() {
+        let merge = MergingIter::new(
+            vec![
+                TestIter::new(&[1, 4, 6]).unwrap(),
+                TestIter::new(&[2, 3, 8]).unwrap(),
+            ],
+            OrdComparator,
+        );
+        let mut tagged = WithMeta::new(merge, |merge: &MergingIter<_, _, _>| {
+            merge.current_source().unwrap()
+        });
+        assert_eq!(tagged.meta(), None);
+
+        for (key, source) in [(1, 0), (2, 1), (3, 1), (4, 0), (6, 0), (8, 1)] {
+            assert_eq!(tagged.next(), Some((&key, &source)));
+        }
+        assert_eq!(tagged.next(), None);
+        assert_eq!(tagged.meta(), None);
+
+        // Seeks are forwarded, and their positions are tagged as well.
+        tagged.seek(&5);
+        assert_eq!(tagged.current(), Some((&6, &0)));
+        tagged.seek_before(&4);
+        assert_eq!(tagged.current(), Some((&3, &1)));
+        assert_eq!(tagged.prev(), Some((&2, &1)));
+        tagged.reset();
+        assert!(!tagged.valid());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{cursor::CursorLendingIterator, lending_iterator_support::LentItem};
+
+
+/// An adapter which advances a [`CursorLendingIterator`] and a positionally-aligned [`Iterator`]
+/// in lockstep, pairing each item of the former with the item of the latter at the same
+/// position.
+///
+/// This is intended for collections whose keys and values are stored separately, such as a
+/// [`MergingIter`] over keys alongside a stream of values in the same order. Since the first
+/// iterator lends its items, the second iterator must yield owned items (or references which
+/// are not borrowed from the iterator itself), as with [`OwnedSliceIter`] or a
+/// [`PooledIterator`] adapter.
+///
+/// Both iterators must yield the same number of items. The number of pairs yielded is tracked,
+/// and with debug assertions enabled, it is asserted that both iterators run out of items at the
+/// same position.
+///
+/// This adapter only supports forwards iteration, and is fused: once either iterator runs out of
+/// items, neither iterator is advanced again. (Advancing the primary cursor again would wrap
+/// around to its first entry, while the secondary iterator would remain exhausted.)
+///
+/// [`MergingIter`]: crate::MergingIter
+/// [`OwnedSliceIter`]: crate::OwnedSliceIter
+/// [`PooledIterator`]: crate::PooledIterator
+#[derive(Debug, Clone)]
+pub struct ZipPositionsIter<A, B> {
+    primary:   A,
+    secondary: B,
+    /// The number of pairs yielded.
+    position:  usize,
+    /// Whether either iterator has run out of items, after which neither is advanced again.
+    exhausted: bool,
+}
+
+impl<A, B> ZipPositionsIter<A, B>
+where
+    A: CursorLendingIterator,
+    B: Iterator,
+{
+    /// Pair up the items of `primary` and `secondary`, which must be positionally aligned.
+    ///
+    /// Both iterators should be at their initial positions, before their first items.
+    #[inline]
+    #[must_use]
+    pub const fn new(primary: A, secondary: B) -> Self {
+        Self {
+            primary,
+            secondary,
+            position:  0,
+            exhausted: false,
+        }
+    }
+
+    /// Get the number of pairs yielded.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Advance both iterators, and return the pair of their next items.
+    ///
+    /// Once either iterator runs out of items, `None` is returned, now and on every later call.
+    ///
+    /// # Panics
+    /// With debug assertions enabled, panics if exactly one of the iterators runs out of items,
+    /// since the iterators are then misaligned.
+    #[expect(clippy::should_implement_trait, reason = "this is a lending iterator")]
+    pub fn next(&mut self) -> Option<(LentItem<'_, A>, B::Item)> {
+        if self.exhausted {
+            return None;
+        }
+
+        let primary = self.primary.next();
+        let secondary = self.secondary.next();
+
+        debug_assert_eq!(
+            primary.is_some(),
+            secondary.is_some(),
+            "ZipPositionsIter: iterators are misaligned after {} pairs",
+            self.position,
+        );
+
+        if let (Some(primary), Some(secondary)) = (primary, secondary) {
+            self.position += 1;
+            Some((primary, secondary))
+        } else {
+            self.exhausted = true;
+            None
+        }
+    }
+
+    /// Get references to the two inner iterators.
+    #[inline]
+    #[must_use]
+    pub const fn inner(&self) -> (&A, &B) {
+        (&self.primary, &self.secondary)
+    }
+
+    /// Convert the adapter back into the two inner iterators.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> (A, B) {
+        (self.primary, self.secondary)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{comparator::OrdComparator, sorted_slice_cursor::SortedSliceCursor};
+    #[cfg(feature = "alloc")]
+    use crate::merging_iter::MergingIter;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+    use super::*;
+
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn zip_merge_with_values() {
+        let keys_one: &[u8] = [1, 4, 6].as_slice();
+        let keys_two: &[u8] = [2, 3, 7].as_slice();
+        let merge = MergingIter::new(
+            vec![
+                SortedSliceCursor::new(keys_one, OrdComparator),
+                SortedSliceCursor::new(keys_two, OrdComparator),
+            ],
+            OrdComparator,
+        );
+        let values = ["one", "two", "three", "four", "six", "seven"];
+        let mut zipped = ZipPositionsIter::new(merge, values.into_iter());
+
+        for (expected_key, expected_value) in [1, 2, 3, 4, 6, 7].into_iter().zip(values) {
+            assert_eq!(zipped.next(), Some((&expected_key, expected_value)));
+        }
+        assert_eq!(zipped.position(), 6);
+        assert_eq!(zipped.next(), None);
+        assert_eq!(zipped.position(), 6);
+        assert!(!zipped.inner().0.valid());
+
+        // The adapter is fused; the merge does not wrap around to its first entry.
+        assert_eq!(zipped.next(), None);
+        assert!(!zipped.inner().0.valid());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic = "misaligned after 2 pairs"]
+    fn misaligned_lengths() {
+        let keys: &[u8] = [1, 2, 3].as_slice();
+        let mut zipped = ZipPositionsIter::new(
+            SortedSliceCursor::new(keys, OrdComparator),
+            ['a', 'b'].into_iter(),
+        );
+
+        while zipped.next().is_some() {}
+    }
+}